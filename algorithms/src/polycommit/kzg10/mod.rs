@@ -23,12 +23,13 @@
 
 use crate::{
     fft::{DensePolynomial, Polynomial},
-    msm::VariableBase,
+    msm::{FixedBase, VariableBase},
     polycommit::PCError,
 };
 use anyhow::anyhow;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
-use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_parameters::testnet3::{PowersOfG, MAX_NUM_POWERS};
 use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE};
 
 use core::{
@@ -38,6 +39,7 @@ use core::{
 };
 use itertools::Itertools;
 use rand_core::RngCore;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -99,6 +101,92 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(params)
     }
 
+    /// Samples a new universal SRS with `num_powers` powers of beta, along with the shifted powers
+    /// of beta that lead up to the maximum degree. The trapdoor is sampled from the given `rng`,
+    /// so the resulting SRS is **insecure**, and must only be used for tests and local networks.
+    pub fn setup<R: RngCore>(num_powers: usize, rng: &mut R) -> Result<UniversalParams<E>, PCError> {
+        if num_powers < 2 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let setup_time = start_timer!(|| format!("KZG10::Setup with {num_powers} powers"));
+
+        // The maximum degree is fixed by the SRS, regardless of the number of powers that are sampled.
+        let max_degree = MAX_NUM_POWERS - 1;
+
+        // Sample the trapdoor.
+        let beta = E::Fr::rand(rng);
+        let gamma_g = E::G1Projective::rand(rng);
+        let g = E::G1Projective::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        // Computes `num_powers` consecutive powers of beta, starting from `beta^start`.
+        let powers_of_beta_from = |start: usize| {
+            let mut powers = Vec::with_capacity(num_powers);
+            let mut power = beta.pow([start as u64]);
+            for _ in 0..num_powers {
+                powers.push(power);
+                power *= &beta;
+            }
+            powers
+        };
+
+        // Compute the powers of beta G, and the shifted powers of beta G.
+        let g_time = start_timer!(|| "Compute the powers of beta G");
+        let scalar_bits = <E::Fr as PrimeField>::size_in_bits();
+        let window_size = FixedBase::get_mul_window_size(2 * num_powers);
+        let g_table = FixedBase::get_window_table(scalar_bits, window_size, g);
+        let powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(FixedBase::msm(
+            scalar_bits,
+            window_size,
+            &g_table,
+            &powers_of_beta_from(0),
+        ));
+        let shifted_powers_of_beta_g = E::G1Projective::batch_normalization_into_affine(FixedBase::msm(
+            scalar_bits,
+            window_size,
+            &g_table,
+            &powers_of_beta_from(MAX_NUM_POWERS - num_powers),
+        ));
+        end_timer!(g_time);
+
+        // Compute the powers of beta times gamma G, for the hiding bound and for each supported degree bound.
+        let gamma_time = start_timer!(|| "Compute the powers of beta times gamma G");
+        let degree_bounds = KZGDegreeBounds::Marlin.get_list::<E::Fr>(num_powers - 1);
+        let mut gamma_indices = (0..=3).collect::<BTreeSet<usize>>();
+        for degree_bound in &degree_bounds {
+            let shift_degree = max_degree - degree_bound;
+            gamma_indices.extend((shift_degree..shift_degree + 3).filter(|index| *index < max_degree + 2));
+        }
+        let powers_of_beta_times_gamma_g = gamma_indices
+            .into_iter()
+            .map(|index| (index, gamma_g.mul(beta.pow([index as u64])).to_affine()))
+            .collect::<BTreeMap<_, _>>();
+        end_timer!(gamma_time);
+
+        // Compute the negative powers of beta H, for each supported degree bound.
+        let neg_time = start_timer!(|| "Compute the negative powers of beta H");
+        let beta_inverse = beta.inverse().ok_or_else(|| anyhow!("Failed to invert beta"))?;
+        let negative_powers_of_beta_h = degree_bounds
+            .iter()
+            .map(|degree_bound| {
+                (*degree_bound, h.mul(beta_inverse.pow([(max_degree - degree_bound) as u64])).to_affine())
+            })
+            .collect::<BTreeMap<_, _>>();
+        let beta_h = h.mul(beta).to_affine();
+        end_timer!(neg_time);
+
+        let powers = PowersOfG::from_parts(
+            powers_of_beta_g,
+            shifted_powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            beta_h,
+        )?;
+        end_timer!(setup_time);
+
+        Ok(UniversalParams::from_powers(powers))
+    }
+
     /// Outputs a commitment to `polynomial`.
     pub fn commit(
         powers: &Powers<E>,
@@ -647,6 +735,26 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_setup() {
+        let rng = &mut TestRng::default();
+
+        let num_powers = 32;
+        let pp = KZG_Bls12_377::setup(num_powers, rng).unwrap();
+        let hiding_bound = Some(1);
+        let (ck, vk) = KZG_Bls12_377::trim(&pp, num_powers - 1, hiding_bound);
+
+        for _ in 0..10 {
+            let p = DensePolynomial::<Fr>::rand(num_powers - 1, rng);
+            let (comm, rand) =
+                KZG_Bls12_377::commit(&ck, &(&p).into(), hiding_bound, &AtomicBool::new(false), Some(rng)).unwrap();
+            let point = Fr::rand(rng);
+            let value = p.evaluate(point);
+            let proof = KZG_Bls12_377::open(&ck, &p, point, &rand).unwrap();
+            assert!(KZG_Bls12_377::check(&vk, &comm, point, value, &proof).unwrap());
+        }
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Equal<Self> for Signature<A> {
+    type Output = Boolean<A>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        // Determine if the challenge, response, and compute key are equal.
+        self.challenge.is_equal(&other.challenge)
+            & self.response.is_equal(&other.response)
+            & self.compute_key.pk_sig().is_equal(other.compute_key.pk_sig())
+            & self.compute_key.pr_sig().is_equal(other.compute_key.pr_sig())
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_is_equal() {
+        let rng = &mut TestRng::default();
+
+        // Sample two signatures over the same message.
+        let (private_key, _, _, _) = generate_account().unwrap();
+        let message: Vec<_> = (0..4).map(|_| Uniform::rand(rng)).collect();
+        let a = console::Signature::sign(&private_key, &message, rng).unwrap();
+        let b = console::Signature::sign(&private_key, &message, rng).unwrap();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let first = Signature::<Circuit>::new(mode, a);
+            let second = Signature::<Circuit>::new(mode, b);
+
+            assert!(first.is_equal(&first).eject_value());
+            assert!(!first.is_not_equal(&first).eject_value());
+            assert!(!first.is_equal(&second).eject_value());
+            assert!(first.is_not_equal(&second).eject_value());
+            Circuit::reset();
+        }
+    }
+}
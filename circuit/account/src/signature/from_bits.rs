@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> FromBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new signature from a list of little-endian bits *without* trailing zeros.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        let (scalar_size, group_size) = (A::ScalarField::size_in_bits(), A::BaseField::size_in_bits());
+
+        // Ensure the number of bits matches the expected size.
+        if bits_le.len() != 2 * scalar_size + 2 * group_size {
+            A::halt(format!("Invalid signature size ({} bits)", bits_le.len()))
+        }
+
+        let (challenge, rest) = bits_le.split_at(scalar_size);
+        let (response, rest) = rest.split_at(scalar_size);
+        let (pk_sig, pr_sig) = rest.split_at(group_size);
+
+        Self {
+            challenge: Scalar::from_bits_le(challenge),
+            response: Scalar::from_bits_le(response),
+            compute_key: ComputeKey::from((Group::from_bits_le(pk_sig), Group::from_bits_le(pr_sig))),
+        }
+    }
+
+    /// Initializes a new signature from a list of big-endian bits *without* leading zeros.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let (scalar_size, group_size) = (A::ScalarField::size_in_bits(), A::BaseField::size_in_bits());
+
+        // Ensure the number of bits matches the expected size.
+        if bits_be.len() != 2 * scalar_size + 2 * group_size {
+            A::halt(format!("Invalid signature size ({} bits)", bits_be.len()))
+        }
+
+        let (challenge, rest) = bits_be.split_at(scalar_size);
+        let (response, rest) = rest.split_at(scalar_size);
+        let (pk_sig, pr_sig) = rest.split_at(group_size);
+
+        Self {
+            challenge: Scalar::from_bits_be(challenge),
+            response: Scalar::from_bits_be(response),
+            compute_key: ComputeKey::from((Group::from_bits_be(pk_sig), Group::from_bits_be(pr_sig))),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_from_bits() {
+        let rng = &mut TestRng::default();
+
+        let (private_key, _, _, _) = generate_account().unwrap();
+        let message: Vec<_> = (0..4).map(|_| Uniform::rand(rng)).collect();
+        let expected = console::Signature::sign(&private_key, &message, rng).unwrap();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = Signature::<Circuit>::new(mode, expected);
+            assert_eq!(expected, Signature::<Circuit>::from_bits_le(&candidate.to_bits_le()).eject_value());
+            assert_eq!(expected, Signature::<Circuit>::from_bits_be(&candidate.to_bits_be()).eject_value());
+            Circuit::reset();
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> ToBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Outputs the little-endian bit representation of the signature *without* trailing zeros.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        (&self).to_bits_le()
+    }
+
+    /// Outputs the big-endian bit representation of the signature *without* leading zeros.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        (&self).to_bits_be()
+    }
+}
+
+impl<A: Aleo> ToBits for &Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Outputs the little-endian bit representation of the signature *without* trailing zeros.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        let mut bits_le = self.challenge.to_bits_le();
+        bits_le.extend(self.response.to_bits_le());
+        bits_le.extend(self.compute_key.pk_sig().to_bits_le());
+        bits_le.extend(self.compute_key.pr_sig().to_bits_le());
+        bits_le
+    }
+
+    /// Outputs the big-endian bit representation of the signature *without* leading zeros.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        let mut bits_be = self.challenge.to_bits_be();
+        bits_be.extend(self.response.to_bits_be());
+        bits_be.extend(self.compute_key.pk_sig().to_bits_be());
+        bits_be.extend(self.compute_key.pr_sig().to_bits_be());
+        bits_be
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_to_bits() {
+        let rng = &mut TestRng::default();
+
+        let (private_key, _, _, _) = generate_account().unwrap();
+        let message: Vec<_> = (0..4).map(|_| Uniform::rand(rng)).collect();
+        let expected = console::Signature::sign(&private_key, &message, rng).unwrap();
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = Signature::<Circuit>::new(mode, expected);
+            assert_eq!(expected.to_bits_le(), candidate.to_bits_le().eject_value());
+            assert_eq!(expected.to_bits_be(), candidate.to_bits_be().eject_value());
+            Circuit::reset();
+        }
+    }
+}
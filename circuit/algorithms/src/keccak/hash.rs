@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Returns the Keccak hash of the given input as a list of little-endian bits.
+    pub fn hash(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the variant is supported.
+        if VARIANT != 256 {
+            E::halt(format!("Unsupported Keccak variant: {VARIANT}"))
+        }
+
+        // Absorb the padded input into the state.
+        let mut state = vec![vec![Boolean::constant(false); 64]; 25];
+        for block in Self::pad(input).chunks(Self::RATE) {
+            for (lane, bits_le) in state.iter_mut().zip(block.chunks(64)) {
+                *lane = lane.iter().zip_eq(bits_le).map(|(a, b)| a ^ b).collect();
+            }
+            Self::permute(&mut state);
+        }
+
+        // Squeeze the output from the state.
+        state.into_iter().flatten().take(VARIANT).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    fn check_hash<const TYPE: u8, const VARIANT: usize>(
+        native: impl console::Hash<Input = bool, Output = Vec<bool>>,
+        mode: Mode,
+        rng: &mut TestRng,
+    ) {
+        let circuit = Keccak::<Circuit, TYPE, VARIANT>::new();
+
+        // Include inputs that are not byte-aligned, and inputs that span multiple blocks.
+        for num_bits in [0, 3, 8, 255, 1087, 1088, 1500] {
+            // Sample a random input.
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Keccak {mode} {num_bits}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_keccak_256() {
+        let mut rng = TestRng::default();
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_hash::<{ KeccakType::Keccak as u8 }, 256>(console::Keccak256::default(), mode, &mut rng);
+        }
+    }
+
+    #[test]
+    fn test_sha3_256() {
+        let mut rng = TestRng::default();
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_hash::<{ KeccakType::Sha3 as u8 }, 256>(console::Sha3_256::default(), mode, &mut rng);
+        }
+    }
+}
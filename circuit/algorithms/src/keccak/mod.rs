@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used in Ethereum.
+pub type Keccak256<E> = Keccak<E, { KeccakType::Keccak as u8 }, 256>;
+/// Sha3_256 is the SHA-3 hash function with a 256-bit output, as standardized in FIPS 202.
+pub type Sha3_256<E> = Keccak<E, { KeccakType::Sha3 as u8 }, 256>;
+
+/// The Keccak variant, which determines the domain separation bits in the padding.
+enum KeccakType {
+    Keccak,
+    Sha3,
+}
+
+/// The round constants of the Keccak-f[1600] permutation.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the Keccak-f[1600] permutation, indexed by lane `x + 5y`.
+const ROTATION_OFFSETS: [usize; 25] =
+    [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// Keccak is a cryptographic hash function built from a sponge over the Keccak-f[1600] permutation.
+/// It is *not* SNARK-friendly, and is provided for interoperability with external systems (i.e. Ethereum).
+///
+/// The input and output are little-endian bits, so byte-aligned inputs match the standard byte-oriented digests.
+pub struct Keccak<E: Environment, const TYPE: u8, const VARIANT: usize> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Default for Keccak<E, TYPE, VARIANT> {
+    /// Initializes a new Keccak hash function.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// The number of bits absorbed in each permutation.
+    const RATE: usize = 1600 - 2 * VARIANT;
+
+    /// Initializes a new Keccak hash function.
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+
+    /// Returns the given input with the domain separation bits and the `pad10*1` padding.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        let mut bits_le = input.to_vec();
+        // Append the domain separation bits for SHA-3.
+        if TYPE == KeccakType::Sha3 as u8 {
+            bits_le.extend([Boolean::constant(false), Boolean::constant(true)]);
+        }
+        // Append the `pad10*1` padding, up to a multiple of the rate.
+        bits_le.push(Boolean::constant(true));
+        while (bits_le.len() + 1) % Self::RATE != 0 {
+            bits_le.push(Boolean::constant(false));
+        }
+        bits_le.push(Boolean::constant(true));
+        bits_le
+    }
+
+    /// Applies the Keccak-f[1600] permutation to the given state of 25 little-endian 64-bit lanes.
+    fn permute(state: &mut [Vec<Boolean<E>>]) {
+        // Returns the bitwise XOR of the given lanes.
+        let xor = |a: &[Boolean<E>], b: &[Boolean<E>]| a.iter().zip_eq(b).map(|(a, b)| a ^ b).collect::<Vec<_>>();
+        // Returns the given lane rotated left by `n` bits.
+        let rotate_left =
+            |a: &[Boolean<E>], n: usize| (0..64).map(|i| a[(i + 64 - n) % 64].clone()).collect::<Vec<_>>();
+
+        for round_constant in ROUND_CONSTANTS {
+            // Theta.
+            let c = (0..5)
+                .map(|x| (1..5).fold(state[x].clone(), |acc, y| xor(&acc, &state[x + 5 * y])))
+                .collect::<Vec<_>>();
+            for x in 0..5 {
+                let d = xor(&c[(x + 4) % 5], &rotate_left(&c[(x + 1) % 5], 1));
+                for y in 0..5 {
+                    state[x + 5 * y] = xor(&state[x + 5 * y], &d);
+                }
+            }
+            // Rho and Pi.
+            let mut b = vec![Vec::new(); 25];
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = rotate_left(&state[x + 5 * y], ROTATION_OFFSETS[x + 5 * y]);
+                }
+            }
+            // Chi.
+            for x in 0..5 {
+                for y in 0..5 {
+                    let (b0, b1, b2) = (&b[x + 5 * y], &b[(x + 1) % 5 + 5 * y], &b[(x + 2) % 5 + 5 * y]);
+                    state[x + 5 * y] = b0.iter().zip_eq(b1).zip_eq(b2).map(|((b0, b1), b2)| b0 ^ &(!b1 & b2)).collect();
+                }
+            }
+            // Iota.
+            for (i, bit) in state[0].iter_mut().enumerate() {
+                if (round_constant >> i) & 1 == 1 {
+                    *bit = !&*bit;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Sha256<E> {
+    /// Returns the SHA-256 hash of the given input as a list of little-endian bits (per byte).
+    pub fn hash(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        let mut state = INITIAL_STATE.iter().map(|word| Self::constant(*word)).collect::<Vec<_>>();
+        for block in Self::pad(input).chunks(512) {
+            Self::compress(&mut state, block);
+        }
+        // Output each byte of the state in little-endian bit order.
+        state.iter().flat_map(|word| word.to_bits_le().chunks(8).rev().flatten().cloned().collect::<Vec<_>>()).collect()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use console::Hash as H;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    fn check_hash(mode: Mode, rng: &mut TestRng) {
+        let native = console::Sha256;
+        let circuit = Sha256::<Circuit>::new();
+
+        // Include inputs that are not byte-aligned, and inputs that span multiple blocks.
+        for num_bits in [0, 3, 8, 255, 447, 448, 512, 1000] {
+            // Sample a random input.
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            // Compute the expected hash.
+            let expected = native.hash(&input).expect("Failed to hash native input");
+            // Prepare the circuit input.
+            let circuit_input: Vec<Boolean<_>> = Inject::new(mode, input);
+
+            Circuit::scope(format!("Sha256 {mode} {num_bits}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+            });
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_hash_constant() {
+        check_hash(Mode::Constant, &mut TestRng::default());
+    }
+
+    #[test]
+    fn test_hash_public() {
+        check_hash(Mode::Public, &mut TestRng::default());
+    }
+
+    #[test]
+    fn test_hash_private() {
+        check_hash(Mode::Private, &mut TestRng::default());
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_circuit_types::prelude::*;
+
+use core::marker::PhantomData;
+
+/// The SHA-256 round constants.
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98,
+    0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786,
+    0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8,
+    0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13,
+    0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+    0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a,
+    0x5b9cca4f, 0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+    0xc67178f2,
+];
+
+/// The SHA-256 initial hash values.
+const INITIAL_STATE: [u32; 8] =
+    [0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19];
+
+/// SHA-256 is the SHA-2 hash function with a 256-bit output, as standardized in FIPS 180-4.
+/// It is *not* SNARK-friendly, and is provided for interoperability with external systems.
+///
+/// The input and output are little-endian bits (per byte), so byte-aligned inputs match the standard digests.
+pub struct Sha256<E: Environment> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Environment> Default for Sha256<E> {
+    /// Initializes a new SHA-256 hash function.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Environment> Sha256<E> {
+    /// Initializes a new SHA-256 hash function.
+    pub fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+
+    /// Returns the given input as a big-endian bit stream, with the SHA-256 padding.
+    fn pad(input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Reverse the bit order within each byte, to recover the big-endian bit stream.
+        let mut bits_be: Vec<Boolean<E>> = input.chunks(8).flat_map(|byte| byte.iter().rev().cloned()).collect();
+        let num_bits = bits_be.len() as u64;
+        // Append the `1 0*` padding, up to 448 bits modulo 512.
+        bits_be.push(Boolean::constant(true));
+        while bits_be.len() % 512 != 448 {
+            bits_be.push(Boolean::constant(false));
+        }
+        // Append the input length as a 64-bit big-endian integer.
+        bits_be.extend((0..64).rev().map(|i| Boolean::constant((num_bits >> i) & 1 == 1)));
+        bits_be
+    }
+
+    /// Returns the given value as a constant 32-bit word.
+    fn constant(value: u32) -> U32<E> {
+        U32::from_bits_le(&(0..32).map(|i| Boolean::constant((value >> i) & 1 == 1)).collect::<Vec<_>>())
+    }
+
+    /// Returns the given word rotated right by `n` bits.
+    fn rotate_right(word: &U32<E>, n: usize) -> U32<E> {
+        let bits_le = word.to_bits_le();
+        U32::from_bits_le(&(0..32).map(|i| bits_le[(i + n) % 32].clone()).collect::<Vec<_>>())
+    }
+
+    /// Returns the given word shifted right by `n` bits.
+    fn shift_right(word: &U32<E>, n: usize) -> U32<E> {
+        U32::from_bits_le(&word.to_bits_le()[n..])
+    }
+
+    /// Applies the SHA-256 compression function to the given state and 512-bit block.
+    fn compress(state: &mut [U32<E>], block: &[Boolean<E>]) {
+        // Prepare the message schedule.
+        let mut w: Vec<U32<E>> = block.chunks(32).map(U32::from_bits_be).collect();
+        for i in 16..64 {
+            let s0 = Self::rotate_right(&w[i - 15], 7)
+                ^ Self::rotate_right(&w[i - 15], 18)
+                ^ Self::shift_right(&w[i - 15], 3);
+            let s1 = Self::rotate_right(&w[i - 2], 17)
+                ^ Self::rotate_right(&w[i - 2], 19)
+                ^ Self::shift_right(&w[i - 2], 10);
+            w.push(w[i - 16].add_wrapped(&s0).add_wrapped(&w[i - 7]).add_wrapped(&s1));
+        }
+
+        // Apply the rounds.
+        let (mut a, mut b, mut c, mut d) = (state[0].clone(), state[1].clone(), state[2].clone(), state[3].clone());
+        let (mut e, mut f, mut g, mut h) = (state[4].clone(), state[5].clone(), state[6].clone(), state[7].clone());
+        for (round_constant, w) in ROUND_CONSTANTS.iter().zip_eq(&w) {
+            let s1 = Self::rotate_right(&e, 6) ^ Self::rotate_right(&e, 11) ^ Self::rotate_right(&e, 25);
+            let ch = (&e & &f) ^ (!&e & &g);
+            let t1 = h.add_wrapped(&s1).add_wrapped(&ch).add_wrapped(&Self::constant(*round_constant)).add_wrapped(w);
+            let s0 = Self::rotate_right(&a, 2) ^ Self::rotate_right(&a, 13) ^ Self::rotate_right(&a, 22);
+            let maj = (&a & &b) ^ (&a & &c) ^ (&b & &c);
+            let t2 = s0.add_wrapped(&maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.add_wrapped(&t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.add_wrapped(&t2);
+        }
+
+        // Update the state.
+        for (word, value) in state.iter_mut().zip_eq([a, b, c, d, e, f, g, h]) {
+            *word = word.add_wrapped(&value);
+        }
+    }
+}
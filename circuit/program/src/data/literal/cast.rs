@@ -0,0 +1,240 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuit_types::integers::Integer;
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Casts the literal into the given literal type, enforcing that the value is preserved.
+    /// The circuit is unsatisfied if the value does not fit in the given literal type.
+    pub fn cast(&self, to_type: console::LiteralType) -> Result<Self> {
+        // Perform the lossy cast.
+        let output = self.cast_lossy(to_type)?;
+        // Enforce the value was not truncated, for the literal types that may truncate.
+        match to_type {
+            console::LiteralType::Boolean
+            | console::LiteralType::I8
+            | console::LiteralType::I16
+            | console::LiteralType::I32
+            | console::LiteralType::I64
+            | console::LiteralType::I128
+            | console::LiteralType::U8
+            | console::LiteralType::U16
+            | console::LiteralType::U32
+            | console::LiteralType::U64
+            | console::LiteralType::U128
+            | console::LiteralType::Scalar => A::assert_eq(output.to_cast_field()?, self.to_cast_field()?),
+            _ => (),
+        }
+        Ok(output)
+    }
+
+    /// Casts the literal into the given literal type, truncating the value if it does not fit.
+    ///   - Integers and booleans are sign-extended (or zero-extended) and truncated to the destination size.
+    ///   - All other literals are cast through their base field representation.
+    pub fn cast_lossy(&self, to_type: console::LiteralType) -> Result<Self> {
+        match to_type {
+            console::LiteralType::Address => {
+                Ok(Literal::Address(Address::from_group(Group::from_x_coordinate(self.to_cast_field()?))))
+            }
+            console::LiteralType::Boolean => Ok(Literal::Boolean(self.to_cast_bits_le(1)?[0].clone())),
+            console::LiteralType::Field => Ok(Literal::Field(self.to_cast_field()?)),
+            console::LiteralType::Group => Ok(Literal::Group(Group::from_x_coordinate(self.to_cast_field()?))),
+            console::LiteralType::I8 => Ok(Literal::I8(self.cast_lossy_integer()?)),
+            console::LiteralType::I16 => Ok(Literal::I16(self.cast_lossy_integer()?)),
+            console::LiteralType::I32 => Ok(Literal::I32(self.cast_lossy_integer()?)),
+            console::LiteralType::I64 => Ok(Literal::I64(self.cast_lossy_integer()?)),
+            console::LiteralType::I128 => Ok(Literal::I128(self.cast_lossy_integer()?)),
+            console::LiteralType::U8 => Ok(Literal::U8(self.cast_lossy_integer()?)),
+            console::LiteralType::U16 => Ok(Literal::U16(self.cast_lossy_integer()?)),
+            console::LiteralType::U32 => Ok(Literal::U32(self.cast_lossy_integer()?)),
+            console::LiteralType::U64 => Ok(Literal::U64(self.cast_lossy_integer()?)),
+            console::LiteralType::U128 => Ok(Literal::U128(self.cast_lossy_integer()?)),
+            console::LiteralType::Scalar => {
+                // Truncate the base field representation to the scalar field data bits.
+                let bits_le = self.to_cast_bits_le(A::ScalarField::size_in_data_bits())?;
+                Ok(Literal::Scalar(Scalar::from_bits_le(&bits_le)))
+            }
+            console::LiteralType::String => bail!("Cannot cast '{}' to a string", self.eject_value()),
+            console::LiteralType::Signature => bail!("Cannot cast '{}' to a signature", self.eject_value()),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Returns the base field representation of the literal, for casting.
+    /// Note: Signed integers are mapped to their (possibly negative) base field value.
+    fn to_cast_field(&self) -> Result<Field<A>> {
+        match self {
+            Self::Address(literal) => Ok(literal.to_field()),
+            Self::Boolean(literal) => Ok(Field::from_boolean(literal)),
+            Self::Field(literal) => Ok(literal.clone()),
+            Self::Group(literal) => Ok(literal.to_x_coordinate()),
+            Self::I8(literal) => Ok(integer_to_cast_field(literal)),
+            Self::I16(literal) => Ok(integer_to_cast_field(literal)),
+            Self::I32(literal) => Ok(integer_to_cast_field(literal)),
+            Self::I64(literal) => Ok(integer_to_cast_field(literal)),
+            Self::I128(literal) => Ok(integer_to_cast_field(literal)),
+            Self::U8(literal) => Ok(integer_to_cast_field(literal)),
+            Self::U16(literal) => Ok(integer_to_cast_field(literal)),
+            Self::U32(literal) => Ok(integer_to_cast_field(literal)),
+            Self::U64(literal) => Ok(integer_to_cast_field(literal)),
+            Self::U128(literal) => Ok(integer_to_cast_field(literal)),
+            Self::Scalar(literal) => Ok(literal.to_field()),
+            Self::String(..) => bail!("Cannot cast a string literal"),
+            Self::Signature(..) => bail!("Cannot cast a signature literal"),
+        }
+    }
+
+    /// Returns the first `num_bits` little-endian bits of the literal, for casting.
+    /// Integers are sign-extended (or zero-extended), and all other literals are zero-extended.
+    fn to_cast_bits_le(&self, num_bits: usize) -> Result<Vec<Boolean<A>>> {
+        let (mut bits_le, sign) = match self {
+            Self::Boolean(literal) => (vec![literal.clone()], Boolean::constant(false)),
+            Self::I8(literal) => integer_to_cast_bits_le(literal),
+            Self::I16(literal) => integer_to_cast_bits_le(literal),
+            Self::I32(literal) => integer_to_cast_bits_le(literal),
+            Self::I64(literal) => integer_to_cast_bits_le(literal),
+            Self::I128(literal) => integer_to_cast_bits_le(literal),
+            Self::U8(literal) => integer_to_cast_bits_le(literal),
+            Self::U16(literal) => integer_to_cast_bits_le(literal),
+            Self::U32(literal) => integer_to_cast_bits_le(literal),
+            Self::U64(literal) => integer_to_cast_bits_le(literal),
+            Self::U128(literal) => integer_to_cast_bits_le(literal),
+            _ => (self.to_cast_field()?.to_bits_le(), Boolean::constant(false)),
+        };
+        // Extend (or truncate) the bits to the given size.
+        bits_le.resize(num_bits, sign);
+        Ok(bits_le)
+    }
+
+    /// Casts the literal into an integer, truncating the value if it does not fit.
+    fn cast_lossy_integer<I: IntegerType>(&self) -> Result<Integer<A, I>> {
+        Ok(Integer::from_bits_le(&self.to_cast_bits_le(I::BITS as usize)?))
+    }
+}
+
+/// Returns the base field representation of the integer, mapping negative values to negative field elements.
+#[cfg(console)]
+fn integer_to_cast_field<A: Aleo, I: IntegerType>(integer: &Integer<A, I>) -> Field<A> {
+    match I::is_signed() {
+        // Compute the field as `sum(bits_le[0..n-1]) - msb * 2^(n-1)`.
+        true => {
+            let bits_le = integer.to_bits_le();
+            let (msb, bits_le) = match bits_le.split_last() {
+                Some((msb, bits_le)) => (msb, bits_le),
+                None => A::halt("Malformed integer detected while casting"),
+            };
+            let offset = Field::constant(console::Field::from_u128(1u128 << (I::BITS - 1)));
+            Field::from_bits_le(bits_le) - Field::from_boolean(msb) * offset
+        }
+        false => integer.to_field(),
+    }
+}
+
+/// Returns the little-endian bits of the integer, along with the bit used to extend it.
+#[cfg(console)]
+fn integer_to_cast_bits_le<A: Aleo, I: IntegerType>(integer: &Integer<A, I>) -> (Vec<Boolean<A>>, Boolean<A>) {
+    let bits_le = integer.to_bits_le();
+    let sign = match (I::is_signed(), bits_le.last()) {
+        (true, Some(msb)) => msb.clone(),
+        _ => Boolean::constant(false),
+    };
+    (bits_le, sign)
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    fn check_cast(input: &str, to_type: console::LiteralType, mode: Mode) {
+        let expected = console::Literal::<<Circuit as Environment>::Network>::from_str(input).unwrap();
+        let literal = Literal::<Circuit>::new(mode, expected.clone());
+
+        Circuit::scope(format!("{mode} {input} {to_type}"), || {
+            let candidate = literal.cast(to_type).unwrap();
+            match expected.cast(to_type) {
+                Ok(expected) => {
+                    assert_eq!(expected, candidate.eject_value());
+                    assert!(Circuit::is_satisfied_in_scope());
+                }
+                Err(_) => match mode {
+                    Mode::Constant => (),
+                    _ => assert!(!Circuit::is_satisfied_in_scope()),
+                },
+            }
+        });
+        Circuit::reset();
+    }
+
+    fn check_cast_lossy(input: &str, to_type: console::LiteralType, mode: Mode) {
+        let expected = console::Literal::<<Circuit as Environment>::Network>::from_str(input).unwrap();
+        let literal = Literal::<Circuit>::new(mode, expected.clone());
+
+        Circuit::scope(format!("{mode} {input} {to_type}"), || {
+            let candidate = literal.cast_lossy(to_type).unwrap();
+            assert_eq!(expected.cast_lossy(to_type).unwrap(), candidate.eject_value());
+            assert!(Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_cast() {
+        for mode in [Mode::Public, Mode::Private] {
+            check_cast("255u8", console::LiteralType::U64, mode);
+            check_cast("255u64", console::LiteralType::U8, mode);
+            check_cast("256u64", console::LiteralType::U8, mode);
+            check_cast("-1i8", console::LiteralType::I64, mode);
+            check_cast("-1i8", console::LiteralType::U8, mode);
+            check_cast("-1i8", console::LiteralType::Field, mode);
+            check_cast("128u8", console::LiteralType::I8, mode);
+            check_cast("-129i16", console::LiteralType::I8, mode);
+            check_cast("340282366920938463463374607431768211456field", console::LiteralType::U128, mode);
+            check_cast("true", console::LiteralType::U8, mode);
+            check_cast("2field", console::LiteralType::Boolean, mode);
+            check_cast("7scalar", console::LiteralType::Field, mode);
+            check_cast("7field", console::LiteralType::Scalar, mode);
+        }
+    }
+
+    #[test]
+    fn test_cast_lossy() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            check_cast_lossy("256u64", console::LiteralType::U8, mode);
+            check_cast_lossy("255u8", console::LiteralType::I8, mode);
+            check_cast_lossy("-1i8", console::LiteralType::U16, mode);
+            check_cast_lossy("-1i8", console::LiteralType::I128, mode);
+            check_cast_lossy("2field", console::LiteralType::Boolean, mode);
+            check_cast_lossy("340282366920938463463374607431768211457field", console::LiteralType::U128, mode);
+        }
+    }
+
+    #[test]
+    fn test_cast_address() {
+        let mut rng = TestRng::default();
+        let address = console::Address::<<Circuit as Environment>::Network>::new(Uniform::rand(&mut rng));
+        for mode in [Mode::Public, Mode::Private] {
+            check_cast(&address.to_string(), console::LiteralType::Field, mode);
+            check_cast(&address.to_string(), console::LiteralType::Group, mode);
+            check_cast(&address.to_x_coordinate().to_string(), console::LiteralType::Address, mode);
+        }
+    }
+}
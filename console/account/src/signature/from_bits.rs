@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBits for Signature<N> {
+    /// Initializes a new signature from a list of **little-endian** bits of `(challenge, response, pk_sig, pr_sig)`.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        ensure!(bits_le.len() == Self::size_in_bits(), "Expected {} bits for a signature", Self::size_in_bits());
+
+        // Split the bits into their components.
+        let (challenge, bits_le) = bits_le.split_at(Scalar::<N>::size_in_bits());
+        let (response, bits_le) = bits_le.split_at(Scalar::<N>::size_in_bits());
+        let (pk_sig, pr_sig) = bits_le.split_at(Group::<N>::size_in_bits());
+
+        // Recover the compute key from the x-coordinates of `pk_sig` and `pr_sig`.
+        let compute_key = ComputeKey::try_from((Group::from_bits_le(pk_sig)?, Group::from_bits_le(pr_sig)?))?;
+        // Output the signature.
+        Ok(Self { challenge: Scalar::from_bits_le(challenge)?, response: Scalar::from_bits_le(response)?, compute_key })
+    }
+
+    /// Initializes a new signature from a list of **big-endian** bits of `(challenge, response, pk_sig, pr_sig)`.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        // Ensure the number of bits is correct.
+        ensure!(bits_be.len() == Self::size_in_bits(), "Expected {} bits for a signature", Self::size_in_bits());
+
+        // Split the bits into their components.
+        let (challenge, bits_be) = bits_be.split_at(Scalar::<N>::size_in_bits());
+        let (response, bits_be) = bits_be.split_at(Scalar::<N>::size_in_bits());
+        let (pk_sig, pr_sig) = bits_be.split_at(Group::<N>::size_in_bits());
+
+        // Recover the compute key from the x-coordinates of `pk_sig` and `pr_sig`.
+        let compute_key = ComputeKey::try_from((Group::from_bits_be(pk_sig)?, Group::from_bits_be(pr_sig)?))?;
+        // Output the signature.
+        Ok(Self { challenge: Scalar::from_bits_be(challenge)?, response: Scalar::from_bits_be(response)?, compute_key })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new signature.
+            let expected = test_helpers::sample_signature(i, &mut rng);
+
+            // Check the little-endian and big-endian bit representations.
+            assert_eq!(expected, Signature::from_bits_le(&expected.to_bits_le())?);
+            assert_eq!(expected, Signature::from_bits_be(&expected.to_bits_be())?);
+
+            // Ensure a truncated bit representation fails.
+            assert!(Signature::<CurrentNetwork>::from_bits_le(&expected.to_bits_le()[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<const TYPE: u8, const VARIANT: usize> Hash for Keccak<TYPE, VARIANT> {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the Keccak hash of the given input as a list of little-endian bits.
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the variant is supported.
+        ensure!(VARIANT == 256, "Unsupported Keccak variant: {VARIANT}");
+
+        // Absorb the padded input into the state.
+        let mut state = [0u64; 25];
+        for block in Self::pad(input).chunks(Self::RATE) {
+            for (lane, bits_le) in state.iter_mut().zip(block.chunks(64)) {
+                *lane ^= bits_le.iter().rev().fold(0u64, |acc, bit| (acc << 1) | *bit as u64);
+            }
+            Self::permute(&mut state);
+        }
+
+        // Squeeze the output from the state.
+        Ok(state.iter().flat_map(|lane| (0..64).map(move |i| (lane >> i) & 1 == 1)).take(VARIANT).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the little-endian bits of the given bytes.
+    fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    fn check_hash<H: Hash<Input = bool, Output = Vec<bool>>>(hasher: H, input: &[u8], expected: &str) {
+        let candidate = hasher.hash(&bytes_to_bits_le(input)).unwrap();
+        assert_eq!(candidate, bytes_to_bits_le(&hex::decode(expected).unwrap()));
+    }
+
+    #[test]
+    fn test_keccak256() {
+        check_hash(Keccak256::default(), b"", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        check_hash(Keccak256::default(), b"abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45");
+        check_hash(
+            Keccak256::default(),
+            &[b'a'; 200],
+            "96ea54061def936c4be90b518992fdc6f12f535068a256229aca54267b4d084d",
+        );
+    }
+
+    #[test]
+    fn test_sha3_256() {
+        check_hash(Sha3_256::default(), b"", "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+        check_hash(Sha3_256::default(), b"abc", "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+        check_hash(
+            Sha3_256::default(),
+            &[b'a'; 200],
+            "cce34485baf2bf2aca99b94833892a4f52896d3d153f7b840cc4f9fe695f1387",
+        );
+    }
+
+    #[test]
+    fn test_unaligned_input() {
+        // Ensure inputs that are not byte-aligned are not confused with their zero-padded counterparts.
+        let input = [true, false, true];
+        let mut padded = input.to_vec();
+        padded.resize(8, false);
+        assert_ne!(Keccak256::default().hash(&input).unwrap(), Keccak256::default().hash(&padded).unwrap());
+        assert_ne!(Sha3_256::default().hash(&input).unwrap(), Sha3_256::default().hash(&padded).unwrap());
+    }
+}
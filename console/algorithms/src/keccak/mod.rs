@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used in Ethereum.
+pub type Keccak256 = Keccak<{ KeccakType::Keccak as u8 }, 256>;
+/// Sha3_256 is the SHA-3 hash function with a 256-bit output, as standardized in FIPS 202.
+pub type Sha3_256 = Keccak<{ KeccakType::Sha3 as u8 }, 256>;
+
+/// The Keccak variant, which determines the domain separation bits in the padding.
+enum KeccakType {
+    Keccak,
+    Sha3,
+}
+
+/// The number of rounds in the Keccak-f[1600] permutation.
+const NUM_ROUNDS: usize = 24;
+
+/// The round constants of the Keccak-f[1600] permutation.
+const ROUND_CONSTANTS: [u64; NUM_ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the Keccak-f[1600] permutation, indexed by lane `x + 5y`.
+const ROTATION_OFFSETS: [u32; 25] =
+    [0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// Keccak is a cryptographic hash function built from a sponge over the Keccak-f[1600] permutation.
+/// It is *not* SNARK-friendly, and is provided for interoperability with external systems (i.e. Ethereum).
+///
+/// The input and output are little-endian bits, so byte-aligned inputs match the standard byte-oriented digests.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Keccak<const TYPE: u8, const VARIANT: usize>;
+
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// The number of bits absorbed in each permutation.
+    const RATE: usize = 1600 - 2 * VARIANT;
+
+    /// Returns the given input with the domain separation bits and the `pad10*1` padding.
+    fn pad(input: &[bool]) -> Vec<bool> {
+        let mut bits_le = input.to_vec();
+        // Append the domain separation bits for SHA-3.
+        if TYPE == KeccakType::Sha3 as u8 {
+            bits_le.extend([false, true]);
+        }
+        // Append the `pad10*1` padding, up to a multiple of the rate.
+        bits_le.push(true);
+        while (bits_le.len() + 1) % Self::RATE != 0 {
+            bits_le.push(false);
+        }
+        bits_le.push(true);
+        bits_le
+    }
+
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    fn permute(state: &mut [u64; 25]) {
+        for round_constant in ROUND_CONSTANTS {
+            // Theta.
+            let mut c = [0u64; 5];
+            for x in 0..5 {
+                c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+            }
+            for x in 0..5 {
+                let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+                for y in 0..5 {
+                    state[x + 5 * y] ^= d;
+                }
+            }
+            // Rho and Pi.
+            let mut b = [0u64; 25];
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] = state[x + 5 * y].rotate_left(ROTATION_OFFSETS[x + 5 * y]);
+                }
+            }
+            // Chi.
+            for x in 0..5 {
+                for y in 0..5 {
+                    state[x + 5 * y] = b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+                }
+            }
+            // Iota.
+            state[0] ^= round_constant;
+        }
+    }
+}
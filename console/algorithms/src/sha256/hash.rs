@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl Hash for Sha256 {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the SHA-256 hash of the given input as a list of little-endian bits (per byte).
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        let mut state = INITIAL_STATE;
+        for block in Self::pad(input).chunks(512) {
+            Self::compress(&mut state, block);
+        }
+        // Output each byte of the state in little-endian bit order.
+        Ok(state
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Digest;
+
+    /// Returns the little-endian bits of the given bytes.
+    fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter().flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1)).collect()
+    }
+
+    fn check_hash(input: &[u8], expected: &str) {
+        let candidate = Sha256.hash(&bytes_to_bits_le(input)).unwrap();
+        assert_eq!(candidate, bytes_to_bits_le(&hex::decode(expected).unwrap()));
+    }
+
+    #[test]
+    fn test_sha256() {
+        check_hash(b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        check_hash(b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        check_hash(&[b'a'; 200], "c2a908d98f5df987ade41b5fce213067efbcc21ef2240212a41e54b5e7c28ae5");
+    }
+
+    #[test]
+    fn test_sha256_matches_sha2() {
+        let mut rng = TestRng::default();
+        for num_bytes in 0..200 {
+            let input: Vec<u8> = (0..num_bytes).map(|_| rng.gen()).collect();
+            let expected = sha2::Sha256::digest(&input);
+            assert_eq!(Sha256.hash(&bytes_to_bits_le(&input)).unwrap(), bytes_to_bits_le(&expected));
+        }
+    }
+}
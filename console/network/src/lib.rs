@@ -27,6 +27,9 @@ pub use snarkvm_console_network_environment::*;
 mod helpers;
 pub use helpers::*;
 
+pub mod devnet;
+pub use devnet::Devnet;
+
 mod testnet3;
 pub use testnet3::*;
 
@@ -37,6 +40,7 @@ pub mod prelude {
 use crate::environment::prelude::*;
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
+    polycommit::kzg10::UniversalParams,
     snark::marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinHidingMode},
     AlgebraicSponge,
 };
@@ -124,6 +128,8 @@ pub trait Network:
     const MAX_INSTRUCTIONS: usize = u16::MAX as usize;
     /// The maximum number of commands in finalize.
     const MAX_COMMANDS: usize = u8::MAX as usize;
+    /// The maximum number of iterations of a `repeat` block in finalize.
+    const MAX_REPEAT_ITERATIONS: usize = u8::MAX as usize;
 
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 8;
@@ -149,10 +155,24 @@ pub trait Network:
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<MarlinVerifyingKey<Self>>>;
 
     /// Returns the `proving key` for the inclusion circuit.
-    fn inclusion_proving_key() -> &'static Arc<MarlinProvingKey<Self>>;
+    fn inclusion_proving_key() -> Result<&'static Arc<MarlinProvingKey<Self>>>;
 
     /// Returns the `verifying key` for the inclusion circuit.
-    fn inclusion_verifying_key() -> &'static Arc<MarlinVerifyingKey<Self>>;
+    fn inclusion_verifying_key() -> Result<&'static Arc<MarlinVerifyingKey<Self>>>;
+
+    /// Returns the universal SRS, if the network samples it locally instead of using the universal setup.
+    fn local_universal_srs() -> Option<&'static UniversalParams<Self::PairingCurve>> {
+        None
+    }
+
+    /// Sets the locally-synthesized proving and verifying keys for `credits.aleo` and the inclusion circuit.
+    /// This is only supported by networks that sample their universal SRS locally.
+    fn set_local_circuit_keys(
+        _credits_keys: IndexMap<String, (Arc<MarlinProvingKey<Self>>, Arc<MarlinVerifyingKey<Self>>)>,
+        _inclusion_keys: (Arc<MarlinProvingKey<Self>>, Arc<MarlinVerifyingKey<Self>>),
+    ) -> Result<()> {
+        bail!("{} does not support locally-synthesized circuit keys", Self::NAME)
+    }
 
     /// Returns the powers of `G`.
     fn g_powers() -> &'static Vec<Group<Self>>;
@@ -217,6 +237,15 @@ pub trait Network:
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[bool]) -> Result<Field<Self>>;
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>>;
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>>;
+
+    /// Returns the SHA-256 hash with a 256-bit output.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>>;
 
@@ -17,11 +17,14 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Keccak256,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha256,
+    Sha3_256,
     BHP1024,
     BHP256,
     BHP512,
@@ -158,26 +161,24 @@ impl Network for Testnet3 {
     }
 
     /// Returns the `proving key` for the inclusion circuit.
-    fn inclusion_proving_key() -> &'static Arc<MarlinProvingKey<Self>> {
+    fn inclusion_proving_key() -> Result<&'static Arc<MarlinProvingKey<Self>>> {
         static INSTANCE: OnceCell<Arc<MarlinProvingKey<Console>>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
+        INSTANCE.get_or_try_init(|| {
             // Skipping the first 2 bytes, which is the encoded version.
-            Arc::new(
-                CircuitProvingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_PROVING_KEY[2..])
-                    .expect("Failed to load inclusion proving key."),
-            )
+            CircuitProvingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_PROVING_KEY[2..])
+                .map(Arc::new)
+                .map_err(|_| anyhow!("Failed to load inclusion proving key."))
         })
     }
 
     /// Returns the `verifying key` for the inclusion circuit.
-    fn inclusion_verifying_key() -> &'static Arc<MarlinVerifyingKey<Self>> {
+    fn inclusion_verifying_key() -> Result<&'static Arc<MarlinVerifyingKey<Self>>> {
         static INSTANCE: OnceCell<Arc<MarlinVerifyingKey<Console>>> = OnceCell::new();
-        INSTANCE.get_or_init(|| {
+        INSTANCE.get_or_try_init(|| {
             // Skipping the first 2 bytes, which is the encoded version.
-            Arc::new(
-                CircuitVerifyingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_VERIFYING_KEY[2..])
-                    .expect("Failed to load inclusion verifying key."),
-            )
+            CircuitVerifyingKey::from_bytes_le(&snarkvm_parameters::testnet3::INCLUSION_VERIFYING_KEY[2..])
+                .map(Arc::new)
+                .map_err(|_| anyhow!("Failed to load inclusion verifying key."))
         })
     }
 
@@ -293,6 +294,21 @@ impl Network for Testnet3 {
         PEDERSEN_128.hash(input)
     }
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+        Keccak256::default().hash(input)
+    }
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha3_256::default().hash(input)
+    }
+
+    /// Returns the SHA-256 hash with a 256-bit output.
+    fn hash_sha256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha256::default().hash(input)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
         POSEIDON_2.hash(input)
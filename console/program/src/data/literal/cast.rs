@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console_types::integers::Integer;
+
+impl<N: Network> Literal<N> {
+    /// Casts the literal into the given literal type, ensuring the value is preserved.
+    /// Returns an error if the value does not fit in the given literal type.
+    pub fn cast(&self, to_type: LiteralType) -> Result<Self> {
+        // Perform the lossy cast.
+        let output = self.cast_lossy(to_type)?;
+        // Ensure the value was not truncated, for the literal types that may truncate.
+        match to_type {
+            LiteralType::Boolean
+            | LiteralType::I8
+            | LiteralType::I16
+            | LiteralType::I32
+            | LiteralType::I64
+            | LiteralType::I128
+            | LiteralType::U8
+            | LiteralType::U16
+            | LiteralType::U32
+            | LiteralType::U64
+            | LiteralType::U128
+            | LiteralType::Scalar => ensure!(
+                output.to_cast_field()? == self.to_cast_field()?,
+                "Failed to cast '{self}' to '{to_type}': the value does not fit in the type"
+            ),
+            _ => (),
+        }
+        Ok(output)
+    }
+
+    /// Casts the literal into the given literal type, truncating the value if it does not fit.
+    ///   - Integers and booleans are sign-extended (or zero-extended) and truncated to the destination size.
+    ///   - All other literals are cast through their base field representation.
+    pub fn cast_lossy(&self, to_type: LiteralType) -> Result<Self> {
+        match to_type {
+            LiteralType::Address => {
+                Ok(Literal::Address(Address::new(Group::from_x_coordinate(self.to_cast_field()?)?)))
+            }
+            LiteralType::Boolean => Ok(Literal::Boolean(Boolean::new(self.to_cast_bits_le(1)?[0]))),
+            LiteralType::Field => Ok(Literal::Field(self.to_cast_field()?)),
+            LiteralType::Group => Ok(Literal::Group(Group::from_x_coordinate(self.to_cast_field()?)?)),
+            LiteralType::I8 => Ok(Literal::I8(self.cast_lossy_integer()?)),
+            LiteralType::I16 => Ok(Literal::I16(self.cast_lossy_integer()?)),
+            LiteralType::I32 => Ok(Literal::I32(self.cast_lossy_integer()?)),
+            LiteralType::I64 => Ok(Literal::I64(self.cast_lossy_integer()?)),
+            LiteralType::I128 => Ok(Literal::I128(self.cast_lossy_integer()?)),
+            LiteralType::U8 => Ok(Literal::U8(self.cast_lossy_integer()?)),
+            LiteralType::U16 => Ok(Literal::U16(self.cast_lossy_integer()?)),
+            LiteralType::U32 => Ok(Literal::U32(self.cast_lossy_integer()?)),
+            LiteralType::U64 => Ok(Literal::U64(self.cast_lossy_integer()?)),
+            LiteralType::U128 => Ok(Literal::U128(self.cast_lossy_integer()?)),
+            LiteralType::Scalar => {
+                // Truncate the base field representation to the scalar field data bits.
+                let bits_le = self.to_cast_bits_le(Scalar::<N>::size_in_data_bits())?;
+                Ok(Literal::Scalar(Scalar::from_bits_le(&bits_le)?))
+            }
+            LiteralType::String => bail!("Cannot cast '{self}' to a string"),
+            LiteralType::Signature => bail!("Cannot cast '{self}' to a signature"),
+        }
+    }
+}
+
+impl<N: Network> Literal<N> {
+    /// Returns the base field representation of the literal, for casting.
+    /// Note: Signed integers are mapped to their (possibly negative) base field value.
+    fn to_cast_field(&self) -> Result<Field<N>> {
+        match self {
+            Self::Address(literal) => literal.to_field(),
+            Self::Boolean(literal) => Ok(Field::from_u64(**literal as u64)),
+            Self::Field(literal) => Ok(*literal),
+            Self::Group(literal) => Ok(literal.to_x_coordinate()),
+            Self::I8(literal) => integer_to_cast_field(literal),
+            Self::I16(literal) => integer_to_cast_field(literal),
+            Self::I32(literal) => integer_to_cast_field(literal),
+            Self::I64(literal) => integer_to_cast_field(literal),
+            Self::I128(literal) => integer_to_cast_field(literal),
+            Self::U8(literal) => integer_to_cast_field(literal),
+            Self::U16(literal) => integer_to_cast_field(literal),
+            Self::U32(literal) => integer_to_cast_field(literal),
+            Self::U64(literal) => integer_to_cast_field(literal),
+            Self::U128(literal) => integer_to_cast_field(literal),
+            Self::Scalar(literal) => literal.to_field(),
+            Self::String(..) => bail!("Cannot cast a string literal"),
+            Self::Signature(..) => bail!("Cannot cast a signature literal"),
+        }
+    }
+
+    /// Returns the first `num_bits` little-endian bits of the literal, for casting.
+    /// Integers are sign-extended (or zero-extended), and all other literals are zero-extended.
+    fn to_cast_bits_le(&self, num_bits: usize) -> Result<Vec<bool>> {
+        let (mut bits_le, sign) = match self {
+            Self::Boolean(literal) => (vec![**literal], false),
+            Self::I8(literal) => integer_to_cast_bits_le(literal),
+            Self::I16(literal) => integer_to_cast_bits_le(literal),
+            Self::I32(literal) => integer_to_cast_bits_le(literal),
+            Self::I64(literal) => integer_to_cast_bits_le(literal),
+            Self::I128(literal) => integer_to_cast_bits_le(literal),
+            Self::U8(literal) => integer_to_cast_bits_le(literal),
+            Self::U16(literal) => integer_to_cast_bits_le(literal),
+            Self::U32(literal) => integer_to_cast_bits_le(literal),
+            Self::U64(literal) => integer_to_cast_bits_le(literal),
+            Self::U128(literal) => integer_to_cast_bits_le(literal),
+            _ => (self.to_cast_field()?.to_bits_le(), false),
+        };
+        // Extend (or truncate) the bits to the given size.
+        bits_le.resize(num_bits, sign);
+        Ok(bits_le)
+    }
+
+    /// Casts the literal into an integer, truncating the value if it does not fit.
+    fn cast_lossy_integer<I: IntegerType>(&self) -> Result<Integer<N, I>> {
+        Integer::from_bits_le(&self.to_cast_bits_le(I::BITS as usize)?)
+    }
+}
+
+/// Returns the base field representation of the integer, mapping negative values to negative field elements.
+fn integer_to_cast_field<N: Network, I: IntegerType>(integer: &Integer<N, I>) -> Result<Field<N>> {
+    let bits_le = integer.to_bits_le();
+    match I::is_signed() {
+        // Compute the field as `sum(bits_le[0..n-1]) - msb * 2^(n-1)`.
+        true => {
+            let (msb, bits_le) = bits_le.split_last().ok_or_else(|| anyhow!("Malformed integer"))?;
+            let magnitude = Field::from_bits_le(bits_le)?;
+            match msb {
+                true => Ok(magnitude - Field::from_u128(1u128 << (I::BITS - 1))),
+                false => Ok(magnitude),
+            }
+        }
+        false => Field::from_bits_le(&bits_le),
+    }
+}
+
+/// Returns the little-endian bits of the integer, along with the bit used to extend it.
+fn integer_to_cast_bits_le<N: Network, I: IntegerType>(integer: &Integer<N, I>) -> (Vec<bool>, bool) {
+    let bits_le = integer.to_bits_le();
+    let sign = I::is_signed() && bits_le.last().copied().unwrap_or(false);
+    (bits_le, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn check_cast(input: &str, to_type: LiteralType, expected: Option<&str>) {
+        let literal = Literal::<CurrentNetwork>::from_str(input).unwrap();
+        match expected {
+            Some(expected) => assert_eq!(literal.cast(to_type).unwrap().to_string(), expected),
+            None => assert!(literal.cast(to_type).is_err(), "Cast of '{input}' to '{to_type}' should fail"),
+        }
+    }
+
+    fn check_cast_lossy(input: &str, to_type: LiteralType, expected: &str) {
+        let literal = Literal::<CurrentNetwork>::from_str(input).unwrap();
+        assert_eq!(literal.cast_lossy(to_type).unwrap().to_string(), expected);
+    }
+
+    #[test]
+    fn test_cast_integers() {
+        check_cast("255u8", LiteralType::U64, Some("255u64"));
+        check_cast("255u64", LiteralType::U8, Some("255u8"));
+        check_cast("256u64", LiteralType::U8, None);
+        check_cast("-1i8", LiteralType::I64, Some("-1i64"));
+        check_cast("-1i8", LiteralType::U8, None);
+        check_cast("127u8", LiteralType::I8, Some("127i8"));
+        check_cast("128u8", LiteralType::I8, None);
+        check_cast("-128i16", LiteralType::I8, Some("-128i8"));
+        check_cast("-129i16", LiteralType::I8, None);
+    }
+
+    #[test]
+    fn test_cast_fields() {
+        check_cast(
+            "340282366920938463463374607431768211455u128",
+            LiteralType::Field,
+            Some("340282366920938463463374607431768211455field"),
+        );
+        check_cast(
+            "340282366920938463463374607431768211455field",
+            LiteralType::U128,
+            Some("340282366920938463463374607431768211455u128"),
+        );
+        check_cast("340282366920938463463374607431768211456field", LiteralType::U128, None);
+        check_cast("-1i8", LiteralType::Field, Some(&(-Field::<CurrentNetwork>::one()).to_string()));
+        check_cast(&(-Field::<CurrentNetwork>::one()).to_string(), LiteralType::I8, None);
+        check_cast("true", LiteralType::U8, Some("1u8"));
+        check_cast("1field", LiteralType::Boolean, Some("true"));
+        check_cast("2field", LiteralType::Boolean, None);
+        check_cast("7scalar", LiteralType::Field, Some("7field"));
+        check_cast("7field", LiteralType::Scalar, Some("7scalar"));
+        check_cast("\"hello\"", LiteralType::Field, None);
+        check_cast("1u8", LiteralType::String, None);
+    }
+
+    #[test]
+    fn test_cast_address() {
+        let mut rng = TestRng::default();
+        let address = Address::<CurrentNetwork>::new(Uniform::rand(&mut rng));
+        let field = address.to_field().unwrap();
+        check_cast(&address.to_string(), LiteralType::Field, Some(&field.to_string()));
+        check_cast(&field.to_string(), LiteralType::Address, Some(&address.to_string()));
+        check_cast(&address.to_string(), LiteralType::Group, Some(&(*address).to_string()));
+        check_cast(&address.to_string(), LiteralType::U8, None);
+    }
+
+    #[test]
+    fn test_cast_lossy() {
+        check_cast_lossy("256u64", LiteralType::U8, "0u8");
+        check_cast_lossy("511u16", LiteralType::U8, "255u8");
+        check_cast_lossy("255u8", LiteralType::I8, "-1i8");
+        check_cast_lossy("-1i8", LiteralType::U16, "65535u16");
+        check_cast_lossy("-1i8", LiteralType::I128, "-1i128");
+        check_cast_lossy("3u8", LiteralType::Boolean, "true");
+        check_cast_lossy("2field", LiteralType::Boolean, "false");
+        check_cast_lossy("340282366920938463463374607431768211457field", LiteralType::U128, "1u128");
+    }
+}
@@ -84,4 +84,23 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_serde_signature() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Sample a new signature literal.
+            let expected = Literal::<CurrentNetwork>::sample(LiteralType::Signature, &mut rng);
+
+            // Serialize and deserialize as JSON.
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+            // Serialize and deserialize as bytes.
+            let candidate_bytes = bincode::serialize(&expected)?;
+            assert_eq!(expected, bincode::deserialize(&candidate_bytes[..])?);
+        }
+        Ok(())
+    }
 }
@@ -62,6 +62,28 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default())))
         }
 
+        /// Parses a plaintext as an array: `[plaintext_0, ..., plaintext_n]`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, elements) = map_res(separated_list1(tag(","), Plaintext::parse), |elements: Vec<_>| {
+                // Ensure the number of elements is within `N::MAX_DATA_ENTRIES`.
+                match elements.len() <= N::MAX_DATA_ENTRIES {
+                    true => Ok(elements),
+                    false => Err(error(format!("Found a plaintext that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Array(elements, Default::default())))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -70,6 +92,8 @@ impl<N: Network> Parser for Plaintext<N> {
             map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
             // Parse a plaintext struct.
             parse_struct,
+            // Parse a plaintext array.
+            parse_array,
         ))(string)
     }
 }
@@ -120,32 +144,51 @@ impl<N: Network> Plaintext<N> {
                 write!(f, "{{")?;
                 // Print the members.
                 struct_.iter().enumerate().try_for_each(|(i, (name, plaintext))| {
+                    // Print the member name.
+                    write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
+                    // Print the member.
                     match plaintext {
-                        Self::Literal(literal, ..) => match i == struct_.len() - 1 {
-                            true => {
-                                // Print the last member without a comma.
-                                write!(f, "\n{:indent$}{name}: {literal}", "", indent = (depth + 1) * INDENT)?;
-                                // Print the closing brace.
-                                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
-                            }
-                            // Print the member with a comma.
-                            false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
-                        },
-                        Self::Struct(..) => {
-                            // Print the member name.
-                            write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
-                            // Print the member.
-                            plaintext.fmt_internal(f, depth + 1)?;
-                            // Print the closing brace.
-                            match i == struct_.len() - 1 {
-                                // Print the last member without a comma.
-                                true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
-                                // Print the member with a comma.
-                                false => write!(f, "\n{:indent$}}},", "", indent = depth * INDENT),
-                            }
-                        }
+                        Self::Literal(literal, ..) => write!(f, "{literal}")?,
+                        Self::Struct(..) | Self::Array(..) => plaintext.fmt_internal(f, depth + 1)?,
+                    }
+                    // Print the comma, if this is not the last member.
+                    match i == struct_.len() - 1 {
+                        true => Ok(()),
+                        false => write!(f, ","),
                     }
-                })
+                })?;
+                // Print the closing brace.
+                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+            }
+            // Prints the array, i.e. [10u64, 198u64]
+            Self::Array(elements, ..) => {
+                // If the elements are all literals, print the array on a single line.
+                if elements.iter().all(|element| matches!(element, Self::Literal(..))) {
+                    write!(f, "[")?;
+                    elements.iter().enumerate().try_for_each(|(i, element)| match i == elements.len() - 1 {
+                        true => write!(f, "{element}"),
+                        false => write!(f, "{element}, "),
+                    })?;
+                    return write!(f, "]");
+                }
+                // Otherwise, print each element on its own line.
+                write!(f, "[")?;
+                elements.iter().enumerate().try_for_each(|(i, element)| {
+                    // Print the indentation.
+                    write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                    // Print the element.
+                    match element {
+                        Self::Literal(literal, ..) => write!(f, "{literal}")?,
+                        Self::Struct(..) | Self::Array(..) => element.fmt_internal(f, depth + 1)?,
+                    }
+                    // Print the comma, if this is not the last element.
+                    match i == elements.len() - 1 {
+                        true => Ok(()),
+                        false => write!(f, ","),
+                    }
+                })?;
+                // Print the closing bracket.
+                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
             }
         }
     }
@@ -213,11 +256,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_struct_with_sibling() -> Result<()> {
+        let expected = r"{
+  foo: {
+    bar: 5u8
+  },
+  baz: 10field
+}";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("{ foo: { bar: 5u8 }, baz: 10field }")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("[1u8,2u8, 3u8 ]")?;
+        assert_eq!("[1u8, 2u8, 3u8]", candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [5u8, 6u8],
+  bar: [
+    {
+      baz: 10field
+    },
+    {
+      baz: 11field
+    }
+  ]
+}";
+        let (remainder, candidate) =
+            Plaintext::<CurrentNetwork>::parse("{ foo: [5u8, 6u8], bar: [{ baz: 10field }, { baz: 11field }] }")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure the display output can be parsed back.
+        assert_eq!(candidate, Plaintext::<CurrentNetwork>::from_str(expected)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
         assert!(Plaintext::<CurrentNetwork>::parse("").is_err());
         assert!(Plaintext::<CurrentNetwork>::parse("{}").is_err());
+        assert!(Plaintext::<CurrentNetwork>::parse("[]").is_err());
 
         // Invalid characters.
         assert!(Plaintext::<CurrentNetwork>::parse("_").is_err());
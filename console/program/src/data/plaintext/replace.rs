@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns a copy of the plaintext, with the member at the given path replaced by the given value.
+    pub fn replace(&self, path: &[Access<N>], value: Plaintext<N>) -> Result<Plaintext<N>> {
+        // Ensure the path is not empty.
+        ensure!(!path.is_empty(), "Attempted to replace member with an empty path.");
+        // Replace the member.
+        self.replace_internal(path, value)
+    }
+
+    /// Returns a copy of the plaintext, with the member at the given (possibly empty) path replaced by the given value.
+    fn replace_internal(&self, path: &[Access<N>], value: Plaintext<N>) -> Result<Plaintext<N>> {
+        // Retrieve the next access in the path, or return the value if the path is exhausted.
+        let (access, remaining) = match path.split_first() {
+            Some((access, remaining)) => (access, remaining),
+            None => return Ok(value),
+        };
+
+        match (self, access) {
+            // Replace the member of the struct.
+            (Self::Struct(members, ..), Access::Member(identifier)) => {
+                let mut members = members.clone();
+                match members.get_mut(identifier) {
+                    Some(member) => *member = member.replace_internal(remaining, value)?,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}' in '{self}'"),
+                }
+                Ok(Self::Struct(members, Default::default()))
+            }
+            // Replace the element of the array.
+            (Self::Array(elements, ..), Access::Index(index)) => {
+                let mut elements = elements.clone();
+                match elements.get_mut(**index as usize) {
+                    Some(element) => *element = element.replace_internal(remaining, value)?,
+                    // Halts if the index is out of bounds.
+                    None => bail!("Index '{index}' is out of bounds in '{self}'"),
+                }
+                Ok(Self::Array(elements, Default::default()))
+            }
+            // Halts if the access does not match the plaintext.
+            (Self::Struct(..), Access::Index(index)) => bail!("Cannot index into a struct with '{index}'"),
+            (Self::Array(..), Access::Member(identifier)) => bail!("Cannot access member '{identifier}' of an array"),
+            (Self::Literal(..), _) => bail!("Cannot apply '{access}' to the literal '{self}'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_replace() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: [1u8, 2u8], b: { c: true, d: 5u64 } }")?;
+
+        // Check a member replacement.
+        let path = [Access::from_str(".b")?, Access::from_str(".d")?];
+        let candidate = plaintext.replace(&path, Plaintext::from_str("7u64")?)?;
+        assert_eq!(candidate, Plaintext::from_str("{ a: [1u8, 2u8], b: { c: true, d: 7u64 } }")?);
+        assert_eq!(candidate.find(&path)?, Plaintext::from_str("7u64")?);
+
+        // Check an index replacement.
+        let path = [Access::from_str(".a")?, Access::from_str("[1u32]")?];
+        let candidate = plaintext.replace(&path, Plaintext::from_str("3u8")?)?;
+        assert_eq!(candidate, Plaintext::from_str("{ a: [1u8, 3u8], b: { c: true, d: 5u64 } }")?);
+
+        // Ensure the bits are recomputed for the replaced plaintext.
+        assert_ne!(plaintext.to_bits_le(), candidate.to_bits_le());
+
+        // Ensure invalid paths fail.
+        assert!(plaintext.replace(&[Access::from_str(".e")?], Plaintext::from_str("1u8")?).is_err());
+        assert!(
+            plaintext
+                .replace(&[Access::from_str(".a")?, Access::from_str("[2u32]")?], Plaintext::from_str("1u8")?)
+                .is_err()
+        );
+        assert!(plaintext.replace(&[], Plaintext::from_str("1u8")?).is_err());
+        Ok(())
+    }
+}
@@ -55,6 +55,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_literal,
                 // Parse a struct.
                 parse_struct,
+                // Parse an array.
+                parse_array,
             ))(string)?;
             // Return the identifier, plaintext, and visibility.
             Ok((string, (identifier, plaintext, mode)))
@@ -96,6 +98,44 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             Ok((string, (Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default()), mode)))
         }
 
+        /// Parses an entry as an array: `[plaintext_0.visibility, ..., plaintext_n.visibility]`.
+        /// Observe the `visibility` is the same for all elements of the plaintext value.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            /// Parses a sanitized element: `entry`.
+            fn parse_element<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+                // Parse the whitespace and comments from the string.
+                let (string, _) = Sanitizer::parse(string)?;
+                // Parse the plaintext and visibility from the string.
+                alt((parse_literal, parse_struct, parse_array))(string)
+            }
+
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, (elements, mode)) = map_res(separated_list1(tag(","), parse_element), |elements: Vec<_>| {
+                // Ensure the elements all have the same visibility.
+                let mode = elements.iter().map(|(_, mode)| mode).dedup().collect::<Vec<_>>();
+                let mode = match mode.len() == 1 {
+                    true => *mode[0],
+                    false => return Err(error("Elements of array in entry have different visibilities")),
+                };
+                // Ensure the number of elements is within `N::MAX_DATA_ENTRIES`.
+                match elements.len() <= N::MAX_DATA_ENTRIES {
+                    // Return the elements and the visibility.
+                    true => Ok((elements.into_iter().map(|(p, _)| p).collect::<Vec<_>>(), mode)),
+                    false => Err(error(format!("Found a plaintext that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext and visibility.
+            Ok((string, (Plaintext::Array(elements, Default::default()), mode)))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the entry (order matters).
@@ -104,6 +144,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_literal,
             // Parse a struct.
             parse_struct,
+            // Parse an array.
+            parse_array,
         ))(string)?;
 
         // Return the entry.
@@ -169,40 +211,71 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                 write!(f, "{{")?;
                 // Print the members.
                 struct_.iter().enumerate().try_for_each(|(i, (name, plaintext))| {
-                    match plaintext {
-                        #[rustfmt::skip]
-                        Plaintext::Literal(literal, ..) => match i == struct_.len() - 1 {
-                            true => {
-                                // Print the last member without a comma.
-                                write!(f, "\n{:indent$}{name}: {literal}.{visibility}", "", indent = (depth + 1) * INDENT)?;
-                                // Print the closing brace.
-                                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
-                            }
-                            // Print the member with a comma.
-                            false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
-                        },
-                        Plaintext::Struct(..) => {
-                            // Print the member name.
-                            write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
-                            // Print the member.
-                            match self {
-                                Self::Constant(..) => Self::Constant(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                                Self::Public(..) => Self::Public(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                                Self::Private(..) => Self::Private(plaintext.clone()).fmt_internal(f, depth + 1)?,
-                            }
-                            // Print the closing brace.
-                            match i == struct_.len() - 1 {
-                                // Print the last member without a comma.
-                                true => write!(f, "\n{:indent$}}}", "", indent = depth * INDENT),
-                                // Print the member with a comma.
-                                false => write!(f, "\n{:indent$}}},", "", indent = depth * INDENT),
-                            }
+                    // Print the member name.
+                    write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
+                    // Print the member.
+                    self.fmt_child(f, plaintext, depth + 1)?;
+                    // Print the comma, if this is not the last member.
+                    match i == struct_.len() - 1 {
+                        true => Ok(()),
+                        false => write!(f, ","),
+                    }
+                })?;
+                // Print the closing brace.
+                write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
+            }
+            // Prints the array, i.e. [10u64.private, 198u64.private]
+            Plaintext::Array(elements, ..) => {
+                // If the elements are all literals, print the array on a single line.
+                if elements.iter().all(|element| matches!(element, Plaintext::Literal(..))) {
+                    write!(f, "[")?;
+                    elements.iter().enumerate().try_for_each(|(i, element)| {
+                        // Print the element.
+                        self.fmt_child(f, element, depth)?;
+                        // Print the separator, if this is not the last element.
+                        match i == elements.len() - 1 {
+                            true => Ok(()),
+                            false => write!(f, ", "),
                         }
+                    })?;
+                    return write!(f, "]");
+                }
+                // Otherwise, print each element on its own line.
+                write!(f, "[")?;
+                elements.iter().enumerate().try_for_each(|(i, element)| {
+                    // Print the indentation.
+                    write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                    // Print the element.
+                    self.fmt_child(f, element, depth + 1)?;
+                    // Print the comma, if this is not the last element.
+                    match i == elements.len() - 1 {
+                        true => Ok(()),
+                        false => write!(f, ","),
                     }
-                })
+                })?;
+                // Print the closing bracket.
+                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
             }
         }
     }
+
+    /// Prints the given child plaintext with the visibility of this entry.
+    fn fmt_child(&self, f: &mut Formatter, plaintext: &Plaintext<N>, depth: usize) -> fmt::Result {
+        match plaintext {
+            // Prints the literal without indentation, i.e. 10field.public
+            Plaintext::Literal(..) => match self {
+                Self::Constant(..) => Self::Constant(plaintext.clone()).fmt_internal(f, 0),
+                Self::Public(..) => Self::Public(plaintext.clone()).fmt_internal(f, 0),
+                Self::Private(..) => Self::Private(plaintext.clone()).fmt_internal(f, 0),
+            },
+            // Prints the struct or array with the given depth.
+            Plaintext::Struct(..) | Plaintext::Array(..) => match self {
+                Self::Constant(..) => Self::Constant(plaintext.clone()).fmt_internal(f, depth),
+                Self::Public(..) => Self::Public(plaintext.clone()).fmt_internal(f, depth),
+                Self::Private(..) => Self::Private(plaintext.clone()).fmt_internal(f, depth),
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -254,6 +327,37 @@ mod tests {
         assert_eq!(expected, candidate.to_string());
         assert_eq!("", remainder);
 
+        Ok(())
+    }
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let (remainder, candidate) =
+            Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("[1u8.private, 2u8.private]")?;
+        assert_eq!("[1u8.private, 2u8.private]", candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [5u8.public, 6u8.public],
+  bar: [
+    {
+      baz: 10field.public
+    },
+    {
+      baz: 11field.public
+    }
+  ],
+  qux: true.public
+}";
+        let (remainder, candidate) = Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(
+            "{ foo: [5u8.public, 6u8.public], bar: [{ baz: 10field.public }, { baz: 11field.public }], qux: true.public }",
+        )?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure mismatched visibilities fail.
+        assert!(Entry::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse("[1u8.private, 2u8.public]").is_err());
+
         Ok(())
     }
 }
@@ -23,17 +23,42 @@ impl<N: Network> FromBytes for Register<N> {
         let locator = read_variable_length_integer(&mut reader)?;
         match variant {
             0 => Ok(Self::Locator(locator)),
+            // Note: This variant is an access path with only members, which is encoded without the access variants.
             1 => {
                 // Read the number of identifiers.
                 let num_identifiers = u16::read_le(&mut reader)?;
+                // Ensure the number of identifiers is within `N::MAX_DATA_DEPTH`.
+                if num_identifiers as usize > N::MAX_DATA_DEPTH {
+                    return Err(error(format!(
+                        "Failed to deserialize register: too many identifiers ({num_identifiers})"
+                    )));
+                }
                 // Read the identifiers.
-                let mut identifiers = Vec::with_capacity(num_identifiers as usize);
+                let mut accesses = Vec::with_capacity(num_identifiers as usize);
                 for _ in 0..num_identifiers {
-                    identifiers.push(Identifier::read_le(&mut reader)?);
+                    accesses.push(Access::Member(Identifier::read_le(&mut reader)?));
+                }
+                Ok(Self::Member(locator, accesses))
+            }
+            2 => {
+                // Read the number of accesses.
+                let num_accesses = u16::read_le(&mut reader)?;
+                // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+                if num_accesses as usize > N::MAX_DATA_DEPTH {
+                    return Err(error(format!("Failed to deserialize register: too many accesses ({num_accesses})")));
+                }
+                // Read the accesses.
+                let mut accesses = Vec::with_capacity(num_accesses as usize);
+                for _ in 0..num_accesses {
+                    accesses.push(Access::read_le(&mut reader)?);
+                }
+                // Ensure the access path contains an index, as an access path with only members uses variant 1.
+                if !accesses.iter().any(|access| matches!(access, Access::Index(..))) {
+                    return Err(error("Failed to deserialize register: expected an index in the access path"));
                 }
-                Ok(Self::Member(locator, identifiers))
+                Ok(Self::Member(locator, accesses))
             }
-            2.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
+            3.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
         }
     }
 }
@@ -46,19 +71,90 @@ impl<N: Network> ToBytes for Register<N> {
                 u8::write_le(&0u8, &mut writer)?;
                 variable_length_integer(locator).write_le(&mut writer)
             }
-            Self::Member(locator, identifiers) => {
-                // Ensure the number of identifiers is within `N::MAX_DATA_DEPTH`.
-                if identifiers.len() > N::MAX_DATA_DEPTH {
-                    return Err(error("Failed to serialize register: too many identifiers"));
+            Self::Member(locator, accesses) => {
+                // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+                if accesses.len() > N::MAX_DATA_DEPTH {
+                    return Err(error("Failed to serialize register: too many accesses"));
                 }
 
-                u8::write_le(&1u8, &mut writer)?;
-                variable_length_integer(locator).write_le(&mut writer)?;
-                u16::try_from(identifiers.len())
-                    .or_halt_with::<N>("Register path length exceeds u16::MAX")
-                    .write_le(&mut writer)?;
-                identifiers.write_le(&mut writer)
+                // Retrieve the identifiers, if the access path only contains members.
+                let identifiers = accesses
+                    .iter()
+                    .map(|access| match access {
+                        Access::Member(identifier) => Some(identifier),
+                        Access::Index(..) => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+
+                match identifiers {
+                    // Note: An access path with only members is encoded without the access variants,
+                    // to preserve the encoding of registers that predate array indices.
+                    Some(identifiers) => {
+                        u8::write_le(&1u8, &mut writer)?;
+                        variable_length_integer(locator).write_le(&mut writer)?;
+                        u16::try_from(identifiers.len())
+                            .or_halt_with::<N>("Register path length exceeds u16::MAX")
+                            .write_le(&mut writer)?;
+                        for identifier in identifiers {
+                            identifier.write_le(&mut writer)?;
+                        }
+                        Ok(())
+                    }
+                    None => {
+                        u8::write_le(&2u8, &mut writer)?;
+                        variable_length_integer(locator).write_le(&mut writer)?;
+                        u16::try_from(accesses.len())
+                            .or_halt_with::<N>("Register path length exceeds u16::MAX")
+                            .write_le(&mut writer)?;
+                        accesses.write_le(&mut writer)
+                    }
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["r0", "r1.owner", "r2.owner.gates", "r3[0u32]", "r4.owner[1u32].gates"] {
+            let expected = Register::<CurrentNetwork>::from_str(string)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Register::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_member_bytes_are_unchanged() -> Result<()> {
+        // Prepare the encoding of a register with only members, which predates array indices.
+        let identifiers = [Identifier::<CurrentNetwork>::from_str("owner")?, Identifier::from_str("gates")?];
+        let mut expected_bytes = vec![1u8];
+        expected_bytes.extend(variable_length_integer(&2));
+        expected_bytes.extend(2u16.to_bytes_le()?);
+        for identifier in &identifiers {
+            expected_bytes.extend(identifier.to_bytes_le()?);
+        }
+
+        // Ensure the register is encoded as before.
+        let register = Register::<CurrentNetwork>::from_str("r2.owner.gates")?;
+        assert_eq!(expected_bytes, register.to_bytes_le()?);
+        assert_eq!(register, Register::read_le(&expected_bytes[..])?);
+
+        // Ensure an access path with only members is rejected in the index variant.
+        let mut bytes = vec![2u8];
+        bytes.extend(variable_length_integer(&2));
+        bytes.extend(2u16.to_bytes_le()?);
+        for identifier in identifiers {
+            bytes.extend(Access::Member(identifier).to_bytes_le()?);
+        }
+        assert!(Register::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{Identifier, LiteralType, PlaintextType};
+use snarkvm_console_network::prelude::*;
+
+/// An `ArrayType` defines the element type and the (fixed) number of elements in an array.
+/// The element type is a literal type or a struct type; nested arrays must be declared as struct members.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The element type of the array.
+    element_type: ElementType<N>,
+    /// The number of elements in the array.
+    length: u32,
+}
+
+/// The element type of an array.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum ElementType<N: Network> {
+    /// A literal type.
+    Literal(LiteralType),
+    /// A struct type.
+    Struct(Identifier<N>),
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type from the given element type and length.
+    pub fn new(element_type: PlaintextType<N>, length: u32) -> Result<Self> {
+        // Ensure the element type is not an array.
+        let element_type = match element_type {
+            PlaintextType::Literal(literal_type) => ElementType::Literal(literal_type),
+            PlaintextType::Struct(identifier) => ElementType::Struct(identifier),
+            PlaintextType::Array(..) => bail!("The element type of an array cannot be an array"),
+        };
+        // Ensure the array is not empty.
+        ensure!(length > 0, "An array must contain at least one element");
+        // Ensure the number of elements is within `N::MAX_DATA_ENTRIES`.
+        ensure!(length as usize <= N::MAX_DATA_ENTRIES, "An array cannot exceed {} elements", N::MAX_DATA_ENTRIES);
+        // Return the array type.
+        Ok(Self { element_type, length })
+    }
+
+    /// Returns the element type of the array.
+    #[inline]
+    pub const fn element_type(&self) -> PlaintextType<N> {
+        match self.element_type {
+            ElementType::Literal(literal_type) => PlaintextType::Literal(literal_type),
+            ElementType::Struct(identifier) => PlaintextType::Struct(identifier),
+        }
+    }
+
+    /// Returns the number of elements in the array.
+    #[inline]
+    pub const fn length(&self) -> u32 {
+        self.length
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for ArrayType<N> {
+    /// Parses a string into an array type.
+    /// The array type is of the form `[{element_type}; {length}]`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the "[" from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the element type from the string (order matters).
+        let (string, element_type) = alt((
+            map(LiteralType::parse, |type_| PlaintextType::Literal(type_)),
+            map(Identifier::parse, |identifier| PlaintextType::Struct(identifier)),
+        ))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the length from the string.
+        let (string, length) =
+            map_res(recognize(many1(one_of("0123456789"))), |length: &str| length.parse::<u32>())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "]" from the string.
+        let (string, _) = tag("]")(string)?;
+        // Construct the array type.
+        match ArrayType::new(element_type, length) {
+            Ok(array_type) => Ok((string, array_type)),
+            Err(e) => map_res(fail, |_: ParserResult<Self>| Err(error(e.to_string())))(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for ArrayType<N> {
+    type Err = Error;
+
+    /// Returns an array type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArrayType<N> {
+    /// Prints the array type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArrayType<N> {
+    /// Prints the array type as a string, i.e. `[u64; 8]`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[{}; {}]", self.element_type(), self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let array_type = ArrayType::<CurrentNetwork>::from_str("[u64; 8]")?;
+        assert_eq!(array_type.element_type(), PlaintextType::Literal(LiteralType::U64));
+        assert_eq!(array_type.length(), 8);
+
+        let array_type = ArrayType::<CurrentNetwork>::from_str("[token;2]")?;
+        assert_eq!(array_type.element_type(), PlaintextType::Struct(Identifier::from_str("token")?));
+        assert_eq!(array_type.length(), 2);
+
+        let (remainder, _) = ArrayType::<CurrentNetwork>::parse("[field; 1].private")?;
+        assert_eq!(remainder, ".private");
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        // Must be non-empty.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 0]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[]").is_err());
+        // Must be within `MAX_DATA_ENTRIES`.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 33]").is_err());
+        // Must not directly nest arrays.
+        assert!(ArrayType::<CurrentNetwork>::parse("[[u64; 2]; 2]").is_err());
+        // Must have a well-formed length.
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; 2u32]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64; -1]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u64]").is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[u64;8]")?.to_string(), "[u64; 8]");
+        assert_eq!(ArrayType::<CurrentNetwork>::from_str("[ token ; 32 ]")?.to_string(), "[token; 32]");
+        Ok(())
+    }
+}
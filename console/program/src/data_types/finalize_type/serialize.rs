@@ -63,8 +63,8 @@ mod tests {
         "u128",
         "scalar",
         "string",
-        // Struct
         "signature",
+        // Struct
         "message",
         "item",
         "passport",
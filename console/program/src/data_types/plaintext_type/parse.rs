@@ -22,6 +22,7 @@ impl<N: Network> Parser for PlaintextType<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the plaintext type (order matters).
         alt((
+            map(ArrayType::parse, |type_| Self::Array(type_)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
         ))(string)
@@ -60,6 +61,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Literal(literal) => Display::fmt(literal, f),
             // Prints the struct, i.e. signature
             Self::Struct(struct_) => Display::fmt(struct_, f),
+            // Prints the array, i.e. [u64; 8]
+            Self::Array(array) => Display::fmt(array, f),
         }
     }
 }
@@ -78,8 +81,12 @@ mod tests {
             Ok(("", PlaintextType::<CurrentNetwork>::Literal(LiteralType::Field)))
         );
         assert_eq!(
-            PlaintextType::parse("signature"),
-            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?)))
+            PlaintextType::parse("message"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("message")?)))
+        );
+        assert_eq!(
+            PlaintextType::parse("[u64; 8]"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[u64; 8]")?)))
         );
         Ok(())
     }
@@ -149,10 +156,8 @@ mod tests {
     #[test]
     fn test_display() -> Result<()> {
         assert_eq!(PlaintextType::<CurrentNetwork>::Literal(LiteralType::Field).to_string(), "field");
-        assert_eq!(
-            PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?).to_string(),
-            "signature"
-        );
+        assert_eq!(PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("message")?).to_string(), "message");
+        assert_eq!(PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[u64;8]")?).to_string(), "[u64; 8]");
         Ok(())
     }
 }
@@ -48,7 +48,7 @@ const NUM_POWERS_27: usize = 1 << 27;
 const NUM_POWERS_28: usize = 1 << 28;
 
 /// The maximum degree supported by the SRS.
-const MAX_NUM_POWERS: usize = NUM_POWERS_28;
+pub const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
 lazy_static::lazy_static! {
     static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
@@ -94,6 +94,35 @@ impl<E: PairingEngine> PowersOfG<E> {
         Ok(powers)
     }
 
+    /// Initializes a new instance of the powers from the given components.
+    /// This is intended for a locally-sampled SRS, which must contain all of the powers it will
+    /// be used with, as the remaining powers can not be downloaded for it.
+    pub fn from_parts(
+        powers_of_beta_g: Vec<E::G1Affine>,
+        shifted_powers_of_beta_g: Vec<E::G1Affine>,
+        powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
+        negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+        beta_h: E::G2Affine,
+    ) -> Result<Self> {
+        // Ensure the number of powers is a power of two, as the remaining powers are fetched in powers of two.
+        ensure!(powers_of_beta_g.len().is_power_of_two(), "The number of powers in the SRS must be a power of two");
+        // Ensure the shifted powers are present, and do not overlap with the powers.
+        ensure!(!shifted_powers_of_beta_g.is_empty(), "The shifted powers in the SRS must not be empty");
+        ensure!(
+            powers_of_beta_g.len() + shifted_powers_of_beta_g.len() <= MAX_NUM_POWERS,
+            "Incorrect number of powers in the SRS"
+        );
+
+        // Initialize the powers.
+        let powers_of_beta_g = PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g };
+        Ok(Self {
+            powers_of_beta_g,
+            powers_of_beta_times_gamma_g: Arc::new(powers_of_beta_times_gamma_g),
+            negative_powers_of_beta_h: Arc::new(negative_powers_of_beta_h),
+            beta_h,
+        })
+    }
+
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&mut self, range: Range<usize>) -> Result<()> {
         self.powers_of_beta_g.download_powers_for(&range)
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use thiserror::Error;
+
+/// An error raised when a block is not a valid successor of the current chain tip.
+#[derive(Debug, Error)]
+pub enum BlockError {
+    #[error("Invalid network ID for block {height}: expected {expected}, found {found}")]
+    InvalidNetworkID { height: u32, expected: u16, found: u16 },
+    #[error("Invalid height: expected {expected}, found {found}")]
+    InvalidHeight { expected: u32, found: u32 },
+    #[error("Invalid previous hash for block {height}: expected '{expected}', found '{found}'")]
+    InvalidPreviousHash { height: u32, expected: String, found: String },
+    #[error("Invalid round for block {height}: round {found} does not follow round {previous}")]
+    InvalidRound { height: u32, previous: u64, found: u64 },
+    #[error("Invalid timestamp for block {height}: timestamp {found} does not follow timestamp {previous}")]
+    InvalidTimestamp { height: u32, previous: i64, found: i64 },
+    #[error("Invalid previous state root for block {height}: expected '{expected}', found '{found}'")]
+    InvalidPreviousStateRoot { height: u32, expected: String, found: String },
+    #[error("Invalid transactions root for block {height}")]
+    InvalidTransactionsRoot { height: u32 },
+    #[error("Invalid block hash for block {height}")]
+    InvalidBlockHash { height: u32 },
+    #[error("Invalid signature for block {height}")]
+    InvalidSignature { height: u32 },
+    #[error("Invalid header for block {height}")]
+    InvalidHeader { height: u32 },
+    #[error("Invalid coinbase targets for block {height}: {reason}")]
+    InvalidTargets { height: u32, reason: String },
+    #[error("Invalid coinbase solution for block {height}: {reason}")]
+    InvalidCoinbaseSolution { height: u32, reason: String },
+    #[error("Invalid genesis block: {reason}")]
+    InvalidGenesis { reason: String },
+    #[error("Invalid transaction '{transaction_id}' in block {height}: {reason}")]
+    InvalidTransaction { height: u32, transaction_id: String, reason: String },
+    #[error("Failed to finalize block {height}: {error}")]
+    FinalizeFailed { height: u32, error: anyhow::Error },
+    #[error("{0}")]
+    AnyhowError(#[from] anyhow::Error),
+}
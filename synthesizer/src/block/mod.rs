@@ -26,13 +26,18 @@ pub use transactions::*;
 pub mod transition;
 pub use transition::*;
 
+mod error;
+pub use error::*;
+
 mod bytes;
 mod genesis;
 mod serialize;
 mod string;
+mod verify;
 
 use crate::{
     coinbase_puzzle::{CoinbaseSolution, PuzzleCommitment},
+    consensus::{coinbase_reward, staking_reward},
     process::{Deployment, Execution},
     vm::VM,
 };
@@ -198,6 +203,36 @@ impl<N: Network> Block<N> {
     pub const fn timestamp(&self) -> i64 {
         self.header.timestamp()
     }
+
+    /// Returns the coinbase reward for this block, which is zero if the block does not have a coinbase solution.
+    pub fn coinbase_reward(&self) -> Result<u64> {
+        match &self.coinbase {
+            Some(coinbase_solution) => coinbase_reward(
+                self.height(),
+                N::STARTING_SUPPLY,
+                N::ANCHOR_TIME,
+                self.coinbase_target(),
+                coinbase_solution.to_cumulative_proof_target()?,
+            ),
+            None => Ok(0),
+        }
+    }
+
+    /// Returns the block reward for this block, defined as the staking reward plus the coinbase reward.
+    /// Note: The genesis block does not have a block reward.
+    pub fn block_reward(&self) -> Result<u64> {
+        // Ensure the block is not the genesis block.
+        if self.height() == 0 {
+            return Ok(0);
+        }
+        // Compute the staking reward.
+        let staking_reward = staking_reward(N::STARTING_SUPPLY, N::ANCHOR_TIME);
+        // Compute the block reward.
+        match staking_reward.checked_add(self.coinbase_reward()?) {
+            Some(block_reward) => Ok(block_reward),
+            None => bail!("The block reward for block {} overflowed", self.height()),
+        }
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -30,7 +30,7 @@ impl<N: Network> Transaction<N> {
                 if *id == **fee.id() {
                     // Return the transaction leaf.
                     return Ok(TransactionLeaf::new_deployment(
-                        deployment.program().functions().len() as u16, // The last index.
+                        deployment.program().functions().len() as u16, // The index after the functions.
                         *id,
                     ));
                 }
@@ -94,49 +94,82 @@ impl<N: Network> Transaction<N> {
     pub(super) fn deployment_tree(deployment: &Deployment<N>, fee: &Fee<N>) -> Result<TransactionTree<N>> {
         // Ensure the number of leaves is within the Merkle tree size.
         Self::check_deployment_size(deployment)?;
-        // Retrieve the program.
-        let program = deployment.program();
-        // Prepare the leaves.
-        let leaves = program
+        // Retrieve the function hashes.
+        let function_hashes = deployment
+            .program()
             .functions()
             .values()
-            .enumerate()
-            .map(|(index, function)| {
-                // Construct the transaction leaf.
-                Ok(TransactionLeaf::new_deployment(
-                    index as u16,
-                    N::hash_bhp1024(&function.to_bytes_le()?.to_bits_le())?,
-                )
-                .to_bits_le())
-            })
-            .chain(
-                [Ok(TransactionLeaf::new_deployment(
-                    program.functions().len() as u16, // The last index.
-                    **fee.transition_id(),
-                )
-                .to_bits_le())]
-                .into_iter(),
-            );
+            .map(|function| N::hash_bhp1024(&function.to_bytes_le()?.to_bits_le()))
+            .collect::<Result<Vec<_>>>()?;
         // Compute the deployment tree.
-        N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&leaves.collect::<Result<Vec<_>>>()?)
+        Self::deployment_tree_from_ids(&function_hashes, fee.transition_id(), &deployment.to_owner_id()?)
+    }
+
+    /// Returns the Merkle tree for the given deployment, given its function hashes, the fee ID, and the owner ID.
+    /// Note: The owner ID commits to the edition and owner of the deployment, and is the leaf after the fee.
+    pub(crate) fn deployment_tree_from_ids(
+        function_hashes: &[Field<N>],
+        fee_id: &N::TransitionID,
+        owner_id: &Field<N>,
+    ) -> Result<TransactionTree<N>> {
+        // Ensure the number of functions is within the allowed range.
+        ensure!(
+            function_hashes.len() < Self::MAX_TRANSITIONS - 1, // Note: Observe we hold back 2 for the fee and owner.
+            "Deployment must contain less than {} functions, found {}",
+            Self::MAX_TRANSITIONS - 1,
+            function_hashes.len()
+        );
+        // Prepare the leaves.
+        let leaves = function_hashes.iter().enumerate().map(|(index, function_hash)| {
+            // Construct the transaction leaf.
+            TransactionLeaf::new_deployment(index as u16, *function_hash).to_bits_le()
+        });
+        // Add the fee and the owner to the leaves.
+        let leaves = leaves.chain(
+            [
+                TransactionLeaf::new_deployment(function_hashes.len() as u16, **fee_id).to_bits_le(),
+                TransactionLeaf::new_deployment(function_hashes.len() as u16 + 1, *owner_id).to_bits_le(),
+            ]
+            .into_iter(),
+        );
+        // Compute the deployment tree.
+        N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&leaves.collect::<Vec<_>>())
     }
 
     /// Returns the Merkle tree for the given execution.
     pub fn execution_tree(execution: &Execution<N>, additional_fee: &Option<Fee<N>>) -> Result<TransactionTree<N>> {
         // Ensure the number of leaves is within the Merkle tree size.
         Self::check_execution_size(execution)?;
+        // Retrieve the transition IDs.
+        let transition_ids = execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>();
+        // Compute the execution tree.
+        Self::execution_tree_from_ids(&transition_ids, additional_fee.as_ref().map(|fee| fee.transition_id()))
+    }
+
+    /// Returns the Merkle tree for the given execution, given its transition IDs and the (optional) additional fee ID.
+    pub(crate) fn execution_tree_from_ids(
+        transition_ids: &[N::TransitionID],
+        additional_fee_id: Option<&N::TransitionID>,
+    ) -> Result<TransactionTree<N>> {
+        // Ensure the number of transitions is within the allowed range.
+        ensure!(
+            transition_ids.len() < Self::MAX_TRANSITIONS, // Note: Observe we hold back 1 for the additional fee.
+            "Execution must contain less than {} transitions, found {}",
+            Self::MAX_TRANSITIONS,
+            transition_ids.len()
+        );
         // Prepare the leaves.
-        let leaves = execution.transitions().enumerate().map(|(index, transition)| {
+        let leaves = transition_ids.iter().enumerate().map(|(index, transition_id)| {
             // Construct the transaction leaf.
-            TransactionLeaf::new_execution(index as u16, **transition.id()).to_bits_le()
+            TransactionLeaf::new_execution(index as u16, **transition_id).to_bits_le()
         });
         // If the additional fee is present, add it to the leaves.
-        let leaves = match additional_fee {
-            Some(additional_fee) => {
+        let leaves = match additional_fee_id {
+            Some(additional_fee_id) => {
                 // Construct the transaction leaf.
                 let leaf = TransactionLeaf::new_execution(
-                    execution.len() as u16, // The last index.
-                    **additional_fee.transition_id(),
+                    transition_ids.len() as u16, // The last index.
+                    **additional_fee_id,
                 )
                 .to_bits_le();
                 // Add the leaf to the leaves.
@@ -167,9 +200,9 @@ impl<N: Network> Transaction<N> {
         );
         // Ensure the number of functions is within the allowed range.
         ensure!(
-            functions.len() < Self::MAX_TRANSITIONS, // Note: Observe we hold back 1 for the additional fee.
+            functions.len() < Self::MAX_TRANSITIONS - 1, // Note: Observe we hold back 2 for the fee and owner.
             "Deployment must contain less than {} functions, found {}",
-            Self::MAX_TRANSITIONS,
+            Self::MAX_TRANSITIONS - 1,
             functions.len()
         );
         Ok(())
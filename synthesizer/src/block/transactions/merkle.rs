@@ -37,16 +37,24 @@ impl<N: Network> Transactions<N> {
 
     /// Returns the Merkle tree for the given transactions.
     fn transactions_tree(transactions: &IndexMap<N::TransactionID, Transaction<N>>) -> Result<TransactionsTree<N>> {
+        // Retrieve the transaction IDs.
+        let transaction_ids = transactions.values().map(|transaction| transaction.id()).collect::<Vec<_>>();
+        // Compute the transactions tree.
+        Self::transactions_tree_from_ids(&transaction_ids)
+    }
+
+    /// Returns the Merkle tree for the given transaction IDs.
+    pub(crate) fn transactions_tree_from_ids(transaction_ids: &[N::TransactionID]) -> Result<TransactionsTree<N>> {
         // Ensure the number of transactions is within the allowed range.
         ensure!(
-            transactions.len() <= Self::MAX_TRANSACTIONS,
+            transaction_ids.len() <= Self::MAX_TRANSACTIONS,
             "Block cannot exceed {} transactions, found {}",
             Self::MAX_TRANSACTIONS,
-            transactions.len()
+            transaction_ids.len()
         );
         // Prepare the leaves.
-        let leaves = transactions.values().map(|transaction| transaction.id().to_bits_le());
-        // Compute the deployment tree.
+        let leaves = transaction_ids.iter().map(|transaction_id| transaction_id.to_bits_le());
+        // Compute the transactions tree.
         N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&leaves.collect::<Vec<_>>())
     }
 }
@@ -0,0 +1,321 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    coinbase_puzzle::{CoinbasePuzzle, EpochChallenge},
+    consensus::{anchor_reward, coinbase_target, proof_target},
+};
+
+impl<N: Network> Block<N> {
+    /// Ensures the block is a valid successor of the given `previous_block`,
+    /// where `current_state_root` is the state root of the chain ending at `previous_block`.
+    /// The coinbase puzzle and epoch challenge are required if the block has a coinbase solution.
+    pub fn verify(
+        &self,
+        previous_block: &Block<N>,
+        current_state_root: N::StateRoot,
+        coinbase: Option<(&CoinbasePuzzle<N>, &EpochChallenge<N>)>,
+    ) -> Result<(), BlockError> {
+        let height = self.height();
+
+        /* Header */
+
+        // Ensure the block is for the expected network.
+        if self.network() != N::ID {
+            return Err(BlockError::InvalidNetworkID { height, expected: N::ID, found: self.network() });
+        }
+        // Ensure the block height increments by one.
+        let expected_height = previous_block.height().saturating_add(1);
+        if height != expected_height {
+            return Err(BlockError::InvalidHeight { expected: expected_height, found: height });
+        }
+        // Ensure the previous block hash matches the chain tip.
+        if self.previous_hash != previous_block.hash() {
+            return Err(BlockError::InvalidPreviousHash {
+                height,
+                expected: previous_block.hash().to_string(),
+                found: self.previous_hash.to_string(),
+            });
+        }
+        // Ensure the previous state root matches the current state root.
+        if self.previous_state_root() != *current_state_root {
+            return Err(BlockError::InvalidPreviousStateRoot {
+                height,
+                expected: current_state_root.to_string(),
+                found: self.previous_state_root().to_string(),
+            });
+        }
+        // Ensure the header is well-formed.
+        if !self.header.is_valid() {
+            return Err(BlockError::InvalidHeader { height });
+        }
+
+        /* Metadata */
+
+        // Ensure the round number increases.
+        if self.round() <= previous_block.round() {
+            return Err(BlockError::InvalidRound { height, previous: previous_block.round(), found: self.round() });
+        }
+        // Ensure the timestamp increases.
+        if self.timestamp() <= previous_block.timestamp() {
+            return Err(BlockError::InvalidTimestamp {
+                height,
+                previous: previous_block.timestamp(),
+                found: self.timestamp(),
+            });
+        }
+        // Ensure the coinbase target is retargeted correctly.
+        let expected_coinbase_target = coinbase_target(
+            previous_block.last_coinbase_target(),
+            previous_block.last_coinbase_timestamp(),
+            self.timestamp(),
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        if self.coinbase_target() != expected_coinbase_target {
+            return Err(BlockError::InvalidTargets {
+                height,
+                reason: format!(
+                    "expected a coinbase target of {expected_coinbase_target}, found {}",
+                    self.coinbase_target()
+                ),
+            });
+        }
+        // Ensure the proof target corresponds to the coinbase target.
+        let expected_proof_target = proof_target(self.coinbase_target(), N::GENESIS_PROOF_TARGET);
+        if self.proof_target() != expected_proof_target {
+            return Err(BlockError::InvalidTargets {
+                height,
+                reason: format!("expected a proof target of {expected_proof_target}, found {}", self.proof_target()),
+            });
+        }
+        // Ensure the last coinbase target and timestamp are carried over correctly.
+        let (expected_last_coinbase_target, expected_last_coinbase_timestamp) = match self.coinbase {
+            Some(_) => (self.coinbase_target(), self.timestamp()),
+            None => (previous_block.last_coinbase_target(), previous_block.last_coinbase_timestamp()),
+        };
+        if self.last_coinbase_target() != expected_last_coinbase_target {
+            return Err(BlockError::InvalidTargets {
+                height,
+                reason: format!(
+                    "expected a last coinbase target of {expected_last_coinbase_target}, found {}",
+                    self.last_coinbase_target()
+                ),
+            });
+        }
+        if self.last_coinbase_timestamp() != expected_last_coinbase_timestamp {
+            return Err(BlockError::InvalidTargets {
+                height,
+                reason: format!(
+                    "expected a last coinbase timestamp of {expected_last_coinbase_timestamp}, found {}",
+                    self.last_coinbase_timestamp()
+                ),
+            });
+        }
+
+        /* Transactions */
+
+        // Ensure the transactions root matches the transactions.
+        if self.transactions_root() != self.transactions.to_root()? {
+            return Err(BlockError::InvalidTransactionsRoot { height });
+        }
+
+        /* Coinbase */
+
+        // Ensure the coinbase accumulator point matches the coinbase solution.
+        let expected_accumulator_point = match &self.coinbase {
+            Some(coinbase_solution) => coinbase_solution.to_accumulator_point()?,
+            None => Field::<N>::zero(),
+        };
+        if self.header.coinbase_accumulator_point() != expected_accumulator_point {
+            return Err(BlockError::InvalidCoinbaseSolution {
+                height,
+                reason: "the coinbase accumulator point does not match the coinbase solution".to_string(),
+            });
+        }
+        // Ensure the coinbase solution is valid for the epoch challenge and targets.
+        if let Some(coinbase_solution) = &self.coinbase {
+            let (coinbase_puzzle, epoch_challenge) = match coinbase {
+                Some(coinbase) => coinbase,
+                None => {
+                    return Err(BlockError::InvalidCoinbaseSolution {
+                        height,
+                        reason: "the coinbase puzzle is required to verify the coinbase solution".to_string(),
+                    });
+                }
+            };
+            match coinbase_puzzle.verify(
+                coinbase_solution,
+                epoch_challenge,
+                self.coinbase_target(),
+                self.proof_target(),
+            ) {
+                Ok(true) => (),
+                Ok(false) => {
+                    return Err(BlockError::InvalidCoinbaseSolution {
+                        height,
+                        reason: "the coinbase proof is invalid".to_string(),
+                    });
+                }
+                Err(error) => return Err(BlockError::InvalidCoinbaseSolution { height, reason: error.to_string() }),
+            }
+            // Ensure the coinbase solution earns the full anchor reward, as it meets the coinbase target.
+            let coinbase_reward = self.coinbase_reward()?;
+            let expected_coinbase_reward = anchor_reward(height, N::STARTING_SUPPLY, N::ANCHOR_TIME);
+            if coinbase_reward != expected_coinbase_reward {
+                return Err(BlockError::InvalidCoinbaseSolution {
+                    height,
+                    reason: format!(
+                        "expected a coinbase reward of {expected_coinbase_reward}, found {coinbase_reward}"
+                    ),
+                });
+            }
+        }
+        // Ensure the block reward is well-defined.
+        self.block_reward()?;
+
+        /* Block */
+
+        // Ensure the block hash is correct.
+        let block_hash =
+            N::hash_bhp1024(&[self.previous_hash.to_bits_le(), self.header.to_root()?.to_bits_le()].concat())?;
+        if *self.block_hash != block_hash {
+            return Err(BlockError::InvalidBlockHash { height });
+        }
+        // Ensure the signature is valid.
+        if !self.signature.verify(&self.signature.to_address(), &[block_hash]) {
+            return Err(BlockError::InvalidSignature { height });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{
+        sample_genesis_credits,
+        sample_genesis_private_key,
+        sample_next_block_with_deployment,
+        sample_program,
+        sample_vm_with_genesis_block,
+        CurrentNetwork,
+    };
+    use console::program::Value;
+
+    /// Returns a copy of the given block with the given metadata, signed by the given private key.
+    fn sample_block_with_metadata(
+        block: &Block<CurrentNetwork>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        metadata: Metadata<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Block<CurrentNetwork> {
+        let header = Header::from(
+            block.previous_state_root(),
+            block.transactions_root(),
+            block.header().coinbase_accumulator_point(),
+            metadata,
+        )
+        .unwrap();
+        Block::new(private_key, block.previous_hash(), header, block.transactions().clone(), None, rng).unwrap()
+    }
+
+    /// Returns the metadata of the given block, with the given round, height, and timestamp.
+    fn sample_metadata(
+        block: &Block<CurrentNetwork>,
+        round: u64,
+        height: u32,
+        timestamp: i64,
+    ) -> Metadata<CurrentNetwork> {
+        Metadata::new(
+            block.network(),
+            round,
+            height,
+            block.coinbase_target(),
+            block.proof_target(),
+            block.last_coinbase_target(),
+            block.last_coinbase_timestamp(),
+            timestamp,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_next_block_rejects_tampered_blocks() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Add a block with a deployment, so that the next block does not follow the genesis block.
+        let credits = sample_genesis_credits(rng);
+        let (previous_block, credits) =
+            sample_next_block_with_deployment(&vm, &private_key, &sample_program(), credits, rng);
+        vm.add_next_block(&previous_block).unwrap();
+
+        // Propose a valid block, which transfers the change record of the deployment fee.
+        let inputs =
+            [Value::Record(credits), Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization = vm.authorize(&private_key, "credits.aleo", "transfer", inputs.into_iter(), rng).unwrap();
+        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let block = vm.propose_next_block(&private_key, &[transaction], None, rng).unwrap();
+        vm.check_next_block(&block).unwrap();
+
+        // Ensure a block with a tampered previous hash is rejected.
+        let tampered =
+            Block::new(&private_key, block.hash(), block.header().clone(), block.transactions().clone(), None, rng)
+                .unwrap();
+        let error = vm.check_next_block(&tampered).unwrap_err();
+        assert!(matches!(error, BlockError::InvalidPreviousHash { height: 2, .. }), "{error}");
+
+        // Ensure a block with a tampered height is rejected.
+        let metadata = sample_metadata(&block, block.round(), 3, block.timestamp());
+        let tampered = sample_block_with_metadata(&block, &private_key, metadata, rng);
+        let error = vm.check_next_block(&tampered).unwrap_err();
+        assert!(matches!(error, BlockError::InvalidHeight { expected: 2, found: 3 }), "{error}");
+
+        // Ensure a block with a round that does not increase is rejected.
+        let metadata = sample_metadata(&block, previous_block.round(), 2, block.timestamp());
+        let tampered = sample_block_with_metadata(&block, &private_key, metadata, rng);
+        let error = vm.check_next_block(&tampered).unwrap_err();
+        assert!(matches!(error, BlockError::InvalidRound { height: 2, .. }), "{error}");
+
+        // Ensure a block with a timestamp that does not increase is rejected.
+        let metadata = sample_metadata(&block, block.round(), 2, previous_block.timestamp());
+        let tampered = sample_block_with_metadata(&block, &private_key, metadata, rng);
+        let error = vm.check_next_block(&tampered).unwrap_err();
+        assert!(matches!(error, BlockError::InvalidTimestamp { height: 2, .. }), "{error}");
+
+        // Ensure a block whose transactions do not match the transactions root is rejected.
+        let tampered = Block { transactions: previous_block.transactions().clone(), ..block.clone() };
+        let error = vm.check_next_block(&tampered).unwrap_err();
+        assert!(matches!(error, BlockError::InvalidTransactionsRoot { height: 2 }), "{error}");
+
+        // Ensure a block with a signature over a different message is rejected.
+        let signature = private_key.sign(&[*previous_block.hash()], rng).unwrap();
+        let tampered = Block { signature, ..block.clone() };
+        let error = vm.check_next_block(&tampered).unwrap_err();
+        assert!(matches!(error, BlockError::InvalidSignature { height: 2 }), "{error}");
+
+        // Ensure the valid block is still accepted.
+        vm.add_next_block(&block).unwrap();
+    }
+}
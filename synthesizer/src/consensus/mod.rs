@@ -0,0 +1,254 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::prelude::{ensure, Result};
+
+/// Returns the coinbase target for the next block, given the last coinbase target and timestamp.
+/// The coinbase target halves (or doubles) for every half-life of drift from the anchor time,
+/// and never falls below the genesis coinbase target.
+pub fn coinbase_target(
+    last_coinbase_target: u64,
+    last_coinbase_timestamp: i64,
+    next_timestamp: i64,
+    anchor_time: u16,
+    num_blocks_per_epoch: u32,
+    genesis_coinbase_target: u64,
+) -> Result<u64> {
+    // Compute the half-life, in seconds.
+    let half_life = num_blocks_per_epoch.saturating_div(2).saturating_mul(anchor_time as u32);
+    // Compute the candidate coinbase target.
+    let candidate_target =
+        retarget(last_coinbase_target, last_coinbase_timestamp, next_timestamp, anchor_time, half_life, true)?;
+    // Return the candidate target, floored at the genesis coinbase target.
+    Ok(candidate_target.max(genesis_coinbase_target))
+}
+
+/// Returns the proof target for the given coinbase target.
+/// The proof target is 1/128th of the coinbase target, and never falls below the genesis proof target.
+pub const fn proof_target(coinbase_target: u64, genesis_proof_target: u64) -> u64 {
+    let candidate_target = (coinbase_target >> 7).saturating_add(1);
+    match candidate_target > genesis_proof_target {
+        true => candidate_target,
+        false => genesis_proof_target,
+    }
+}
+
+/// Returns the block height at the given number of years, for the given block time in seconds.
+pub const fn block_height_at_year(block_time: u16, num_years: u32) -> u32 {
+    // Compute the number of seconds in a year.
+    const SECONDS_PER_YEAR: u32 = 365 * 24 * 60 * 60;
+    // Compute the number of blocks per year.
+    let blocks_per_year = SECONDS_PER_YEAR / block_time as u32;
+    // Return the block height for the given number of years.
+    blocks_per_year * num_years
+}
+
+/// Returns the staking reward for a block, defined as:
+///     R_staking = floor((0.025 * S) / H_Y1)
+/// where `S` is the starting supply and `H_Y1` is the block height at year 1.
+pub const fn staking_reward(starting_supply: u64, anchor_time: u16) -> u64 {
+    // Compute the annual staking reward: (0.025 * S).
+    let annual_staking_reward = (starting_supply / 1000) * 25;
+    // Compute the staking reward: (0.025 * S) / H_Y1.
+    annual_staking_reward / block_height_at_year(anchor_time, 1) as u64
+}
+
+/// Returns the anchor reward for a block at the given height, defined as:
+///     R_anchor = floor((2 * S * max(0, H_Y10 - H)) / (H_Y10 * (H_Y10 + 1)))
+/// where `S` is the starting supply, `H_Y10` is the block height at year 10, and `H` is the block height.
+/// The anchor rewards decrease linearly, and sum to the starting supply over 10 years.
+pub const fn anchor_reward(block_height: u32, starting_supply: u64, anchor_time: u16) -> u64 {
+    // Compute the block height at year 10.
+    let block_height_at_year_10 = block_height_at_year(anchor_time, 10) as u128;
+    // Compute the number of remaining blocks until year 10.
+    let num_remaining_blocks = block_height_at_year_10.saturating_sub(block_height as u128);
+    // Compute the anchor reward.
+    let numerator = 2 * starting_supply as u128 * num_remaining_blocks;
+    let denominator = block_height_at_year_10 * (block_height_at_year_10 + 1);
+    // Note: This cast is lossless, as the anchor reward is at most `2 * S / (H_Y10 + 1)`.
+    (numerator / denominator) as u64
+}
+
+/// Returns the coinbase reward for a block, defined as:
+///     R_coinbase = floor(R_anchor * min(P, C) / C)
+/// where `R_anchor` is the anchor reward, `P` is the cumulative proof target of the coinbase solution,
+/// and `C` is the coinbase target.
+pub fn coinbase_reward(
+    block_height: u32,
+    starting_supply: u64,
+    anchor_time: u16,
+    coinbase_target: u64,
+    cumulative_proof_target: u128,
+) -> Result<u64> {
+    ensure!(coinbase_target > 0, "The coinbase target must be nonzero");
+    // Compute the anchor reward.
+    let anchor_reward = anchor_reward(block_height, starting_supply, anchor_time) as u128;
+    // Compute the coinbase reward, in proportion to the coinbase target that was met.
+    let reward = anchor_reward * cumulative_proof_target.min(coinbase_target as u128) / coinbase_target as u128;
+    // Return the coinbase reward.
+    Ok(u64::try_from(reward)?)
+}
+
+/// Retargets the given target using the ASERT algorithm (https://www.reference.cash/protocol/forks/2020-11-15-asert), as:
+///     T_{i+1} = T_i * 2^(INV * (D - A) / TAU)
+/// where `D` is the elapsed time, `A` is the anchor time, and `TAU` is the half-life.
+/// If `is_inverse` is `true`, the target decreases when blocks are slower than the anchor time.
+fn retarget(
+    previous_target: u64,
+    previous_timestamp: i64,
+    next_timestamp: i64,
+    anchor_time: u16,
+    half_life: u32,
+    is_inverse: bool,
+) -> Result<u64> {
+    ensure!(half_life > 0, "The half-life must be nonzero");
+
+    // Compute the drift from the anchor time.
+    let drift = {
+        // Compute the elapsed time, which is at least 1 second to guard against repeated timestamps.
+        let elapsed = next_timestamp.saturating_sub(previous_timestamp).max(1);
+        // Note: This must be a signed subtraction, to account for blocks that are faster than the anchor time.
+        let drift = elapsed.saturating_sub(anchor_time as i64);
+        match is_inverse {
+            true => -drift,
+            false => drift,
+        }
+    };
+
+    // If there is no drift, return the previous target.
+    if drift == 0 {
+        return Ok(previous_target);
+    }
+
+    // The number of fractional bits used for fixed-point arithmetic.
+    const RBITS: u32 = 16;
+    const RADIX: u128 = 1 << RBITS;
+
+    // Compute the exponent, and decompose it into its integral and fractional parts.
+    let exponent = (RADIX as i128).saturating_mul(drift as i128) / half_life as i128;
+    let integral = exponent >> RBITS;
+    let fractional = (exponent - (integral << RBITS)) as u128;
+    ensure!(fractional < RADIX, "The fractional part of the exponent is out of range");
+
+    // Approximate 2^fractional (scaled by RADIX), using the cubic polynomial:
+    //     2^x ~= 1 + 0.695502049 * x + 0.2262698 * x^2 + 0.0782318 * x^3
+    let fractional_multiplier = RADIX
+        + ((195_766_423_245_049_u128 * fractional
+            + 971_821_376_u128 * fractional.pow(2)
+            + 5_127_u128 * fractional.pow(3)
+            + 2_u128.pow(RBITS * 3 - 1))
+            >> (RBITS * 3));
+
+    // Note: This multiplication cannot overflow, as the multiplier is less than 2^17.
+    let candidate_target = (previous_target as u128) * fractional_multiplier;
+
+    // Multiply the candidate target by 2^integral, and divide it by RADIX.
+    let shift = integral - RBITS as i128;
+    let candidate_target = match shift < 0 {
+        true => match u32::try_from(-shift) {
+            Ok(shift) if shift < u128::BITS => (candidate_target >> shift).max(1),
+            _ => 1,
+        },
+        false => match u32::try_from(shift) {
+            Ok(shift) if shift < candidate_target.leading_zeros() => candidate_target << shift,
+            _ => u64::MAX as u128,
+        },
+    };
+
+    // Return the candidate target, capped at `u64::MAX`.
+    Ok(candidate_target.min(u64::MAX as u128) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::{Network, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const GENESIS_TIMESTAMP: i64 = CurrentNetwork::GENESIS_TIMESTAMP;
+
+    fn next_coinbase_target(last_coinbase_target: u64, elapsed: i64) -> u64 {
+        coinbase_target(
+            last_coinbase_target,
+            GENESIS_TIMESTAMP,
+            GENESIS_TIMESTAMP + elapsed,
+            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_coinbase_target() {
+        // On time.
+        assert_eq!(next_coinbase_target(1023, 25), 1023);
+        assert_eq!(next_coinbase_target(10000, 25), 10000);
+        // Faster than the anchor time.
+        assert_eq!(next_coinbase_target(10000, 1), 10052);
+        assert_eq!(next_coinbase_target(10000, 0), 10052);
+        assert_eq!(next_coinbase_target(1 << 40, 1), 1105249435648);
+        // Slower than the anchor time.
+        assert_eq!(next_coinbase_target(10000, 50), 9946);
+        assert_eq!(next_coinbase_target(1 << 40, 100), 1081836830720);
+        assert_eq!(next_coinbase_target(1 << 40, 25 * 256), 276375273472);
+        // One half-life late.
+        assert_eq!(next_coinbase_target(10000, 25 + 3200), 5000);
+        // The coinbase target is floored at the genesis coinbase target.
+        assert_eq!(next_coinbase_target(1023, 3600), CurrentNetwork::GENESIS_COINBASE_TARGET);
+        // The coinbase target is capped at `u64::MAX`.
+        assert_eq!(next_coinbase_target(u64::MAX, 1), u64::MAX);
+    }
+
+    #[test]
+    fn test_proof_target() {
+        assert_eq!(proof_target(CurrentNetwork::GENESIS_COINBASE_TARGET, CurrentNetwork::GENESIS_PROOF_TARGET), 8);
+        assert_eq!(proof_target(10000, CurrentNetwork::GENESIS_PROOF_TARGET), 79);
+        assert_eq!(proof_target(1105249435648, CurrentNetwork::GENESIS_PROOF_TARGET), 8634761217);
+        assert_eq!(proof_target(u64::MAX, CurrentNetwork::GENESIS_PROOF_TARGET), 1 << 57);
+        assert_eq!(proof_target(0, CurrentNetwork::GENESIS_PROOF_TARGET), CurrentNetwork::GENESIS_PROOF_TARGET);
+    }
+
+    #[test]
+    fn test_rewards() {
+        const STARTING_SUPPLY: u64 = CurrentNetwork::STARTING_SUPPLY;
+        const ANCHOR_TIME: u16 = CurrentNetwork::ANCHOR_TIME;
+
+        assert_eq!(block_height_at_year(ANCHOR_TIME, 1), 1261440);
+        assert_eq!(block_height_at_year(ANCHOR_TIME, 10), 12614400);
+
+        assert_eq!(staking_reward(STARTING_SUPPLY, ANCHOR_TIME), 21800481);
+
+        assert_eq!(anchor_reward(0, STARTING_SUPPLY, ANCHOR_TIME), 174403842);
+        assert_eq!(anchor_reward(1, STARTING_SUPPLY, ANCHOR_TIME), 174403828);
+        assert_eq!(anchor_reward(1261440, STARTING_SUPPLY, ANCHOR_TIME), 156963457);
+        assert_eq!(anchor_reward(6307200, STARTING_SUPPLY, ANCHOR_TIME), 87201921);
+        assert_eq!(anchor_reward(12614399, STARTING_SUPPLY, ANCHOR_TIME), 13);
+        assert_eq!(anchor_reward(12614400, STARTING_SUPPLY, ANCHOR_TIME), 0);
+        assert_eq!(anchor_reward(u32::MAX, STARTING_SUPPLY, ANCHOR_TIME), 0);
+
+        let reward = |height, target, proof_target| {
+            coinbase_reward(height, STARTING_SUPPLY, ANCHOR_TIME, target, proof_target).unwrap()
+        };
+        assert_eq!(reward(0, 1023, 1023), 174403842);
+        assert_eq!(reward(0, 1023, 511), 87116679);
+        assert_eq!(reward(0, 1023, 1_000_000_000), 174403842);
+        assert_eq!(reward(100, 10000, 2500), 43600614);
+        assert_eq!(reward(12614400, 1023, 1023), 0);
+        assert!(coinbase_reward(0, STARTING_SUPPLY, ANCHOR_TIME, 0, 1).is_err());
+    }
+}
@@ -29,6 +29,9 @@ pub use block::*;
 pub mod coinbase_puzzle;
 pub use coinbase_puzzle::*;
 
+pub mod consensus;
+pub use consensus::*;
+
 pub mod process;
 pub use process::*;
 
@@ -92,7 +95,7 @@ pub(crate) mod test_helpers {
     impl<N: Network> TestLedger<N> {
         /// Adds the given block as the next block in the chain.
         pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
-            self.vm.add_next_block(block)
+            Ok(self.vm.add_next_block(block)?)
         }
 
         /// Returns the block for the given block height.
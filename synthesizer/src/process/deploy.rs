@@ -39,6 +39,38 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Deploys the given program as the next edition of an existing program.
+    /// Note: The returned deployment must be signed by the program owner before it is broadcast.
+    #[inline]
+    pub fn deploy_upgrade<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        let timer = timer!("Process::deploy_upgrade");
+
+        // Retrieve the existing stack.
+        let existing = self.get_stack(program.id())?;
+        // Compute the next edition.
+        let edition = existing
+            .edition()
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("Program '{}' has no editions left", program.id()))?;
+
+        // Compute the stack.
+        let mut stack = Stack::new_upgrade(self, program)?;
+        stack.set_deployment_info(edition, existing.owner().copied());
+        lap!(timer, "Compute the stack");
+
+        // Return the deployment.
+        let deployment = stack.deploy::<A, R>(rng);
+        lap!(timer, "Construct the deployment");
+
+        finish!(timer);
+
+        deployment
+    }
+
     /// Verifies the given deployment is well-formed.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
@@ -49,10 +81,21 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::verify_deployment");
         // Retrieve the program ID.
         let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let mut stack = match self.contains_program(program_id) {
+            // If the program exists, ensure the deployment is an authorized and compatible upgrade.
+            true => {
+                self.check_upgrade(deployment)?;
+                Stack::new_upgrade(self, deployment.program())?
+            }
+            // Otherwise, ensure the deployment is the initial edition.
+            false => {
+                let edition = deployment.edition();
+                ensure!(edition == N::EDITION, "Program '{program_id}' must be deployed with edition {}", N::EDITION);
+                Stack::new(self, deployment.program())?
+            }
+        };
+        stack.set_deployment_info(deployment.edition(), deployment.owner().map(|(address, _)| *address));
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
@@ -65,6 +108,7 @@ impl<N: Network> Process<N> {
     }
 
     /// Finalizes the deployment.
+    /// If the program already exists, the deployment replaces it, while its existing mappings are kept.
     /// This method assumes the given deployment **is valid**.
     #[inline]
     pub fn finalize_deployment<P: ProgramStorage<N>>(
@@ -74,29 +118,31 @@ impl<N: Network> Process<N> {
     ) -> Result<()> {
         let timer = timer!("Process::finalize_deployment");
 
-        // TODO (howardwu): Make this function atomic.
-        // TODO (howardwu): Check the program ID and all mappings don't exist in the 'store'. (add this to verify_deployment too)
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // If the program exists, ensure the deployment is an authorized and compatible upgrade.
+        if self.contains_program(program_id) {
+            self.check_upgrade(deployment)?;
+        }
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
-        // Insert the verifying keys.
-        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
-            stack.insert_verifying_key(function_name, verifying_key.clone())?;
-        }
-        lap!(timer, "Insert the verifying keys");
-
-        // Retrieve the program ID.
-        let program_id = deployment.program_id();
         // Iterate through the program mappings.
         for mapping in deployment.program().mappings().values() {
-            store.initialize_mapping(program_id, mapping.name())?;
+            // Initialize the mapping, if it does not already exist from a prior edition.
+            if !store.contains_mapping(program_id, mapping.name())? {
+                store.initialize_mapping(program_id, mapping.name())?;
+            }
         }
         lap!(timer, "Initialize the program mappings");
 
-        // Add the stack to the process.
-        self.stacks.insert(*deployment.program_id(), stack);
+        // Add the stack to the process, replacing the prior edition (if one exists).
+        self.stacks.insert(*program_id, stack);
+        // Update the stacks that import this program, so they resolve to the new edition.
+        self.update_importing_stacks(program_id)?;
+        lap!(timer, "Update the importing stacks");
 
         finish!(timer);
 
@@ -104,28 +150,150 @@ impl<N: Network> Process<N> {
     }
 
     /// Adds the newly-deployed program.
+    /// If a later (or the same) edition of the program is already loaded, the deployment is skipped.
     /// This method assumes the given deployment **is valid**.
     #[inline]
     pub(crate) fn load_deployment(&mut self, deployment: &Deployment<N>) -> Result<()> {
         let timer = timer!("Process::load_deployment");
 
+        // Skip the deployment, if it is superseded by the loaded edition.
+        if let Ok(stack) = self.get_stack(deployment.program_id()) {
+            if stack.edition() >= deployment.edition() {
+                finish!(timer);
+                return Ok(());
+            }
+        }
+
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
-        // Insert the verifying keys.
-        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
-            stack.insert_verifying_key(function_name, verifying_key.clone())?;
-        }
-        lap!(timer, "Insert the verifying keys");
-
         // Add the stack to the process.
         self.stacks.insert(*deployment.program_id(), stack);
+        // Update the stacks that import this program, so they resolve to the new edition.
+        self.update_importing_stacks(deployment.program_id())?;
+        lap!(timer, "Update the importing stacks");
 
         finish!(timer);
 
         Ok(())
     }
+
+    /// Updates the external stacks of every program that imports the given program, directly or transitively,
+    /// to the stacks currently in the process.
+    /// Note: As a program is added only after its imports, and an upgrade retains the position of the
+    /// prior edition, the stacks are visited in dependency order.
+    #[inline]
+    fn update_importing_stacks(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Initialize the set of updated program IDs.
+        let mut updated = IndexSet::new();
+        updated.insert(*program_id);
+        // Iterate through the stacks, after the given program.
+        let start = self.stacks.get_index_of(program_id).map_or(0, |index| index + 1);
+        for index in start..self.stacks.len() {
+            // Retrieve the stack.
+            let (importer_id, stack) = self.stacks.get_index(index).ok_or_else(|| anyhow!("Missing stack"))?;
+            // Retrieve the updated imports of the program.
+            let imports =
+                stack.program().imports().keys().filter(|import| updated.contains(*import)).collect::<Vec<_>>();
+            if imports.is_empty() {
+                continue;
+            }
+            // Replace the external stacks of the updated imports.
+            let (importer_id, mut stack) = (*importer_id, stack.clone());
+            for import in imports {
+                stack.replace_external_stack(self.get_stack(import)?.clone())?;
+            }
+            self.stacks.insert(importer_id, stack);
+            updated.insert(importer_id);
+        }
+        Ok(())
+    }
+
+    /// Returns the stack for the given deployment, with its edition, owner, and verifying keys.
+    #[inline]
+    fn compute_deployment_stack(&self, deployment: &Deployment<N>) -> Result<Stack<N>> {
+        // Compute the program stack.
+        let mut stack = match self.contains_program(deployment.program_id()) {
+            true => Stack::new_upgrade(self, deployment.program())?,
+            false => Stack::new(self, deployment.program())?,
+        };
+        // Set the edition and owner.
+        stack.set_deployment_info(deployment.edition(), deployment.owner().map(|(address, _)| *address));
+        // Insert the verifying keys.
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            stack.insert_verifying_key(function_name, verifying_key.clone())?;
+        }
+        Ok(stack)
+    }
+
+    /// Ensures the given deployment is an authorized and compatible upgrade of an existing program.
+    ///
+    /// An upgrade is accepted if it is signed by the owner of the existing program, its edition
+    /// is greater than the existing edition, and it retains every existing function (with the same
+    /// input and output types), mapping, struct, and record.
+    #[inline]
+    pub fn check_upgrade(&self, deployment: &Deployment<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Retrieve the existing stack.
+        let existing = self.get_stack(program_id)?;
+
+        // Ensure the existing program is upgradable.
+        let owner = match existing.owner() {
+            Some(owner) => owner,
+            None => bail!("Program '{program_id}' is not upgradable"),
+        };
+        // Ensure the deployment is signed by the owner.
+        match deployment.owner() {
+            Some((address, _)) => ensure!(address == owner, "Only the owner of '{program_id}' may upgrade it"),
+            None => bail!("The upgrade of '{program_id}' must be signed by its owner"),
+        }
+        // Ensure the edition increases.
+        ensure!(
+            deployment.edition() > existing.edition(),
+            "The upgrade of '{program_id}' must increase the edition (current '{}', found '{}')",
+            existing.edition(),
+            deployment.edition()
+        );
+
+        // Retrieve the existing and upgraded programs.
+        let (existing, upgrade) = (existing.program(), deployment.program());
+
+        // Ensure the existing structs and records are unchanged.
+        for (name, struct_) in existing.structs() {
+            ensure!(
+                upgrade.structs().get(name) == Some(struct_),
+                "The upgrade of '{program_id}' changes struct '{name}'"
+            );
+        }
+        for (name, record) in existing.records() {
+            ensure!(
+                upgrade.records().get(name) == Some(record),
+                "The upgrade of '{program_id}' changes record '{name}'"
+            );
+        }
+        // Ensure the existing mappings are unchanged, so their stored values remain well-typed.
+        for (name, mapping) in existing.mappings() {
+            ensure!(
+                upgrade.mappings().get(name) == Some(mapping),
+                "The upgrade of '{program_id}' changes mapping '{name}'"
+            );
+        }
+        // Ensure the existing function signatures are unchanged.
+        for (name, function) in existing.functions() {
+            let candidate = match upgrade.functions().get(name) {
+                Some(candidate) => candidate,
+                None => bail!("The upgrade of '{program_id}' removes function '{name}'"),
+            };
+            ensure!(
+                function.input_types() == candidate.input_types()
+                    && function.output_types() == candidate.output_types(),
+                "The upgrade of '{program_id}' changes the signature of function '{name}'"
+            );
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -227,13 +227,14 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
-    /// Finalizes the execution.
+    /// Finalizes the execution, in the block at the given height.
     /// This method assumes the given execution **is valid**.
     #[inline]
     pub fn finalize_execution<P: ProgramStorage<N>>(
         &self,
         store: &ProgramStore<N, P>,
         execution: &Execution<N>,
+        block_height: u32,
     ) -> Result<()> {
         let timer = timer!("Program::finalize_execution");
 
@@ -278,7 +279,8 @@ impl<N: Network> Process<N> {
                 };
 
                 // Initialize the registers.
-                let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
+                let mut registers =
+                    FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone(), block_height);
 
                 // Store the inputs.
                 finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
@@ -286,11 +288,21 @@ impl<N: Network> Process<N> {
                     registers.store(stack, register, input.clone())
                 })?;
 
-                // Evaluate the commands.
-                for command in finalize.commands() {
-                    // If the evaluation fails, bail and return the error.
-                    if let Err(error) = command.evaluate_finalize(stack, store, &mut registers) {
-                        bail!("'finalize' failed to evaluate command ({command}): {error}");
+                // Evaluate the commands, starting from the first command.
+                // Note: Branches only jump forward and repeats are bounded, so this loop always terminates.
+                let mut counter = 0;
+                while let Some(command) = finalize.commands().get(counter) {
+                    match command.evaluate_finalize(stack, store, &mut registers) {
+                        // If the branch is taken, jump forward to the position.
+                        Ok(Some(position)) => match finalize.positions().get(&position) {
+                            Some(index) if *index > counter => counter = *index,
+                            Some(..) => bail!("'finalize' cannot branch backwards to position '{position}'"),
+                            None => bail!("'finalize' failed to locate position '{position}'"),
+                        },
+                        // Otherwise, proceed to the next command.
+                        Ok(None) => counter += 1,
+                        // If the evaluation fails, bail and return the error.
+                        Err(error) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
                     }
                 }
 
@@ -37,7 +37,7 @@ use console::{
 };
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
@@ -83,11 +83,42 @@ impl<N: Network> Process<N> {
         // Add the 'credits.aleo' stack to the process.
         process.stacks.insert(*program.id(), stack);
 
+        // If the network samples its universal SRS locally, set the circuit keys for the network.
+        if N::local_universal_srs().is_some() {
+            process.set_local_circuit_keys::<A, R>(rng)?;
+            lap!(timer, "Set the local circuit keys");
+        }
+
         finish!(timer);
         // Return the process.
         Ok(process)
     }
 
+    /// Synthesizes the inclusion circuit keys, and sets the 'credits.aleo' and inclusion circuit keys for the network.
+    /// Note: This is only supported by networks that sample their universal SRS locally.
+    #[inline]
+    fn set_local_circuit_keys<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<()> {
+        // Retrieve the 'credits.aleo' stack.
+        let stack = self.get_stack(ProgramID::from_str("credits.aleo")?)?;
+
+        // Collect the 'credits.aleo' circuit keys.
+        let mut credits_keys = IndexMap::new();
+        for function_name in stack.program().functions().keys() {
+            let proving_key = stack.get_proving_key(function_name)?;
+            let verifying_key = stack.get_verifying_key(function_name)?;
+            credits_keys.insert(
+                function_name.to_string(),
+                (Arc::new((*proving_key).clone()), Arc::new((*verifying_key).clone())),
+            );
+        }
+
+        // Synthesize the inclusion circuit keys.
+        let (proving_key, verifying_key) = Inclusion::synthesize_keys::<A, R>(&self.universal_srs, rng)?;
+
+        // Set the circuit keys for the network.
+        N::set_local_circuit_keys(credits_keys, (Arc::new((*proving_key).clone()), Arc::new((*verifying_key).clone())))
+    }
+
     /// Adds a new program to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
@@ -134,11 +165,11 @@ impl<N: Network> Process<N> {
         lap!(timer, "Load circuit keys");
 
         // Initialize the inclusion proving key.
-        let _ = N::inclusion_proving_key();
+        N::inclusion_proving_key()?;
         lap!(timer, "Load inclusion proving key");
 
         // Initialize the inclusion verifying key.
-        let _ = N::inclusion_verifying_key();
+        N::inclusion_verifying_key()?;
         lap!(timer, "Load inclusion verifying key");
 
         // Add the stack to the process.
@@ -405,10 +436,10 @@ mod tests {
     use crate::ProgramMemory;
     use circuit::network::AleoV0;
     use console::{
-        account::{Address, PrivateKey, ViewKey},
+        account::{Address, PrivateKey, Signature, ViewKey},
         network::Testnet3,
-        program::{Identifier, Literal, Value},
-        types::Field,
+        program::{Identifier, Literal, LiteralType, Plaintext, Value},
+        types::{Field, Scalar, U128, U8},
     };
 
     type CurrentNetwork = Testnet3;
@@ -914,6 +945,368 @@ function compute:
         // assert_eq!(159387, CurrentAleo::num_gates());
     }
 
+    #[test]
+    fn test_process_execute_cast_literals() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program casts.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as i8.private;
+    input r2 as address.private;
+    cast r0 into r3 as u8;
+    cast r1 into r4 as field;
+    cast.lossy r1 into r5 as u16;
+    cast r2 into r6 as field;
+    output r3 as u8.private;
+    output r4 as field.private;
+    output r5 as u16.private;
+    output r6 as field.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::from_str("200u64").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("-1i8").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+
+        // Declare the expected output values.
+        let r3 = Value::from_str("200u8").unwrap();
+        let r4 = Value::from_str(&format!("{}", -Field::<CurrentNetwork>::one())).unwrap();
+        let r5 = Value::from_str("65535u16").unwrap();
+        let r6 = Value::from_str(&format!("{}", caller.to_x_coordinate())).unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), [r3.clone(), r4.clone(), r5.clone(), r6.clone()]);
+
+        // Execute the request.
+        let (response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), [r3, r4, r5, r6]);
+        process.verify_execution::<false>(&execution).unwrap();
+
+        // Ensure a checked cast that overflows fails to evaluate.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str("256u64").unwrap(),
+            Value::<CurrentNetwork>::from_str("-1i8").unwrap(),
+            Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(),
+        ];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        assert!(process.evaluate::<CurrentAleo>(authorization).is_err());
+    }
+
+    #[test]
+    fn test_process_execute_hash_and_commit_output_types() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program digests.aleo;
+
+function compute:
+    input r0 as field.private;
+    input r1 as scalar.private;
+    hash.bhp256 r0 into r2 as u64;
+    hash.psd2 r0 into r3 as group;
+    hash.ped64 r0 into r4 as scalar;
+    hash.bhp512 r0 into r5 as address;
+    commit.bhp256 r0 r1 into r6 as u8;
+    commit.ped64 r0 r1 into r7 as address;
+    output r2 as u64.private;
+    output r3 as group.private;
+    output r4 as scalar.private;
+    output r5 as address.private;
+    output r6 as u8.private;
+    output r7 as address.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input values.
+        let input = Field::<CurrentNetwork>::from_u64(12345);
+        let randomizer = Scalar::<CurrentNetwork>::from_str("7scalar").unwrap();
+        let r0 = Value::<CurrentNetwork>::from_str(&input.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str(&randomizer.to_string()).unwrap();
+
+        // Compute the expected output values.
+        let bits = r0.to_bits_le();
+        let fields = r0.to_fields().unwrap();
+        let r2 = Literal::Field(CurrentNetwork::hash_bhp256(&bits).unwrap()).cast_lossy(LiteralType::U64).unwrap();
+        let r3 = Literal::Group(CurrentNetwork::hash_to_group_psd2(&fields).unwrap());
+        let r4 = Literal::Scalar(
+            CurrentNetwork::hash_to_scalar_psd2(&[CurrentNetwork::hash_ped64(&bits).unwrap()]).unwrap(),
+        );
+        let r5 = Literal::Address(Address::new(
+            CurrentNetwork::hash_to_group_psd2(&[CurrentNetwork::hash_bhp512(&bits).unwrap()]).unwrap(),
+        ));
+        let r6 = Literal::Field(CurrentNetwork::commit_bhp256(&bits, &randomizer).unwrap())
+            .cast_lossy(LiteralType::U8)
+            .unwrap();
+        let r7 = Literal::Address(Address::new(CurrentNetwork::commit_ped64(&bits, &randomizer).unwrap()));
+        let expected = [r2, r3, r4, r5, r6, r7].map(|literal| Value::Plaintext(Plaintext::from(literal)));
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), expected);
+
+        // Execute the request.
+        let (response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), expected);
+        process.verify_execution::<false>(&execution).unwrap();
+    }
+
+    #[test]
+    fn test_process_execute_keccak_and_sha_hashes() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program interop.aleo;
+
+function compute:
+    input r0 as u8.private;
+    hash.keccak256 r0 into r1 as [u8; 32];
+    hash.sha256 r0 into r2 as [u128; 2];
+    hash.sha3_256 r0 into r3 as u64;
+    hash.keccak256 r0 into r4 as group;
+    output r1 as [u8; 32].private;
+    output r2 as [u128; 2].private;
+    output r3 as u64.private;
+    output r4 as group.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input value, which is the ASCII string "a".
+        let r0 = Value::<CurrentNetwork>::from_str("97u8").unwrap();
+
+        // Compute the expected output values.
+        // Note: The digests of the raw input bits must match the standard digests of the string "a".
+        // keccak256("a") = 0x3ac225168df54212a25c1c01fd35bebfea408fdac2e31ddd6f80a4bbf9a5f1cb
+        let keccak256: [u8; 32] = [
+            58, 194, 37, 22, 141, 245, 66, 18, 162, 92, 28, 1, 253, 53, 190, 191, 234, 64, 143, 218, 194, 227, 29, 221,
+            111, 128, 164, 187, 249, 165, 241, 203,
+        ];
+        let r1 = Plaintext::Array(
+            keccak256.iter().map(|byte| Plaintext::from(Literal::U8(U8::new(*byte)))).collect(),
+            Default::default(),
+        );
+        // sha256("a") = 0xca978112ca1bbdcafac231b39a23dc4da786eff8147c4e72b9807785afee48bb
+        let r2 = Plaintext::Array(
+            vec![
+                Plaintext::from(Literal::U128(U128::new(103493583125247314185617122984401672138))),
+                Plaintext::from(Literal::U128(U128::new(248944321705313861278653247485190833831))),
+            ],
+            Default::default(),
+        );
+        let bits = [true, false, false, false, false, true, true, false];
+        let to_field = |digest: Vec<bool>| Field::from_bits_le(&digest[..Field::<CurrentNetwork>::SIZE_IN_DATA_BITS]);
+        let r3 = Plaintext::from(
+            Literal::Field(to_field(CurrentNetwork::hash_sha3_256(&bits).unwrap()).unwrap())
+                .cast_lossy(LiteralType::U64)
+                .unwrap(),
+        );
+        let r4 = Plaintext::from(Literal::Group(
+            CurrentNetwork::hash_to_group_psd2(&[to_field(CurrentNetwork::hash_keccak256(&bits).unwrap()).unwrap()])
+                .unwrap(),
+        ));
+        let expected = [r1, r2, r3, r4].map(Value::Plaintext);
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), expected);
+
+        // Execute the request.
+        let (response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), expected);
+        process.verify_execution::<false>(&execution).unwrap();
+    }
+
+    #[test]
+    fn test_process_execute_sign_verify() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program signer.aleo;
+
+function check:
+    input r0 as signature.private;
+    input r1 as address.public;
+    input r2 as u64.public;
+    sign.verify r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("check").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Initialize an off-chain signer, and sign the message.
+        let signer_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let signer = Address::try_from(&signer_private_key).unwrap();
+        let message = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+        let signature = Signature::sign(&signer_private_key, &message.to_fields().unwrap(), rng).unwrap();
+
+        // Declare the input values.
+        let r0 = Value::Plaintext(Plaintext::from(Literal::Signature(signature)));
+        let r1 = Value::Plaintext(Plaintext::from(Literal::Address(signer)));
+
+        for (r2, expected) in [("100u64", true), ("101u64", false)] {
+            let r2 = Value::<CurrentNetwork>::from_str(r2).unwrap();
+            let expected = Value::<CurrentNetwork>::from_str(&expected.to_string()).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(
+                    &caller_private_key,
+                    program.id(),
+                    function_name,
+                    [r0.clone(), r1.clone(), r2].iter(),
+                    rng,
+                )
+                .unwrap();
+
+            // Compute the output value.
+            let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+            assert_eq!(response.outputs(), [expected.clone()]);
+
+            // Execute the request.
+            let (response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            assert_eq!(response.outputs(), [expected]);
+            process.verify_execution::<false>(&execution).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_process_execute_array() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program arrays.aleo;
+
+struct point:
+    x as u64;
+    y as u64;
+
+function sum:
+    input r0 as [u64; 3].private;
+    input r1 as [point; 2].public;
+    add r0[0u32] r0[2u32] into r2;
+    add r2 r1[1u32].y into r3;
+    cast r3 r0[1u32] into r4 as [u64; 2];
+    output r4 as [u64; 2].private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("sum").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::from_str("[1u64, 2u64, 3u64]").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("[{ x: 4u64, y: 5u64 }, { x: 6u64, y: 7u64 }]").unwrap();
+
+        // Declare the expected output value.
+        let expected = Value::<CurrentNetwork>::from_str("[11u64, 2u64]").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), [expected.clone()]);
+
+        // Execute the request.
+        let (response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(response.outputs(), [expected]);
+        process.verify_execution::<false>(&execution).unwrap();
+
+        // Ensure an array of the wrong length is rejected.
+        let r0 = Value::<CurrentNetwork>::from_str("[1u64, 2u64]").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("[{ x: 4u64, y: 5u64 }, { x: 6u64, y: 7u64 }]").unwrap();
+        assert!(
+            process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_process_execute_call_external_function() {
         // Initialize a new program.
@@ -1160,7 +1553,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 0).unwrap();
 
         // Check that the account balance is now 8.
         let candidate =
@@ -1257,7 +1650,7 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 0).unwrap();
 
         // Check that the account balance is now 0.
         let candidate =
@@ -1265,6 +1658,803 @@ finalize compute:
         assert_eq!(candidate, Value::from_str("0u64").unwrap());
     }
 
+    #[test]
+    fn test_process_execute_and_finalize_with_instructions() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    mul r1 2u64 into r2;
+    assert.neq r2 0u64;
+    is.eq r2 16u64 into r3;
+    assert.eq r3 true;
+    increment account[r0] by r2;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Now, finalize the execution.
+        process.finalize_execution(&store, &execution, 0).unwrap();
+
+        // Check that the account balance is now 16.
+        let candidate =
+            store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("16u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_get_set() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+mapping seen:
+    key owner as address.public;
+    value flag as boolean.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    contains account[r0] into r2;
+    assert.eq r2 false;
+    get.or_init account[r0] 10u64 into r3;
+    add r3 r1 into r4;
+    set r4 into account[r0];
+    get account[r0] into r5;
+    assert.eq r5 r4;
+    set true into seen[r0];
+    remove seen[r0];
+    contains seen[r0] into r6;
+    assert.eq r6 false;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (_response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Now, finalize the execution.
+        process.finalize_execution(&store, &execution, 0).unwrap();
+
+        // Check that the account balance is now 15.
+        let key = Plaintext::from(Literal::Address(caller));
+        let candidate = store.get_value(program_id, &Identifier::from_str("account").unwrap(), &key).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("15u64").unwrap()));
+        // Check that the flag was removed.
+        assert!(!store.contains_key(program_id, &Identifier::from_str("seen").unwrap(), &key).unwrap());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_branch_repeat() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to reset;
+    repeat 3u32:
+        add r1 1u64 into r2;
+        increment account[r0] by r2;
+    end.repeat;
+    branch.neq r1 0u64 to done;
+    position reset;
+    set 100u64 into account[r0];
+    position done;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Executes and finalizes `compute` with the given amount.
+        let mut execute = |amount: &str| {
+            // Declare the input value.
+            let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str(amount).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+
+            // Execute the request.
+            let (_response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+
+            // Now, finalize the execution.
+            process.finalize_execution(&store, &execution, 0).unwrap();
+        };
+
+        // Check that the repeat increments the account three times, reassigning 'r2' on each iteration,
+        // and the reset is skipped.
+        execute("5u64");
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("18u64").unwrap()));
+
+        // Check that the repeat is skipped, and the account is reset.
+        execute("0u64");
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("100u64").unwrap()));
+    }
+
+    #[test]
+    fn test_process_finalize_branch_is_forward_only() {
+        // Initialize a program with a branch to a preceding position.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as address.public;
+    position start;
+    increment account[r0] by 1u64;
+    branch.eq r0 r0 to start;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+
+        // Initialize a program with a repeat that exceeds the maximum number of iterations.
+        // Note: As each iteration evaluates a command, the program exceeds the maximum number of commands.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as address.public;
+    repeat {}u32:
+        increment account[r0] by 1u64;
+    end.repeat;
+",
+            CurrentNetwork::MAX_REPEAT_ITERATIONS + 1
+        ));
+
+        // Ensure the program is rejected.
+        assert!(program.is_err());
+
+        // Initialize a program that reads a register defined by a command the branch may skip.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to end;
+    add r1 r1 into r2;
+    position end;
+    increment account[r0] by r2;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        assert!(process.add_program(&program).is_err());
+
+        // Initialize a program that outputs a register defined by a command the branch may skip.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    branch.eq r0 0u64 to end;
+    add r0 r0 into r1;
+    position end;
+    output r1 as u64.public;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is rejected.
+        assert!(process.add_program(&program).is_err());
+
+        // Initialize a program that reads a register defined by a command the branch may skip, before the position.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to end;
+    add r1 r1 into r2;
+    increment account[r0] by r2;
+    position end;
+",
+        )
+        .unwrap();
+
+        // Ensure the program is accepted.
+        assert!(process.add_program(&program).is_ok());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_struct_mapping() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+struct account_state:
+    balance as u64;
+    nonce as u64;
+    frozen as boolean;
+
+mapping account:
+    key owner as address.public;
+    value state as account_state.public;
+
+function deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    cast 0u64 0u64 false into r2 as account_state;
+    get.or_init account[r0] r2 into r3;
+    assert.eq r3.frozen false;
+    add r3.balance r1 into r4;
+    set r4 into account[r0].balance;
+    add r3.nonce 1u64 into r5;
+    set r5 into account[r0].nonce;
+    get account[r0].balance into r6;
+    assert.eq r6 r4;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("deposit").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Deposit twice.
+        for amount in ["5u64", "7u64"] {
+            // Declare the input value.
+            let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str(amount).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+
+            // Execute the request.
+            let (_response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+
+            // Now, finalize the execution.
+            process.finalize_execution(&store, &execution, 0).unwrap();
+        }
+
+        // Check that the balance and nonce were updated member-wise.
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap();
+        let expected = Value::from_str("{ balance: 12u64, nonce: 2u64, frozen: false }").unwrap();
+        assert_eq!(candidate, Some(expected));
+    }
+
+    #[test]
+    fn test_process_upgrade_program() {
+        // Initialize the programs.
+        let program = |functions: &str| {
+            let (string, program) = Program::<CurrentNetwork>::parse(&format!(
+                r"
+program testing.aleo;
+
+mapping counter:
+    key owner as address.public;
+    value count as u64.public;
+
+function bump:
+    input r0 as address.public;
+    finalize r0;
+
+finalize bump:
+    input r0 as address.public;
+    get.or_init counter[r0] 0u64 into r1;
+    add r1 1u64 into r2;
+    set r2 into counter[r0];
+{functions}"
+            ))
+            .unwrap();
+            assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+            program
+        };
+        // The initial edition.
+        let program_v0 = program("");
+        // A compatible upgrade, which adds a function.
+        let program_v1 = program(
+            r"
+function echo:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        );
+        // An incompatible upgrade, which removes the added function.
+        let program_v2 = program("");
+
+        // Declare the program ID.
+        let program_id = program_v0.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("counter").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("bump").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Initialize the owner and caller accounts.
+        let owner_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        // Sample the fee transition ID, which the owner signature is bound to.
+        let fee_id = Field::<CurrentNetwork>::rand(rng).into();
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&other_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Deploy the initial edition, signed by the owner.
+        let deployment = process.deploy::<CurrentAleo, _>(&program_v0, rng).unwrap();
+        let deployment = deployment.sign(&owner_private_key, &fee_id, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        process.finalize_deployment(&store, &deployment).unwrap();
+        assert_eq!(process.get_stack(program_id).unwrap().edition(), 0);
+
+        // Ensure the initial edition cannot be redeployed.
+        assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
+        assert!(process.finalize_deployment(&store, &deployment).is_err());
+
+        // Bumps the counter for the caller.
+        let bump = |process: &Process<CurrentNetwork>, rng: &mut TestRng| {
+            let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&other_private_key, program_id, function_name, [r0].iter(), rng)
+                .unwrap();
+            let (_response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            process.verify_execution::<true>(&execution).unwrap();
+            process.finalize_execution(&store, &execution, 0).unwrap();
+        };
+        bump(&process, rng);
+
+        // Compute the upgrade.
+        let upgrade = process.deploy_upgrade::<CurrentAleo, _>(&program_v1, rng).unwrap();
+        assert_eq!(upgrade.edition(), 1);
+        // Ensure an unsigned upgrade is rejected.
+        assert!(process.verify_deployment::<CurrentAleo, _>(&upgrade, rng).is_err());
+        // Ensure an upgrade signed by another account is rejected.
+        let unauthorized = upgrade.clone().sign(&other_private_key, &fee_id, rng).unwrap();
+        assert!(process.verify_deployment::<CurrentAleo, _>(&unauthorized, rng).is_err());
+        assert!(process.finalize_deployment(&store, &unauthorized).is_err());
+
+        // Upgrade the program, signed by the owner.
+        let upgrade = upgrade.sign(&owner_private_key, &fee_id, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&upgrade, rng).unwrap();
+        process.finalize_deployment(&store, &upgrade).unwrap();
+        assert_eq!(process.get_stack(program_id).unwrap().edition(), 1);
+        let echo = Identifier::from_str("echo").unwrap();
+        assert!(process.get_stack(program_id).unwrap().program().contains_function(&echo));
+
+        // Ensure the existing mapping values are kept across the upgrade.
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("1u64").unwrap()));
+        bump(&process, rng);
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("2u64").unwrap()));
+
+        // Ensure an upgrade that removes a function is rejected.
+        let upgrade = process.deploy_upgrade::<CurrentAleo, _>(&program_v2, rng).unwrap();
+        let upgrade = upgrade.sign(&owner_private_key, &fee_id, rng).unwrap();
+        assert!(process.verify_deployment::<CurrentAleo, _>(&upgrade, rng).is_err());
+        assert!(process.finalize_deployment(&store, &upgrade).is_err());
+        assert_eq!(process.get_stack(program_id).unwrap().edition(), 1);
+    }
+
+    #[test]
+    fn test_process_upgrade_imported_program() {
+        // Initialize the imported program, which adds the given amount to its input.
+        let program = |amount: &str| {
+            let (string, program) = Program::<CurrentNetwork>::parse(&format!(
+                r"
+program testing.aleo;
+
+function compute:
+    input r0 as u64.public;
+    add r0 {amount} into r1;
+    output r1 as u64.public;"
+            ))
+            .unwrap();
+            assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+            program
+        };
+        let program_v0 = program("1u64");
+        let program_v1 = program("2u64");
+
+        // Initialize the importing program.
+        let (string, importer) = Program::<CurrentNetwork>::parse(
+            r"
+import testing.aleo;
+
+program importer.aleo;
+
+function compute:
+    input r0 as u64.public;
+    call testing.aleo/compute r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program IDs.
+        let program_id = program_v0.id();
+        let importer_id = importer.id();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Initialize the owner account.
+        let owner_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        // Sample the fee transition ID, which the owner signature is bound to.
+        let fee_id = Field::<CurrentNetwork>::rand(rng).into();
+
+        // Deploy the initial edition of the imported program, signed by the owner.
+        let deployment = process.deploy::<CurrentAleo, _>(&program_v0, rng).unwrap();
+        let deployment = deployment.sign(&owner_private_key, &fee_id, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Deploy the importing program.
+        let deployment = process.deploy::<CurrentAleo, _>(&importer, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Executes the importing program, and returns its output.
+        let compute = |process: &Process<CurrentNetwork>, rng: &mut TestRng| {
+            let r0 = Value::<CurrentNetwork>::from_str("1u64").unwrap();
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&owner_private_key, importer_id, function_name, [r0].iter(), rng)
+                .unwrap();
+            let (response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            process.verify_execution::<true>(&execution).unwrap();
+            response.outputs().to_vec()
+        };
+        assert_eq!(compute(&process, rng), [Value::from_str("2u64").unwrap()]);
+
+        // Upgrade the imported program, signed by the owner.
+        let upgrade = process.deploy_upgrade::<CurrentAleo, _>(&program_v1, rng).unwrap();
+        let upgrade = upgrade.sign(&owner_private_key, &fee_id, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&upgrade, rng).unwrap();
+        process.finalize_deployment(&store, &upgrade).unwrap();
+
+        // Ensure the importing program resolves its import to the new edition.
+        let importer_stack = process.get_stack(importer_id).unwrap();
+        assert_eq!(importer_stack.get_external_stack(program_id).unwrap().edition(), 1);
+        assert_eq!(importer_stack.get_external_program(program_id).unwrap(), &program_v1);
+        assert_eq!(compute(&process, rng), [Value::from_str("3u64").unwrap()]);
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_block_height() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping claims:
+    key owner as address.public;
+    value height as u32.public;
+
+function claim:
+    input r0 as u32.public;
+    finalize self.caller r0;
+
+finalize claim:
+    input r0 as address.public;
+    input r1 as u32.public;
+    gte block.height r1 into r2;
+    assert.eq r2 true;
+    set block.height into claims[r0];
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("claims").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("claim").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Declare the input value, which unlocks the claim at height 10.
+        let r0 = Value::<CurrentNetwork>::from_str("10u32").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, [r0].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (_response, execution, _inclusion) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Ensure the execution cannot be finalized before the unlock height.
+        assert!(process.finalize_execution(&store, &execution, 9).is_err());
+        assert_eq!(store.get_value(program_id, &mapping_name, &key).unwrap(), None);
+
+        // Finalize the execution at the unlock height.
+        process.finalize_execution(&store, &execution, 12).unwrap();
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap();
+        assert_eq!(candidate, Some(Value::from_str("12u32").unwrap()));
+
+        // Ensure 'block.height' is rejected outside of 'finalize'.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program height.aleo;
+
+function height:
+    input r0 as u32.public;
+    add r0 block.height into r1;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+        assert!(Process::<CurrentNetwork>::load().unwrap().add_program(&program).is_err());
+    }
+
     #[test]
     fn test_process_execute_mint_public() {
         // Initialize a new program.
@@ -1372,7 +2562,7 @@ finalize mint_public:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 0).unwrap();
 
         // Check the account balance.
         let candidate =
@@ -1508,7 +2698,7 @@ function mint:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        process.finalize_execution(&store, &execution, 0).unwrap();
 
         // Check the account balance.
         let candidate =
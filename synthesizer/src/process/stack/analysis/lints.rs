@@ -0,0 +1,440 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The registers assigned and read in a closure, function, or finalize scope.
+struct Scope<N: Network> {
+    /// The mapping of assigned register locators to their span, and whether they hold a newly-created record.
+    assigned: IndexMap<u64, (Span, bool)>,
+    /// The set of read register locators.
+    read: IndexSet<u64>,
+    /// The calls to local closures, as `(closure name, destination locators)`.
+    calls: Vec<(Identifier<N>, Vec<u64>)>,
+}
+
+impl<N: Network> Scope<N> {
+    /// Initializes a new scope.
+    fn new() -> Self {
+        Self { assigned: IndexMap::new(), read: IndexSet::new(), calls: Vec::new() }
+    }
+
+    /// Marks the given register as assigned, if it is not already assigned.
+    fn assign(&mut self, register: &Register<N>, span: Span, is_record: bool) {
+        self.assigned.entry(register.locator()).or_insert((span, is_record));
+    }
+
+    /// Marks the given register as read.
+    fn read(&mut self, register: &Register<N>) {
+        self.read.insert(register.locator());
+    }
+
+    /// Marks the registers in the given operands as read.
+    fn read_operands(&mut self, operands: &[Operand<N>]) {
+        for operand in operands {
+            if let Operand::Register(register) = operand {
+                self.read(register);
+            }
+        }
+    }
+}
+
+/// The static analyzer for the program in a stack.
+pub(super) struct Analyzer<'a, N: Network> {
+    /// The stack of the program.
+    stack: &'a Stack<N>,
+    /// The source map of the program.
+    source_map: SourceMap,
+    /// The reported diagnostics.
+    diagnostics: Vec<Diagnostic>,
+    /// The mapping of closure names to their call sites, as whether each destination is read.
+    calls: IndexMap<Identifier<N>, Vec<Vec<bool>>>,
+    /// The set of struct names that are used in a type.
+    used_structs: IndexSet<Identifier<N>>,
+}
+
+impl<'a, N: Network> Analyzer<'a, N> {
+    /// Initializes a new analyzer, given the stack and the program source.
+    pub(super) fn new(stack: &'a Stack<N>, source: &str) -> Self {
+        Self {
+            stack,
+            source_map: SourceMap::new(source),
+            diagnostics: Vec::new(),
+            calls: IndexMap::new(),
+            used_structs: IndexSet::new(),
+        }
+    }
+
+    /// Analyzes the program, and returns the diagnostics ordered by their position in the source.
+    pub(super) fn run(mut self) -> Result<Vec<Diagnostic>> {
+        let stack = self.stack;
+        let program = stack.program();
+        // Check the closures.
+        for closure in program.closures().values() {
+            self.check_closure(closure)?;
+        }
+        // Check the functions.
+        for function in program.functions().values() {
+            self.check_function(function)?;
+        }
+        // Check the closures are called, and their outputs are used.
+        self.check_closure_usage();
+        // Check the structs are used.
+        self.check_struct_usage();
+
+        // Order the diagnostics by their position in the source.
+        self.diagnostics.sort_by_key(|diagnostic| diagnostic.span());
+        Ok(self.diagnostics)
+    }
+
+    /// Reports a diagnostic for the given lint.
+    fn report(&mut self, lint: Lint, span: Span, message: String) {
+        self.diagnostics.push(Diagnostic::new(lint, span, message));
+    }
+
+    /// Checks the registers and instructions in the given closure.
+    fn check_closure(&mut self, closure: &Closure<N>) -> Result<()> {
+        let stack = self.stack;
+        let name = closure.name();
+        // Retrieve the register types.
+        let register_types = stack.get_register_types(name)?;
+        let operand_type = |operand: &Operand<N>| register_types.get_type_from_operand(stack, operand);
+
+        let mut scope = Scope::new();
+        for input in closure.inputs() {
+            self.use_register_type(input.register_type());
+            scope.assign(input.register(), self.source_map.statement("closure", name, input), false);
+        }
+        for instruction in closure.instructions() {
+            self.check_instruction("closure", name, &operand_type, instruction, &mut scope)?;
+        }
+        for output in closure.outputs() {
+            self.use_register_type(output.register_type());
+            scope.read(output.register());
+        }
+        self.check_scope("closure", name, scope);
+        Ok(())
+    }
+
+    /// Checks the registers and instructions in the given function, and its finalize scope.
+    fn check_function(&mut self, function: &Function<N>) -> Result<()> {
+        let stack = self.stack;
+        let name = function.name();
+        // Retrieve the register types.
+        let register_types = stack.get_register_types(name)?;
+        let operand_type = |operand: &Operand<N>| register_types.get_type_from_operand(stack, operand);
+
+        let mut scope = Scope::new();
+        for input in function.inputs() {
+            self.use_value_type(input.value_type());
+            // Note: Record inputs are consumed by the function, even if they are never read.
+            if !matches!(input.value_type(), ValueType::Record(..) | ValueType::ExternalRecord(..)) {
+                scope.assign(input.register(), self.source_map.statement("function", name, input), false);
+            }
+        }
+        for instruction in function.instructions() {
+            self.check_instruction("function", name, &operand_type, instruction, &mut scope)?;
+        }
+        for output in function.outputs() {
+            self.use_value_type(output.value_type());
+            scope.read(output.register());
+        }
+        if let Some((command, finalize)) = function.finalize() {
+            scope.read_operands(command.operands());
+            self.check_finalize(finalize)?;
+        }
+        self.check_scope("function", name, scope);
+        Ok(())
+    }
+
+    /// Checks the registers and commands in the given finalize scope.
+    fn check_finalize(&mut self, finalize: &Finalize<N>) -> Result<()> {
+        let stack = self.stack;
+        let name = finalize.name();
+        // Retrieve the finalize types.
+        let finalize_types = stack.get_finalize_types(name)?;
+        let operand_type = |operand: &Operand<N>| finalize_types.get_type_from_operand(stack, operand);
+
+        let mut scope = Scope::new();
+        for input in finalize.inputs() {
+            self.use_finalize_type(input.finalize_type());
+            scope.assign(input.register(), self.source_map.statement("finalize", name, input), false);
+        }
+        for command in finalize.commands() {
+            self.check_command(name, &operand_type, command, &mut scope)?;
+        }
+        for output in finalize.outputs() {
+            self.use_finalize_type(output.finalize_type());
+            scope.read(output.register());
+        }
+        self.check_scope("finalize", name, scope);
+        Ok(())
+    }
+
+    /// Checks the registers in the given finalize command.
+    fn check_command(
+        &mut self,
+        name: &Identifier<N>,
+        operand_type: &dyn Fn(&Operand<N>) -> Result<RegisterType<N>>,
+        command: &Command<N>,
+        scope: &mut Scope<N>,
+    ) -> Result<()> {
+        let span = self.source_map.statement("finalize", name, command);
+        match command {
+            Command::Instruction(instruction) => {
+                self.check_instruction("finalize", name, operand_type, instruction, scope)?
+            }
+            Command::Contains(contains) => {
+                scope.read_operands(&contains.operands());
+                scope.assign(contains.destination(), span, false);
+            }
+            Command::Get(get) => {
+                scope.read_operands(&get.operands());
+                scope.assign(get.destination(), span, false);
+            }
+            Command::GetOrInit(get_or_init) => {
+                scope.read_operands(&get_or_init.operands());
+                scope.assign(get_or_init.destination(), span, false);
+            }
+            Command::Decrement(decrement) => scope.read_operands(&decrement.operands()),
+            Command::Increment(increment) => scope.read_operands(&increment.operands()),
+            Command::Remove(remove) => scope.read_operands(&remove.operands()),
+            Command::Set(set) => scope.read_operands(&set.operands()),
+            Command::BranchEq(branch) => scope.read_operands(&branch.operands()),
+            Command::BranchNeq(branch) => scope.read_operands(&branch.operands()),
+            Command::Position(..) => (),
+            Command::Repeat(repeat) => {
+                for command in repeat.commands() {
+                    self.check_command(name, operand_type, command, scope)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the given instruction for failures and redundancies, and records its registers in the scope.
+    fn check_instruction(
+        &mut self,
+        kind: &str,
+        name: &Identifier<N>,
+        operand_type: &dyn Fn(&Operand<N>) -> Result<RegisterType<N>>,
+        instruction: &Instruction<N>,
+        scope: &mut Scope<N>,
+    ) -> Result<()> {
+        let span = self.source_map.statement(kind, name, instruction);
+        // Retrieve the instruction, without its terminator.
+        let statement = instruction.to_string();
+        let statement = statement.trim_end_matches(';');
+
+        // Mark the operands as read.
+        let operands = instruction.operands();
+        scope.read_operands(operands);
+
+        match instruction {
+            // Ensure the divisor is not a constant zero.
+            Instruction::Div(..)
+            | Instruction::DivWrapped(..)
+            | Instruction::Modulo(..)
+            | Instruction::Rem(..)
+            | Instruction::RemWrapped(..) => {
+                if let Some(Operand::Literal(literal)) = operands.get(1) {
+                    if is_zero(literal) {
+                        let message = format!("'{statement}' always fails, as it divides by a constant zero");
+                        self.report(Lint::AlwaysFails, span, message);
+                    }
+                }
+            }
+            // Ensure the assertion is not on constants that never satisfy it.
+            Instruction::AssertEq(..) | Instruction::AssertNeq(..) => {
+                if let [Operand::Literal(first), Operand::Literal(second)] = operands {
+                    if (first == second) == matches!(instruction, Instruction::AssertNeq(..)) {
+                        let message = format!("'{statement}' always fails, as its operands are constants");
+                        self.report(Lint::AlwaysFails, span, message);
+                    }
+                }
+            }
+            // Ensure the cast changes the type of its operand.
+            Instruction::Cast(cast) => {
+                self.use_register_type(cast.register_type());
+                if let [operand] = operands {
+                    let operand_type = operand_type(operand)?;
+                    if operand_type == *cast.register_type() {
+                        let message =
+                            format!("'{statement}' is redundant, as '{operand}' is already a '{operand_type}'");
+                        self.report(Lint::RedundantCast, span, message);
+                    }
+                }
+            }
+            // Record the calls to local closures.
+            Instruction::Call(call) => {
+                if let CallOperator::Resource(resource) = call.operator() {
+                    if self.stack.program().contains_closure(resource) {
+                        let destinations = call.destinations().iter().map(|register| register.locator()).collect();
+                        scope.calls.push((*resource, destinations));
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        // Mark the destinations as assigned.
+        let is_record = match instruction {
+            Instruction::Cast(cast) => matches!(cast.register_type(), RegisterType::Record(..)),
+            _ => false,
+        };
+        for destination in instruction.destinations() {
+            scope.assign(&destination, span, is_record);
+        }
+        Ok(())
+    }
+
+    /// Reports the unused registers and records in the given scope, and records its closure calls.
+    fn check_scope(&mut self, kind: &str, name: &Identifier<N>, scope: Scope<N>) {
+        for (locator, (span, is_record)) in &scope.assigned {
+            if !scope.read.contains(locator) {
+                match is_record {
+                    true => {
+                        let message = format!("Record 'r{locator}' created in {kind} '{name}' is never output or used");
+                        self.report(Lint::UnusedRecord, *span, message)
+                    }
+                    false => {
+                        let message = format!("Register 'r{locator}' in {kind} '{name}' is never used");
+                        self.report(Lint::UnusedRegister, *span, message)
+                    }
+                }
+            }
+        }
+        for (closure, destinations) in scope.calls {
+            let is_read = destinations.iter().map(|locator| scope.read.contains(locator)).collect();
+            self.calls.entry(closure).or_default().push(is_read);
+        }
+    }
+
+    /// Reports the closures that are never called, and the closure outputs that are never read by a caller.
+    fn check_closure_usage(&mut self) {
+        let stack = self.stack;
+        let program = stack.program();
+        for (name, closure) in program.closures() {
+            match self.calls.get(name).cloned() {
+                None => {
+                    let span = self.source_map.header("closure", name);
+                    self.report(Lint::UnusedClosure, span, format!("Closure '{name}' is never called"));
+                }
+                Some(calls) => {
+                    for (index, output) in closure.outputs().iter().enumerate() {
+                        if calls.iter().all(|is_read| !is_read.get(index).copied().unwrap_or(false)) {
+                            let span = self.source_map.statement("closure", name, output);
+                            let register = output.register();
+                            let message = format!("Output '{register}' of closure '{name}' is never used by a caller");
+                            self.report(Lint::DeadOutput, span, message);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reports the structs that are never used in a type.
+    fn check_struct_usage(&mut self) {
+        let stack = self.stack;
+        let program = stack.program();
+        // Mark the structs in the record entries as used.
+        for record in program.records().values() {
+            for entry_type in record.entries().values() {
+                match entry_type {
+                    EntryType::Constant(plaintext_type)
+                    | EntryType::Public(plaintext_type)
+                    | EntryType::Private(plaintext_type) => self.use_plaintext_type(plaintext_type),
+                }
+            }
+        }
+        // Mark the structs in the mappings as used.
+        for mapping in program.mappings().values() {
+            self.use_finalize_type(mapping.key().finalize_type());
+            self.use_finalize_type(mapping.value().finalize_type());
+        }
+        // Mark the members of used structs as used, until no new structs are found.
+        let mut index = 0;
+        while let Some(name) = self.used_structs.get_index(index).copied() {
+            if let Some(struct_) = program.structs().get(&name) {
+                struct_.members().values().for_each(|member_type| self.use_plaintext_type(member_type));
+            }
+            index += 1;
+        }
+        // Report the unused structs.
+        for name in program.structs().keys() {
+            if !self.used_structs.contains(name) {
+                let span = self.source_map.header("struct", name);
+                self.report(Lint::UnusedStruct, span, format!("Struct '{name}' is never used"));
+            }
+        }
+    }
+
+    /// Marks the structs in the given plaintext type as used.
+    fn use_plaintext_type(&mut self, plaintext_type: &PlaintextType<N>) {
+        match plaintext_type {
+            PlaintextType::Literal(..) => (),
+            PlaintextType::Struct(name) => {
+                self.used_structs.insert(*name);
+            }
+            PlaintextType::Array(array_type) => self.use_plaintext_type(&array_type.element_type()),
+        }
+    }
+
+    /// Marks the structs in the given register type as used.
+    fn use_register_type(&mut self, register_type: &RegisterType<N>) {
+        if let RegisterType::Plaintext(plaintext_type) = register_type {
+            self.use_plaintext_type(plaintext_type);
+        }
+    }
+
+    /// Marks the structs in the given value type as used.
+    fn use_value_type(&mut self, value_type: &ValueType<N>) {
+        match value_type {
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type) => self.use_plaintext_type(plaintext_type),
+            ValueType::Record(..) | ValueType::ExternalRecord(..) => (),
+        }
+    }
+
+    /// Marks the structs in the given finalize type as used.
+    fn use_finalize_type(&mut self, finalize_type: &FinalizeType<N>) {
+        if let FinalizeType::Public(plaintext_type) = finalize_type {
+            self.use_plaintext_type(plaintext_type);
+        }
+    }
+}
+
+/// Returns `true` if the given literal is a numeric zero.
+fn is_zero<N: Network>(literal: &Literal<N>) -> bool {
+    match literal {
+        Literal::Field(value) => value.is_zero(),
+        Literal::Group(value) => value.is_zero(),
+        Literal::I8(value) => value.is_zero(),
+        Literal::I16(value) => value.is_zero(),
+        Literal::I32(value) => value.is_zero(),
+        Literal::I64(value) => value.is_zero(),
+        Literal::I128(value) => value.is_zero(),
+        Literal::U8(value) => value.is_zero(),
+        Literal::U16(value) => value.is_zero(),
+        Literal::U32(value) => value.is_zero(),
+        Literal::U64(value) => value.is_zero(),
+        Literal::U128(value) => value.is_zero(),
+        Literal::Scalar(value) => value.is_zero(),
+        _ => false,
+    }
+}
@@ -0,0 +1,344 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod lints;
+use lints::Analyzer;
+
+mod source_map;
+use source_map::SourceMap;
+
+use crate::{CallOperator, Closure, Command, Finalize, Function, Instruction, Operand, Program, Stack};
+use console::{
+    network::prelude::*,
+    program::{EntryType, FinalizeType, Identifier, Literal, PlaintextType, Register, RegisterType, ValueType},
+};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The severity of a diagnostic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The program is well-formed, but likely contains a mistake.
+    Warning,
+    /// The program is well-formed, but contains an instruction that always fails.
+    Error,
+}
+
+impl Display for Severity {
+    /// Prints the severity as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// The lint that reported a diagnostic.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A register that is assigned, but never read.
+    UnusedRegister,
+    /// A closure that is never called.
+    UnusedClosure,
+    /// A struct that is never used in a type.
+    UnusedStruct,
+    /// A closure output that is never read by any caller.
+    DeadOutput,
+    /// An instruction that is guaranteed to fail, such as a division by a constant zero.
+    AlwaysFails,
+    /// A cast of an operand into its own type.
+    RedundantCast,
+    /// A record that is created, but never output or used.
+    UnusedRecord,
+}
+
+impl Lint {
+    /// Returns the name of the lint.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::UnusedRegister => "unused-register",
+            Self::UnusedClosure => "unused-closure",
+            Self::UnusedStruct => "unused-struct",
+            Self::DeadOutput => "dead-output",
+            Self::AlwaysFails => "always-fails",
+            Self::RedundantCast => "redundant-cast",
+            Self::UnusedRecord => "unused-record",
+        }
+    }
+
+    /// Returns the severity of the lint.
+    pub const fn severity(&self) -> Severity {
+        match self {
+            Self::AlwaysFails => Severity::Error,
+            _ => Severity::Warning,
+        }
+    }
+}
+
+impl Display for Lint {
+    /// Prints the lint as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A span in the program source. Lines and columns are 1-indexed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Span {
+    /// The line of the start of the span.
+    line: usize,
+    /// The column of the start of the span.
+    column: usize,
+    /// The number of characters in the span.
+    length: usize,
+}
+
+impl Span {
+    /// Initializes a new span.
+    pub const fn new(line: usize, column: usize, length: usize) -> Self {
+        Self { line, column, length }
+    }
+
+    /// Returns the line of the start of the span.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column of the start of the span.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the number of characters in the span.
+    pub const fn length(&self) -> usize {
+        self.length
+    }
+}
+
+/// A diagnostic reported by the static analysis of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The lint that reported the diagnostic.
+    lint: Lint,
+    /// The span of the diagnostic in the program source.
+    span: Span,
+    /// The message of the diagnostic.
+    message: String,
+}
+
+impl Diagnostic {
+    /// Initializes a new diagnostic.
+    pub const fn new(lint: Lint, span: Span, message: String) -> Self {
+        Self { lint, span, message }
+    }
+
+    /// Returns the lint that reported the diagnostic.
+    pub const fn lint(&self) -> Lint {
+        self.lint
+    }
+
+    /// Returns the severity of the diagnostic.
+    pub const fn severity(&self) -> Severity {
+        self.lint.severity()
+    }
+
+    /// Returns the span of the diagnostic in the program source.
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the message of the diagnostic.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for Diagnostic {
+    /// Prints the diagnostic as a string, i.e. `warning[unused-register]: <message> (at 7:5)`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}[{}]: {} (at {}:{})", self.severity(), self.lint, self.message, self.span.line, self.span.column)
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the diagnostics from a static analysis of the program, given the program source.
+    /// The diagnostics are ordered by their position in the source.
+    #[inline]
+    pub fn analyze(&self, source: &str) -> Result<Vec<Diagnostic>> {
+        // Ensure the source corresponds to the program, so that the spans are accurate.
+        ensure!(
+            Program::<N>::from_str(source)? == self.program,
+            "The given source does not match program '{}'",
+            self.program.id()
+        );
+        // Analyze the program.
+        Analyzer::new(self, source).run()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const SOURCE: &str = r"
+program analysis.aleo;
+
+struct point:
+    x as u32;
+    y as u32;
+
+struct unused_point:
+    x as u32;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+
+closure double:
+    input r0 as u32;
+    input r1 as u32;
+    add r0 r0 into r2;
+    add r1 r1 into r3;
+    output r2 as u32;
+    output r3 as u32;
+
+closure unused:
+    input r0 as u32;
+    output r0 as u32;
+
+function compute:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    input r2 as point.private;
+    call double r0 r0 into r3 r4;
+    div r3 0u32 into r5;
+    cast r1 into r6 as u32;
+    cast self.caller 0u64 into r7 as token.record;
+    assert.eq 1u8 2u8;
+    output r6 as u32.public;";
+
+    #[test]
+    fn test_analyze() {
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let program = Program::<CurrentNetwork>::from_str(SOURCE).unwrap();
+        process.add_program(&program).unwrap();
+
+        // Analyze the program.
+        let diagnostics = process.get_stack(program.id()).unwrap().analyze(SOURCE).unwrap();
+        let lints =
+            diagnostics.iter().map(|diagnostic| (diagnostic.lint(), diagnostic.span().line())).collect::<Vec<_>>();
+        assert_eq!(lints, vec![
+            (Lint::UnusedStruct, 8),
+            (Lint::DeadOutput, 21),
+            (Lint::UnusedClosure, 23),
+            (Lint::UnusedRegister, 30),
+            (Lint::UnusedRegister, 31),
+            (Lint::AlwaysFails, 32),
+            (Lint::UnusedRegister, 32),
+            (Lint::RedundantCast, 33),
+            (Lint::UnusedRecord, 34),
+            (Lint::AlwaysFails, 35),
+        ]);
+
+        // Ensure the spans and messages are accurate.
+        let division = &diagnostics[5];
+        assert_eq!(division.span(), Span::new(32, 5, 20));
+        assert_eq!(division.severity(), Severity::Error);
+        assert_eq!(
+            division.to_string(),
+            "error[always-fails]: 'div r3 0u32 into r5' always fails, as it divides by a constant zero (at 32:5)"
+        );
+        assert_eq!(diagnostics[0].span(), Span::new(8, 1, 20));
+        assert_eq!(diagnostics[0].severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn test_analyze_fails_on_mismatched_source() {
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let program = Program::<CurrentNetwork>::from_str(SOURCE).unwrap();
+        process.add_program(&program).unwrap();
+
+        // Ensure the analysis fails on the source of another program.
+        let stack = process.get_stack(program.id()).unwrap();
+        assert!(stack.analyze(&SOURCE.replace("assert.eq 1u8 2u8;", "")).is_err());
+    }
+
+    #[test]
+    fn test_source_map_repeat() {
+        let source = r"
+program analysis.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as address.public;
+    increment account[r0] by 1u64;
+    repeat 2u32:
+        increment account[r0] by 1u64;
+        div 1u64 0u64 into r1;
+    end.repeat;";
+        let program = Program::<CurrentNetwork>::from_str(source).unwrap();
+        let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+        let finalize = function.finalize_logic().unwrap();
+        let (name, commands) = (finalize.name(), finalize.commands());
+
+        // Ensure the repeat is matched line by line, and its span is that of its first line.
+        let source_map = SourceMap::new(source);
+        assert_eq!(source_map.statement("finalize", name, &commands[1]), Span::new(15, 5, 12));
+        // Ensure a command in the body is matched.
+        let body = match &commands[1] {
+            Command::Repeat(repeat) => repeat.commands(),
+            _ => unreachable!("The second command is a repeat"),
+        };
+        assert_eq!(source_map.statement("finalize", name, &body[1]), Span::new(17, 9, 22));
+
+        // Ensure a repeat that differs from the source is not matched.
+        let other = Command::<CurrentNetwork>::from_str("repeat 3u32: increment account[r0] by 1u64; end.repeat;");
+        assert_eq!(source_map.statement("finalize", name, &other.unwrap()), source_map.header("finalize", name));
+    }
+
+    #[test]
+    fn test_source_map_string_literal() {
+        let source = r#"
+program analysis.aleo;
+
+function compute:
+    input r0 as field.public;
+    // A comment with a "quote: and a terminator;
+    is.eq "a: b; // c" "a: \"d;\"" into r1;
+    div 1u64 0u64 into r2;"#;
+
+        // Ensure the terminators and comments inside of the string literals do not split the statement.
+        let source_map = SourceMap::new(source);
+        let statement = r#"is.eq "a: b; // c" "a: \"d;\"" into r1;"#;
+        assert_eq!(source_map.statement("function", &"compute", &statement), Span::new(7, 5, 39));
+        // Ensure the next statement is matched.
+        assert_eq!(source_map.statement("function", &"compute", &"div 1u64 0u64 into r2;"), Span::new(8, 5, 22));
+    }
+}
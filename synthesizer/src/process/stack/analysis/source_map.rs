@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A statement in the program source, i.e. the text up to a `;` or `:` terminator outside of a string literal.
+struct Statement {
+    /// The statement, with its whitespace normalized and without its terminator.
+    text: String,
+    /// The span of the statement in the source.
+    span: Span,
+}
+
+/// A map from the scopes and statements of a program to their spans in the source.
+pub(super) struct SourceMap {
+    /// The mapping of `(scope kind, scope name)` to the header span and the statements in the scope.
+    scopes: IndexMap<(String, String), (Span, Vec<Statement>)>,
+}
+
+impl SourceMap {
+    /// The keywords that open a new scope.
+    const SCOPES: [&'static str; 6] = ["closure", "finalize", "function", "mapping", "record", "struct"];
+
+    /// Initializes a new source map from the given program source.
+    pub(super) fn new(source: &str) -> Self {
+        let mut scopes: IndexMap<(String, String), (Span, Vec<Statement>)> = IndexMap::new();
+        // The scope of the current statement.
+        let mut scope = None;
+        // The text and starting position (line, column, remaining line length) of the current statement.
+        let mut text = String::new();
+        let mut start = None;
+        // Whether the scanner is inside of a block comment.
+        let mut in_block_comment = false;
+        // Whether the scanner is inside of a string literal.
+        let mut in_string = false;
+
+        for (line_index, line) in source.lines().enumerate() {
+            let mut chars = line.char_indices().peekable();
+            while let Some((column_index, character)) = chars.next() {
+                // Skip the contents of block comments.
+                if in_block_comment {
+                    if character == '*' && matches!(chars.peek(), Some((_, '/'))) {
+                        chars.next();
+                        in_block_comment = false;
+                    }
+                    continue;
+                }
+                // Extend the current statement with the contents of string literals, which may contain terminators.
+                if in_string {
+                    text.push(character);
+                    match character {
+                        // Extend the current statement with the escaped character.
+                        '\\' => {
+                            if let Some((_, escaped)) = chars.next() {
+                                text.push(escaped);
+                            }
+                        }
+                        // Exit the string literal.
+                        '"' => in_string = false,
+                        _ => (),
+                    }
+                    continue;
+                }
+                match character {
+                    // Skip the remainder of the line for line comments.
+                    '/' if matches!(chars.peek(), Some((_, '/'))) => break,
+                    // Enter a block comment.
+                    '/' if matches!(chars.peek(), Some((_, '*'))) => {
+                        chars.next();
+                        in_block_comment = true;
+                    }
+                    // Complete the current statement.
+                    ';' | ':' => {
+                        let (start_line, start_column, remainder) =
+                            start.take().unwrap_or((line_index + 1, column_index + 1, line.len() - column_index));
+                        // Note: The span of a statement across multiple lines ends at the end of its first line.
+                        let length = match start_line == line_index + 1 {
+                            true => column_index + 2 - start_column,
+                            false => remainder,
+                        };
+                        let statement = Statement {
+                            text: text.split_whitespace().collect::<Vec<_>>().join(" "),
+                            span: Span::new(start_line, start_column, length),
+                        };
+                        text.clear();
+
+                        // If the statement opens a new scope, record the scope header.
+                        let mut words = statement.text.split(' ');
+                        if let (true, Some(kind), Some(name)) = (character == ':', words.next(), words.next()) {
+                            if Self::SCOPES.contains(&kind) {
+                                let key = (kind.to_string(), name.to_string());
+                                scopes.insert(key.clone(), (statement.span, Vec::new()));
+                                scope = Some(key);
+                                continue;
+                            }
+                        }
+                        // Otherwise, add the statement to the current scope.
+                        if let Some((_, statements)) = scope.as_ref().and_then(|key| scopes.get_mut(key)) {
+                            statements.push(statement);
+                        }
+                    }
+                    // Extend the current statement, and enter a string literal if the character is a quote.
+                    _ => {
+                        if start.is_none() && !character.is_whitespace() {
+                            start = Some((line_index + 1, column_index + 1, line.len() - column_index));
+                        }
+                        in_string = character == '"';
+                        text.push(character);
+                    }
+                }
+            }
+            // Note: A string literal does not span multiple lines.
+            in_string = false;
+            text.push(' ');
+        }
+        Self { scopes }
+    }
+
+    /// Returns the span of the header of the given scope.
+    pub(super) fn header(&self, kind: &str, name: &impl Display) -> Span {
+        match self.scopes.get(&(kind.to_string(), name.to_string())) {
+            Some((span, _)) => *span,
+            None => Span::new(1, 1, 0),
+        }
+    }
+
+    /// Returns the span of the given statement in the given scope.
+    /// If the statement is not found, this method returns the span of the scope header.
+    ///
+    /// Note: A statement that is displayed across multiple lines (i.e. a `repeat`) is matched line by line,
+    /// against consecutive statements in the scope, and the span of its first line is returned.
+    pub(super) fn statement(&self, kind: &str, name: &impl Display, statement: &impl Display) -> Span {
+        // Normalize each line of the statement.
+        let statement = statement.to_string();
+        let lines = statement.lines().map(Self::normalize).filter(|line| !line.is_empty()).collect::<Vec<_>>();
+        // Returns `true` if the given consecutive statements match the lines of the statement.
+        let is_match = |candidates: &&[Statement]| candidates.iter().zip(&lines).all(|(c, line)| c.text == *line);
+        // Search the scope for the statement.
+        match self.scopes.get(&(kind.to_string(), name.to_string())) {
+            Some((header, _)) if lines.is_empty() => *header,
+            Some((header, statements)) => match statements.windows(lines.len()).find(is_match) {
+                Some(candidates) => candidates[0].span,
+                None => *header,
+            },
+            None => Span::new(1, 1, 0),
+        }
+    }
+
+    /// Returns the given line of a statement, with its whitespace normalized and without its terminator.
+    fn normalize(line: &str) -> String {
+        line.trim_end().trim_end_matches([';', ':']).split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
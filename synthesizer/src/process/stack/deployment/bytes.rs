@@ -22,7 +22,7 @@ impl<N: Network> FromBytes for Deployment<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid deployment version"));
         }
 
@@ -46,15 +46,23 @@ impl<N: Network> FromBytes for Deployment<N> {
             bundle.insert(identifier, (verifying_key, certificate));
         }
 
-        Ok(Self { edition, program, verifying_keys: bundle })
+        // Read the owner, which is only present in version 1.
+        let owner = match version {
+            0 => None,
+            _ => Some((Address::read_le(&mut reader)?, Signature::read_le(&mut reader)?)),
+        };
+
+        Self::new(edition, program, bundle)
+            .map(|deployment| deployment.with_owner(owner))
+            .map_err(|e| error(e.to_string()))
     }
 }
 
 impl<N: Network> ToBytes for Deployment<N> {
     /// Writes the deployment to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        0u16.write_le(&mut writer)?;
+        // Write the version, which is `1` if the deployment has an owner.
+        (self.owner.is_some() as u16).write_le(&mut writer)?;
         // Write the edition.
         self.edition.write_le(&mut writer)?;
         // Write the program.
@@ -70,6 +78,13 @@ impl<N: Network> ToBytes for Deployment<N> {
             // Write the certificate.
             certificate.write_le(&mut writer)?;
         }
+        // Write the owner, if one is present.
+        if let Some((address, signature)) = &self.owner {
+            // Write the address.
+            address.write_le(&mut writer)?;
+            // Write the signature.
+            signature.write_le(&mut writer)?;
+        }
         Ok(())
     }
 }
@@ -92,4 +107,28 @@ mod tests {
         assert!(Deployment::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_owner() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sign a new deployment.
+        let private_key = console::account::PrivateKey::<CurrentNetwork>::new(rng)?;
+        let fee_id = Field::<CurrentNetwork>::rand(rng).into();
+        let expected = test_helpers::sample_deployment().sign(&private_key, &fee_id, rng)?;
+        assert!(expected.is_owner_valid(&fee_id)?);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
+
+        // Ensure a deployment with a mismatched edition is rejected.
+        let mut tampered_bytes = expected_bytes.clone();
+        tampered_bytes[2] = tampered_bytes[2].wrapping_add(1);
+        assert!(!Deployment::<CurrentNetwork>::read_le(&tampered_bytes[..])?.is_owner_valid(&fee_id)?);
+
+        // Ensure the signature is rejected for another fee.
+        assert!(!expected.is_owner_valid(&Field::<CurrentNetwork>::rand(rng).into())?);
+        Ok(())
+    }
 }
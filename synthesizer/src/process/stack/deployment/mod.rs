@@ -20,8 +20,10 @@ mod string;
 
 use crate::{Certificate, Program, VerifyingKey};
 use console::{
+    account::{Address, PrivateKey, Signature},
     network::prelude::*,
     program::{Identifier, ProgramID},
+    types::Field,
 };
 
 use indexmap::IndexMap;
@@ -34,6 +36,8 @@ pub struct Deployment<N: Network> {
     program: Program<N>,
     /// The mapping of function names to their verifying key and certificate.
     verifying_keys: IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)>,
+    /// The owner of the program and their signature over the deployment, if the program is upgradable.
+    owner: Option<(Address<N>, Signature<N>)>,
 }
 
 impl<N: Network> Deployment<N> {
@@ -43,7 +47,67 @@ impl<N: Network> Deployment<N> {
         program: Program<N>,
         verifying_keys: IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)>,
     ) -> Result<Self> {
-        Ok(Self { edition, program, verifying_keys })
+        Ok(Self { edition, program, verifying_keys, owner: None })
+    }
+
+    /// Signs the deployment with the given private key, which marks the signer as the owner of the program.
+    /// The signature is bound to the given fee transition ID, so it is only valid in the transaction with that fee.
+    /// Only the owner is permitted to upgrade the program in a later edition.
+    pub fn sign<R: Rng + CryptoRng>(
+        mut self,
+        private_key: &PrivateKey<N>,
+        fee_id: &N::TransitionID,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Compute the owner address.
+        let address = Address::try_from(private_key)?;
+        // Sign the deployment message.
+        let signature = Signature::sign(private_key, &self.to_owner_message(fee_id)?, rng)?;
+        // Set the owner.
+        self.owner = Some((address, signature));
+        Ok(self)
+    }
+
+    /// Initializes a new deployment with the given owner and signature.
+    /// Note: The owner signature is not checked here, as it is bound to the fee of the transaction,
+    /// and must be checked with `Deployment::is_owner_valid`.
+    pub fn with_owner(mut self, owner: Option<(Address<N>, Signature<N>)>) -> Self {
+        // Set the owner.
+        self.owner = owner;
+        self
+    }
+
+    /// Returns `true` if the deployment has no owner, or if the owner signature is valid for the given fee transition ID.
+    pub fn is_owner_valid(&self, fee_id: &N::TransitionID) -> Result<bool> {
+        match &self.owner {
+            Some((address, signature)) => Ok(signature.verify(address, &self.to_owner_message(fee_id)?)),
+            None => Ok(true),
+        }
+    }
+
+    /// Returns the ID of the edition and owner, which is committed to in the deployment tree.
+    pub fn to_owner_id(&self) -> Result<Field<N>> {
+        // Prepare the bits of the edition.
+        let mut bits = self.edition.to_bits_le();
+        // Append the bits of the owner and their signature, if one is present.
+        match &self.owner {
+            Some((address, signature)) => {
+                bits.push(true);
+                bits.extend(address.to_bits_le());
+                bits.extend(signature.to_bits_le());
+            }
+            None => bits.push(false),
+        }
+        // Return the ID.
+        N::hash_bhp1024(&bits)
+    }
+
+    /// Returns the message signed by the owner, which commits to the program, the edition, and the fee transition ID.
+    fn to_owner_message(&self, fee_id: &N::TransitionID) -> Result<Vec<Field<N>>> {
+        // Hash the program bytes.
+        let program_hash = N::hash_bhp1024(&self.program.to_bytes_le()?.to_bits_le())?;
+        // Return the message.
+        Ok(vec![program_hash, Field::from_u16(self.edition), **fee_id])
     }
 
     /// Returns the edition.
@@ -65,6 +129,11 @@ impl<N: Network> Deployment<N> {
     pub const fn verifying_keys(&self) -> &IndexMap<Identifier<N>, (VerifyingKey<N>, Certificate<N>)> {
         &self.verifying_keys
     }
+
+    /// Returns the owner of the program, if the program is upgradable.
+    pub const fn owner(&self) -> Option<&(Address<N>, Signature<N>)> {
+        self.owner.as_ref()
+    }
 }
 
 #[cfg(test)]
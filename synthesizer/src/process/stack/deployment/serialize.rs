@@ -21,10 +21,13 @@ impl<N: Network> Serialize for Deployment<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut deployment = serializer.serialize_struct("Deployment", 3)?;
+                let mut deployment = serializer.serialize_struct("Deployment", 3 + self.owner.is_some() as usize)?;
                 deployment.serialize_field("edition", &self.edition)?;
                 deployment.serialize_field("program", &self.program)?;
                 deployment.serialize_field("verifying_keys", &self.verifying_keys)?;
+                if let Some(owner) = &self.owner {
+                    deployment.serialize_field("owner", owner)?;
+                }
                 deployment.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -49,7 +52,12 @@ impl<'de, N: Network> Deserialize<'de> for Deployment<N> {
                     // Retrieve the verifying keys.
                     serde_json::from_value(deployment["verifying_keys"].take()).map_err(de::Error::custom)?,
                 )
-                .map_err(de::Error::custom)?;
+                .map_err(de::Error::custom)?
+                // Retrieve the owner, if one is present.
+                .with_owner(match deployment.get_mut("owner") {
+                    Some(owner) => Some(serde_json::from_value(owner.take()).map_err(de::Error::custom)?),
+                    None => None,
+                });
 
                 Ok(deployment)
             }
@@ -17,10 +17,11 @@
 mod load;
 mod store;
 
-use crate::{FinalizeTypes, Operand, Stack};
+use crate::{FinalizeTypes, Operand, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
+    types::{Address, Field, U32},
 };
 
 use indexmap::IndexMap;
@@ -31,12 +32,47 @@ pub struct FinalizeRegisters<N: Network> {
     finalize_types: FinalizeTypes<N>,
     /// The mapping of assigned registers to their values.
     registers: IndexMap<u64, Value<N>>,
+    /// The height of the block being finalized.
+    block_height: u32,
 }
 
 impl<N: Network> FinalizeRegisters<N> {
-    /// Initializes a new set of registers, given the finalize types.
+    /// Initializes a new set of registers, given the finalize types and the height of the block being finalized.
     #[inline]
-    pub fn new(finalize_types: FinalizeTypes<N>) -> Self {
-        Self { finalize_types, registers: IndexMap::new() }
+    pub fn new(finalize_types: FinalizeTypes<N>, block_height: u32) -> Self {
+        Self { finalize_types, registers: IndexMap::new(), block_height }
+    }
+
+    /// Returns the height of the block being finalized.
+    #[inline]
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the number of assigned registers.
+    #[inline]
+    pub(crate) fn num_assigned(&self) -> usize {
+        self.registers.len()
+    }
+
+    /// Unassigns all registers after the first `num_assigned` assigned registers.
+    /// Note: This is used to reassign the registers in the body of a `repeat` on each iteration.
+    #[inline]
+    pub(crate) fn truncate(&mut self, num_assigned: usize) {
+        self.registers.truncate(num_assigned)
+    }
+}
+
+impl<N: Network> RegistersCaller<N> for FinalizeRegisters<N> {
+    /// Returns the transition caller.
+    #[inline]
+    fn caller(&self) -> Result<Address<N>> {
+        bail!("Forbidden operation: Cannot access the transition caller in 'finalize'")
+    }
+
+    /// Returns the transition view key.
+    #[inline]
+    fn tvk(&self) -> Result<Field<N>> {
+        bail!("Forbidden operation: Cannot access the transition view key in 'finalize'")
     }
 }
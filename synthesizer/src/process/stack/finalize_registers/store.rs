@@ -16,33 +16,27 @@
 
 use super::*;
 
-impl<N: Network> FinalizeRegisters<N> {
-    /// Assigns the given literal to the given register, assuming the register is not already assigned.
-    ///
-    /// # Errors
-    /// This method will halt if the given register is a register member.
-    /// This method will halt if the given register is an input register.
-    /// This method will halt if the register is already used.
-    #[inline]
-    pub fn store_literal(&mut self, stack: &Stack<N>, register: &Register<N>, literal: Literal<N>) -> Result<()> {
-        self.store(stack, register, Value::Plaintext(Plaintext::from(literal)))
-    }
-
+impl<N: Network> RegistersStore<N> for FinalizeRegisters<N> {
     /// Assigns the given value to the given register, assuming the register is not already assigned.
     ///
+    /// Note: As finalize may branch over commands, the assigned registers may skip locators.
+    /// A register in the body of a `repeat` is unassigned before each iteration, see `Repeat::evaluate_finalize`.
+    ///
     /// # Errors
     /// This method will halt if the given register is a register member.
-    /// This method will halt if the given register is an input register.
     /// This method will halt if the register is already used.
+    /// This method will halt if the register is assigned out of order.
+    /// This method will halt if the given register is not defined in `FinalizeTypes`.
     #[inline]
-    pub fn store(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: Value<N>) -> Result<()> {
+    fn store(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: Value<N>) -> Result<()> {
         match register {
             Register::Locator(locator) => {
-                // Ensure the register assignments are monotonically increasing.
-                let expected_locator = self.registers.len() as u64;
-                ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
                 // Ensure the register does not already exist.
                 ensure!(!self.registers.contains_key(locator), "Cannot write to occupied register '{register}'");
+                // Ensure the register assignments are monotonically increasing.
+                if let Some(last_locator) = self.registers.keys().last() {
+                    ensure!(*locator > *last_locator, "Out-of-order write operation at '{register}'");
+                }
 
                 // Ensure the register type is valid.
                 match self.finalize_types.get_type(stack, register) {
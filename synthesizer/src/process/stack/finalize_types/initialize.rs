@@ -31,9 +31,9 @@ impl<N: Network> FinalizeTypes<N> {
         }
 
         // Step 2. Check the commands are well-formed.
-        for command in finalize.commands() {
+        for (index, command) in finalize.commands().iter().enumerate() {
             // Check the command opcode, operands, and destinations.
-            finalize_types.check_command(stack, finalize.name(), command)?;
+            finalize_types.check_command(stack, finalize, index, command)?;
         }
 
         // Step 3. Check the outputs are well-formed.
@@ -109,6 +109,14 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct, if any, is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -155,6 +163,14 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct, if any, is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -180,13 +196,281 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
-    /// Ensures the given command is well-formed.
+    /// Ensures the given command, at the given command index in finalize, is well-formed.
     #[inline]
-    fn check_command(&mut self, stack: &Stack<N>, finalize_name: &Identifier<N>, command: &Command<N>) -> Result<()> {
+    fn check_command(
+        &mut self,
+        stack: &Stack<N>,
+        finalize: &Finalize<N>,
+        index: usize,
+        command: &Command<N>,
+    ) -> Result<()> {
+        // Retrieve the finalize name.
+        let finalize_name = finalize.name();
+
         match command {
             Command::Decrement(decrement) => self.check_decrement(stack, finalize_name, decrement)?,
             Command::Instruction(instruction) => self.check_instruction(stack, finalize_name, instruction)?,
             Command::Increment(increment) => self.check_increment(stack, finalize_name, increment)?,
+            Command::Contains(contains) => self.check_contains(stack, finalize_name, contains)?,
+            Command::Get(get) => self.check_get(stack, finalize_name, get)?,
+            Command::GetOrInit(get_or_init) => self.check_get_or_init(stack, finalize_name, get_or_init)?,
+            Command::Remove(remove) => self.check_remove(stack, finalize_name, remove)?,
+            Command::Set(set) => self.check_set(stack, finalize_name, set)?,
+            Command::BranchEq(branch_eq) => self.check_branch(stack, finalize, index, branch_eq)?,
+            Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, index, branch_neq)?,
+            // Note: The uniqueness of positions is enforced when the command is added to finalize.
+            Command::Position(..) => (),
+            Command::Repeat(repeat) => self.check_repeat(stack, finalize, index, repeat)?,
+        }
+        Ok(())
+    }
+
+    /// Ensures the given branch command, at the given command index in finalize, is well-formed.
+    /// Note: Branches may only jump forward, which guarantees that finalize terminates.
+    #[inline]
+    fn check_branch<const VARIANT: u8>(
+        &self,
+        stack: &Stack<N>,
+        finalize: &Finalize<N>,
+        index: usize,
+        branch: &Branch<N, VARIANT>,
+    ) -> Result<()> {
+        // Ensure the position is defined in finalize, and is strictly after the branch.
+        let position_index = match finalize.positions().get(branch.position()) {
+            Some(position_index) if *position_index > index => *position_index,
+            Some(..) => bail!("Branch '{branch}' in '{}/{}' must jump forward", stack.program_id(), finalize.name()),
+            None => bail!(
+                "Position '{}' in '{}/{}' is not defined.",
+                branch.position(),
+                stack.program_id(),
+                finalize.name()
+            ),
+        };
+
+        // Retrieve the locators of the registers defined by the commands that the branch may skip.
+        let skipped_locators = finalize.commands()[index + 1..position_index]
+            .iter()
+            .flat_map(|command| command.destinations())
+            .map(|register| register.locator())
+            .collect::<IndexSet<_>>();
+        // Ensure the registers defined by the skipped commands are not read at or after the position,
+        // as they are unassigned when the branch is taken.
+        let operands = finalize.commands()[position_index..].iter().flat_map(|command| command.operands());
+        for register in operands.filter_map(|operand| match operand {
+            Operand::Register(register) => Some(register),
+            _ => None,
+        }) {
+            if skipped_locators.contains(&register.locator()) {
+                bail!(
+                    "Register '{register}' in '{}/{}' may be skipped by '{branch}'",
+                    stack.program_id(),
+                    finalize.name()
+                )
+            }
+        }
+        for output in finalize.outputs() {
+            if skipped_locators.contains(&output.register().locator()) {
+                bail!(
+                    "Output '{}' in '{}/{}' may be skipped by '{branch}'",
+                    output.register(),
+                    stack.program_id(),
+                    finalize.name()
+                )
+            }
+        }
+
+        // Ensure the operands are of the same type.
+        let first_type = self.get_type_from_operand(stack, branch.first())?;
+        let second_type = self.get_type_from_operand(stack, branch.second())?;
+        if first_type != second_type {
+            bail!("Operands in '{branch}' must be of the same type, found '{first_type}' and '{second_type}'")
+        }
+        Ok(())
+    }
+
+    /// Ensures the given repeat command, at the given command index in finalize, is well-formed.
+    /// Note: The number of iterations is a bounded constant, which guarantees that finalize terminates.
+    #[inline]
+    fn check_repeat(
+        &mut self,
+        stack: &Stack<N>,
+        finalize: &Finalize<N>,
+        index: usize,
+        repeat: &Repeat<N>,
+    ) -> Result<()> {
+        // Ensure the number of iterations is within bounds.
+        let count = **repeat.count() as usize;
+        if count == 0 || count > N::MAX_REPEAT_ITERATIONS {
+            bail!(
+                "Repeat in '{}/{}' must iterate 1 to {} times",
+                stack.program_id(),
+                finalize.name(),
+                N::MAX_REPEAT_ITERATIONS
+            )
+        }
+        // Ensure the body is not empty.
+        ensure!(
+            !repeat.commands().is_empty(),
+            "Repeat in '{}/{}' must contain commands",
+            stack.program_id(),
+            finalize.name()
+        );
+
+        // Ensure the unrolled body is within bounds.
+        ensure!(
+            repeat.num_evaluated_commands() <= N::MAX_COMMANDS,
+            "Repeat in '{}/{}' cannot evaluate more than {} commands",
+            stack.program_id(),
+            finalize.name(),
+            N::MAX_COMMANDS
+        );
+
+        // Check the commands in the body.
+        // Note: Destination registers in the body are typed once, and reassigned on each iteration.
+        for command in repeat.commands() {
+            match command.is_control_flow() {
+                true => bail!("Repeat in '{}/{}' cannot contain '{command}'", stack.program_id(), finalize.name()),
+                false => self.check_command(stack, finalize, index, command)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the given mapping is defined in the program, and the given key operand matches the mapping key type.
+    /// Returns the mapping value type as a register type.
+    #[inline]
+    fn check_mapping_key(
+        &self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        mapping_name: &Identifier<N>,
+        key: &Operand<N>,
+    ) -> Result<RegisterType<N>> {
+        // Ensure the declared mapping is defined in the program.
+        if !stack.program().contains_mapping(mapping_name) {
+            bail!("Mapping '{mapping_name}' in '{}/{finalize_name}' is not defined.", stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        let mapping = stack.program().get_mapping(mapping_name)?;
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, key)?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(*mapping.key().finalize_type());
+        if key_type != mapping_key_type {
+            bail!(
+                "Key type in '{}/{finalize_name}' must be '{mapping_key_type}', found '{key_type}'",
+                stack.program_id()
+            )
+        }
+
+        // Return the mapping value type.
+        Ok(RegisterType::from(*mapping.value().finalize_type()))
+    }
+
+    /// Returns the type of the member at the given path, in a mapping value of the given type.
+    /// If the path is empty, the mapping value type is returned.
+    #[inline]
+    fn get_mapping_member_type(
+        &self,
+        stack: &Stack<N>,
+        value_type: RegisterType<N>,
+        path: &[Access<N>],
+    ) -> Result<RegisterType<N>> {
+        // Initialize a tracker for the member type.
+        let mut member_type = value_type;
+
+        // Traverse the member path to find the member type.
+        for access in path.iter() {
+            member_type = match (&member_type, access) {
+                // Retrieve the member type from the struct.
+                (RegisterType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(member_name)) => {
+                    match stack.program().get_struct(struct_name)?.members().get(member_name) {
+                        Some(plaintext_type) => RegisterType::Plaintext(*plaintext_type),
+                        None => bail!("'{member_name}' does not exist in struct '{struct_name}'"),
+                    }
+                }
+                // Retrieve the element type from the array.
+                (RegisterType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
+                    // Ensure the index is within the bounds of the array.
+                    ensure!(**index < array_type.length(), "'{index}' is out of bounds for array '{array_type}'");
+                    RegisterType::Plaintext(array_type.element_type())
+                }
+                // Ensure the access matches the member type.
+                _ => bail!("Cannot apply '{access}' to a mapping value of type '{member_type}'"),
+            };
+        }
+        // Output the member type.
+        Ok(member_type)
+    }
+
+    /// Ensures the given contains command is well-formed.
+    #[inline]
+    fn check_contains(
+        &mut self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        contains: &Contains<N>,
+    ) -> Result<()> {
+        // Ensure the mapping is defined, and the key type matches the mapping key type.
+        self.check_mapping_key(stack, finalize_name, contains.mapping_name(), contains.key())?;
+        // Insert the destination register.
+        self.add_destination(
+            contains.destination().clone(),
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean)),
+        )
+    }
+
+    /// Ensures the given get command is well-formed.
+    #[inline]
+    fn check_get(&mut self, stack: &Stack<N>, finalize_name: &Identifier<N>, get: &Get<N>) -> Result<()> {
+        // Ensure the mapping is defined, and the key type matches the mapping key type.
+        let value_type = self.check_mapping_key(stack, finalize_name, get.mapping_name(), get.key())?;
+        // Retrieve the type of the member at the path, if any.
+        let value_type = self.get_mapping_member_type(stack, value_type, get.path())?;
+        // Insert the destination register.
+        self.add_destination(get.destination().clone(), value_type)
+    }
+
+    /// Ensures the given get.or_init command is well-formed.
+    #[inline]
+    fn check_get_or_init(
+        &mut self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        get_or_init: &GetOrInit<N>,
+    ) -> Result<()> {
+        // Ensure the mapping is defined, and the key type matches the mapping key type.
+        let value_type = self.check_mapping_key(stack, finalize_name, get_or_init.mapping_name(), get_or_init.key())?;
+        // Ensure the default type matches the mapping value type.
+        let default_type = self.get_type_from_operand(stack, get_or_init.default())?;
+        if default_type != value_type {
+            bail!("Default value in '{get_or_init}' must be '{value_type}', found '{default_type}'")
+        }
+        // Insert the destination register.
+        self.add_destination(get_or_init.destination().clone(), value_type)
+    }
+
+    /// Ensures the given remove command is well-formed.
+    #[inline]
+    fn check_remove(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, remove: &Remove<N>) -> Result<()> {
+        // Ensure the mapping is defined, and the key type matches the mapping key type.
+        self.check_mapping_key(stack, finalize_name, remove.mapping_name(), remove.key())?;
+        Ok(())
+    }
+
+    /// Ensures the given set command is well-formed.
+    #[inline]
+    fn check_set(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, set: &Set<N>) -> Result<()> {
+        // Ensure the mapping is defined, and the key type matches the mapping key type.
+        let value_type = self.check_mapping_key(stack, finalize_name, set.mapping_name(), set.key())?;
+        // Retrieve the type of the member at the path, if any.
+        let value_type = self.get_mapping_member_type(stack, value_type, set.path())?;
+        // Ensure the value type matches the mapping value (or member) type.
+        let operand_type = self.get_type_from_operand(stack, set.value())?;
+        if operand_type != value_type {
+            bail!("Value in '{set}' must be '{value_type}', found '{operand_type}'")
         }
         Ok(())
     }
@@ -216,7 +500,7 @@ impl<N: Network> FinalizeTypes<N> {
         match value_type {
             RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 match literal_type {
-                    LiteralType::Address | LiteralType::Boolean | LiteralType::String => {
+                    LiteralType::Address | LiteralType::Boolean | LiteralType::String | LiteralType::Signature => {
                         bail!("Decrement cannot decrement by a(n) '{literal_type}' (found at '{decrement}')")
                     }
                     // These literal types are valid for the 'decrement' command.
@@ -238,12 +522,23 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Decrement cannot decrement by an 'struct' (found at '{decrement}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Decrement cannot decrement by an 'array' (found at '{decrement}')")
+            }
             RegisterType::Record(..) => bail!("Decrement cannot decrement by a 'record' (found at '{decrement}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Decrement cannot decrement by an 'external record' (found at '{decrement}')")
             }
         }
 
+        // Ensure the decrement value type matches the mapping value type.
+        // Note: A struct value must be updated member-wise, using 'get' and 'set' with a member path.
+        let mapping_value_type =
+            self.check_mapping_key(stack, finalize_name, decrement.mapping_name(), decrement.key())?;
+        if value_type != mapping_value_type {
+            bail!("Decrement value in '{decrement}' must be '{mapping_value_type}', found '{value_type}'")
+        }
+
         Ok(())
     }
 
@@ -272,7 +567,7 @@ impl<N: Network> FinalizeTypes<N> {
         match value_type {
             RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 match literal_type {
-                    LiteralType::Address | LiteralType::Boolean | LiteralType::String => {
+                    LiteralType::Address | LiteralType::Boolean | LiteralType::String | LiteralType::Signature => {
                         bail!("Increment cannot increment by a(n) '{literal_type}' (found at '{increment}')")
                     }
                     // These literal types are valid for the 'increment' command.
@@ -294,12 +589,23 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Increment cannot increment by an 'struct' (found at '{increment}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Increment cannot increment by an 'array' (found at '{increment}')")
+            }
             RegisterType::Record(..) => bail!("Increment cannot increment by a 'record' (found at '{increment}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Increment cannot increment by an 'external record' (found at '{increment}')")
             }
         }
 
+        // Ensure the increment value type matches the mapping value type.
+        // Note: A struct value must be updated member-wise, using 'get' and 'set' with a member path.
+        let mapping_value_type =
+            self.check_mapping_key(stack, finalize_name, increment.mapping_name(), increment.key())?;
+        if value_type != mapping_value_type {
+            bail!("Increment value in '{increment}' must be '{mapping_value_type}', found '{value_type}'")
+        }
+
         Ok(())
     }
 
@@ -351,7 +657,10 @@ impl<N: Network> FinalizeTypes<N> {
                 // Ensure the opcode **is** a reserved opcode.
                 ensure!(Program::<N>::is_reserved_opcode(opcode), "'{opcode}' is not an opcode.");
                 // Ensure the instruction is not the cast operation.
-                ensure!(!matches!(instruction, Instruction::Cast(..)), "Instruction '{instruction}' is a 'cast'.");
+                ensure!(
+                    !matches!(instruction, Instruction::Cast(..) | Instruction::CastLossy(..)),
+                    "Instruction '{instruction}' is a 'cast'."
+                );
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
@@ -379,11 +688,12 @@ impl<N: Network> FinalizeTypes<N> {
             Opcode::Call => {
                 bail!("Instruction 'call' is not allowed in 'finalize'");
             }
-            Opcode::Cast => {
-                // Retrieve the cast operation.
-                let operation = match instruction {
-                    Instruction::Cast(operation) => operation,
-                    _ => bail!("Instruction '{instruction}' is not a cast operation."),
+            Opcode::Cast(opcode) => {
+                // Retrieve the casted register type.
+                let register_type = match (opcode, instruction) {
+                    ("cast", Instruction::Cast(operation)) => operation.register_type(),
+                    ("cast.lossy", Instruction::CastLossy(operation)) => operation.register_type(),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 };
 
                 // Ensure the instruction has one destination register.
@@ -393,9 +703,13 @@ impl<N: Network> FinalizeTypes<N> {
                 );
 
                 // Ensure the casted register type is defined.
-                match operation.register_type() {
+                match register_type {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Ensure the instruction has one operand.
+                        ensure!(
+                            instruction.operands().len() == 1,
+                            "Instruction '{instruction}' must have exactly one operand."
+                        );
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -407,6 +721,16 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the element struct, if any, exists in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                            if !stack.program().contains_struct(&struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                        }
+                        // Ensure the operand types match the array.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Unsupported operation: Cannot cast to a record (yet).")
                         // // Ensure the record type is defined in the program.
@@ -479,11 +803,14 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.bhp512",
                     "hash.bhp768",
                     "hash.bhp1024",
+                    "hash.keccak256",
                     "hash.ped64",
                     "hash.ped128",
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.sha3_256",
+                    "hash.sha256",
                 ]
                 .contains(&opcode)
                 {
@@ -507,6 +834,10 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashBHP1024(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.keccak256" => ensure!(
+                        matches!(instruction, Instruction::HashKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     "hash.ped64" => ensure!(
                         matches!(instruction, Instruction::HashPED64(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
@@ -527,6 +858,14 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.sha3_256" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha256" => ensure!(
+                        matches!(instruction, Instruction::HashSha256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -548,6 +887,14 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign => {
+                // Ensure the instruction is the correct one.
+                ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{}'.",
+                    instruction.opcode()
+                );
+            }
         }
         Ok(())
     }
@@ -18,7 +18,7 @@ mod initialize;
 mod matches;
 
 use crate::{
-    finalize::{Command, Decrement, Finalize, Increment},
+    finalize::{Branch, Command, Contains, Decrement, Finalize, Get, GetOrInit, Increment, Remove, Repeat, Set},
     Instruction,
     Opcode,
     Operand,
@@ -27,10 +27,10 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, LiteralType, PlaintextType, RecordType, Register, RegisterType, Struct},
+    program::{Access, EntryType, Identifier, LiteralType, PlaintextType, RecordType, Register, RegisterType, Struct},
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct FinalizeTypes<N: Network> {
@@ -69,6 +69,7 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::BlockHeight => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)),
         })
     }
 
@@ -100,13 +101,13 @@ impl<N: Network> FinalizeTypes<N> {
         };
 
         // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        for access in path.iter() {
             // Update the register type at each step.
-            register_type = match &register_type {
+            register_type = match (&register_type, access) {
                 // Ensure the plaintext type is not a literal, as the register references a member.
-                RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+                (RegisterType::Plaintext(PlaintextType::Literal(..)), _) => bail!("'{register}' references a literal."),
                 // Traverse the member path to output the register type.
-                RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                (RegisterType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(path_name)) => {
                     // Retrieve the member type from the struct.
                     match stack.program().get_struct(struct_name)?.members().get(path_name) {
                         // Update the member type.
@@ -114,7 +115,14 @@ impl<N: Network> FinalizeTypes<N> {
                         None => bail!("'{path_name}' does not exist in struct '{struct_name}'"),
                     }
                 }
-                RegisterType::Record(record_name) => {
+                // Traverse the array index to output the register type.
+                (RegisterType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
+                    // Ensure the index is within the bounds of the array.
+                    ensure!(**index < array_type.length(), "'{index}' is out of bounds for array '{array_type}'");
+                    // Update the element type.
+                    RegisterType::Plaintext(array_type.element_type())
+                }
+                (RegisterType::Record(record_name), Access::Member(path_name)) => {
                     // Ensure the record type exists.
                     ensure!(stack.program().contains_record(record_name), "Record '{record_name}' does not exist");
                     // Retrieve the member type from the record.
@@ -137,7 +145,7 @@ impl<N: Network> FinalizeTypes<N> {
                         }
                     }
                 }
-                RegisterType::ExternalRecord(locator) => {
+                (RegisterType::ExternalRecord(locator), Access::Member(path_name)) => {
                     // Ensure the external record type exists.
                     ensure!(stack.contains_external_record(locator), "External record '{locator}' does not exist");
                     // Retrieve the member type from the external record.
@@ -160,6 +168,16 @@ impl<N: Network> FinalizeTypes<N> {
                         }
                     }
                 }
+                // Ensure the register type is not indexed, unless it is an array.
+                (RegisterType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                | (RegisterType::Record(..), Access::Index(..))
+                | (RegisterType::ExternalRecord(..), Access::Index(..)) => {
+                    bail!("'{register}' references an index of a non-array.")
+                }
+                // Ensure the array type is not accessed by member.
+                (RegisterType::Plaintext(PlaintextType::Array(..)), Access::Member(..)) => {
+                    bail!("'{register}' references a member of an array.")
+                }
             }
         }
         // Output the member type.
@@ -29,6 +29,8 @@ impl<N: Network> Stack<N> {
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
+            edition: N::EDITION,
+            owner: None,
         };
 
         // Add all of the imports into the stack.
@@ -75,6 +77,19 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Replaces the external stack with the same program ID, e.g. with a later edition of the program.
+    #[inline]
+    pub(crate) fn replace_external_stack(&mut self, external_stack: Stack<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *external_stack.program_id();
+        // Ensure the external stack was previously added.
+        ensure!(self.external_stacks.contains_key(&program_id), "Program '{program_id}' does not exist");
+        // Replace the external stack.
+        self.external_stacks.insert(program_id, external_stack);
+        // Return success.
+        Ok(())
+    }
+
     /// Inserts the given closure to the stack.
     #[inline]
     fn insert_closure(&mut self, closure: &Closure<N>) -> Result<()> {
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod snapshot;
+pub use snapshot::*;
+
 use crate::{
     BlockStorage,
     BlockStore,
@@ -27,11 +30,22 @@ use crate::{
     Stack,
     Transaction,
     Transition,
+    UniversalSRS,
     VerifyingKey,
 };
 use console::{
     network::prelude::*,
-    program::{InputID, StatePath, TransactionLeaf, TransitionLeaf, TRANSACTION_DEPTH},
+    program::{
+        InputID,
+        Plaintext,
+        Record,
+        StatePath,
+        TransactionLeaf,
+        TransactionTree,
+        TransitionLeaf,
+        TransitionTree,
+        TRANSACTION_DEPTH,
+    },
     types::{Field, Group},
 };
 
@@ -44,6 +58,8 @@ pub enum Query<N: Network, B: BlockStorage<N>> {
     VM(BlockStore<N, B>),
     /// The base URL of the node.
     REST(String),
+    /// The snapshot of the state paths, for offline use.
+    Snapshot(QuerySnapshot<N>),
 }
 
 impl<N: Network, B: BlockStorage<N>> From<BlockStore<N, B>> for Query<N, B> {
@@ -58,6 +74,18 @@ impl<N: Network, B: BlockStorage<N>> From<&BlockStore<N, B>> for Query<N, B> {
     }
 }
 
+impl<N: Network, B: BlockStorage<N>> From<QuerySnapshot<N>> for Query<N, B> {
+    fn from(snapshot: QuerySnapshot<N>) -> Self {
+        Self::Snapshot(snapshot)
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> From<&QuerySnapshot<N>> for Query<N, B> {
+    fn from(snapshot: &QuerySnapshot<N>) -> Self {
+        Self::Snapshot(snapshot.clone())
+    }
+}
+
 impl<N: Network, B: BlockStorage<N>> From<reqwest::Url> for Query<N, B> {
     fn from(url: reqwest::Url) -> Self {
         Self::REST(url.to_string())
@@ -99,6 +127,7 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/program/{program_id}"))?.json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(snapshot) => snapshot.get_program(program_id),
         }
     }
 
@@ -110,6 +139,7 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/latest/stateRoot"))?.json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(snapshot) => Ok(snapshot.state_root()),
         }
     }
 
@@ -121,6 +151,7 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/statePath/{commitment}"))?.json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Snapshot(snapshot) => snapshot.get_state_path_for_commitment(commitment),
         }
     }
 
@@ -327,7 +358,7 @@ impl<N: Network> Inclusion<N> {
             }
             false => {
                 // Fetch the inclusion proving key.
-                let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+                let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key()?.clone());
 
                 // Compute the inclusion batch proof.
                 let (global_state_root, inclusion_proof) = Self::prove_batch::<A, R>(&proving_key, assignments, rng)?;
@@ -427,7 +458,7 @@ impl<N: Network> Inclusion<N> {
         }
 
         // Fetch the inclusion proving key.
-        let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+        let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key()?.clone());
 
         // Compute the inclusion batch proof.
         let (global_state_root, inclusion_proof) = Self::prove_batch::<A, R>(&proving_key, assignments, rng)?;
@@ -491,7 +522,7 @@ impl<N: Network> Inclusion<N> {
                 }
 
                 // Fetch the inclusion verifying key.
-                let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
+                let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key()?.clone());
                 // Verify the inclusion proof.
                 ensure!(
                     verifying_key.verify_batch(N::INCLUSION_FUNCTION_NAME, &batch_verifier_inputs, inclusion_proof),
@@ -552,7 +583,7 @@ impl<N: Network> Inclusion<N> {
         }
 
         // Fetch the inclusion verifying key.
-        let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
+        let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key()?.clone());
         // Verify the inclusion proof.
         ensure!(
             verifying_key.verify_batch(N::INCLUSION_FUNCTION_NAME, &batch_verifier_inputs, inclusion_proof),
@@ -595,6 +626,45 @@ impl<N: Network> Inclusion<N> {
         // Return the global state root and inclusion proof.
         Ok((global_state_root, inclusion_proof))
     }
+
+    /// Synthesizes the proving and verifying keys for the inclusion circuit.
+    /// Note: This is only required for networks that sample their universal SRS locally.
+    pub fn synthesize_keys<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        universal_srs: &UniversalSRS<N>,
+        rng: &mut R,
+    ) -> Result<(ProvingKey<N>, VerifyingKey<N>)> {
+        // Sample a commitment, and compute its serial number.
+        let commitment = Field::rand(rng);
+        let gamma = Group::rand(rng);
+        let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, commitment)?;
+
+        // Construct the transition path and transition leaf.
+        let transition_leaf = TransitionLeaf::new_with_version(0, 3, commitment);
+        let transition_tree: TransitionTree<N> = N::merkle_tree_bhp(&[transition_leaf.to_bits_le()])?;
+        let transition_path = transition_tree.prove(0, &transition_leaf.to_bits_le())?;
+
+        // Construct the transaction path and transaction leaf.
+        let transaction_leaf = TransactionLeaf::new_execution(0, *transition_tree.root());
+        let transaction_tree: TransactionTree<N> = N::merkle_tree_bhp(&[transaction_leaf.to_bits_le()])?;
+        let transaction_path = transaction_tree.prove(0, &transaction_leaf.to_bits_le())?;
+
+        // Construct the state path to the local state root.
+        let local_state_root = N::TransactionID::from(*transaction_tree.root());
+        let state_path = StatePath::new_local(
+            Field::rand(rng).into(),
+            local_state_root,
+            transaction_path,
+            transaction_leaf,
+            transition_path,
+            transition_leaf,
+        )?;
+
+        // Synthesize the circuit keys from the assignment.
+        let assignment =
+            InclusionAssignment::new(state_path, commitment, gamma, serial_number, local_state_root, false);
+        let function_name = Identifier::from_str(N::INCLUSION_FUNCTION_NAME)?;
+        universal_srs.to_circuit_key(&function_name, &assignment.to_circuit_assignment::<A>()?)
+    }
 }
 
 pub struct InclusionAssignment<N: Network> {
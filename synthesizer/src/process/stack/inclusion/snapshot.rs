@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BlockStorage, BlockStore, Program};
+use console::{
+    network::prelude::*,
+    program::{ProgramID, StatePath},
+    types::Field,
+};
+
+use indexmap::IndexMap;
+use std::{fs::File, io::BufReader, path::Path};
+
+/// A snapshot of the state paths for a set of commitments, taken at a single state root.
+/// A snapshot answers inclusion queries without access to a node or to the block store.
+#[derive(Clone, PartialEq, Eq)]
+pub struct QuerySnapshot<N: Network> {
+    /// The state root of the snapshot.
+    state_root: N::StateRoot,
+    /// The programs in the snapshot.
+    programs: IndexMap<ProgramID<N>, Program<N>>,
+    /// The mapping of `commitment` to `state path`.
+    state_paths: IndexMap<Field<N>, StatePath<N>>,
+}
+
+impl<N: Network> QuerySnapshot<N> {
+    /// Initializes a new snapshot from the given state root, programs, and state paths.
+    pub fn from(
+        state_root: N::StateRoot,
+        programs: IndexMap<ProgramID<N>, Program<N>>,
+        state_paths: IndexMap<Field<N>, StatePath<N>>,
+    ) -> Result<Self> {
+        // Ensure the programs are keyed by their program ID.
+        for (program_id, program) in &programs {
+            ensure!(program_id == program.id(), "Mismatching program ID in snapshot for '{program_id}'");
+        }
+        // Ensure the state paths are for the state root of the snapshot.
+        for (commitment, state_path) in &state_paths {
+            ensure!(
+                state_path.global_state_root() == state_root,
+                "The state path for commitment '{commitment}' is not for the snapshot state root"
+            );
+        }
+        Ok(Self { state_root, programs, state_paths })
+    }
+
+    /// Initializes a new snapshot from the block store, for the given commitments.
+    pub fn from_block_store<B: BlockStorage<N>>(
+        block_store: &BlockStore<N, B>,
+        commitments: impl IntoIterator<Item = Field<N>>,
+    ) -> Result<Self> {
+        // Retrieve the programs.
+        let programs = block_store
+            .transaction_store()
+            .programs()
+            .map(|program| {
+                let program = program.into_owned();
+                (*program.id(), program)
+            })
+            .collect();
+        // Retrieve the state paths.
+        let state_paths = commitments
+            .into_iter()
+            .map(|commitment| Ok((commitment, block_store.get_state_path_for_commitment(&commitment)?)))
+            .collect::<Result<_>>()?;
+        // Return the snapshot.
+        Self::from(block_store.current_state_root(), programs, state_paths)
+    }
+
+    /// Loads the snapshot from the file at the given path.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| anyhow!("Failed to open the snapshot at '{}': {e}", path.display()))?;
+        Ok(Self::read_le(BufReader::new(file))?)
+    }
+
+    /// Saves the snapshot to the file at the given path.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let file =
+            File::create(path).map_err(|e| anyhow!("Failed to create the snapshot at '{}': {e}", path.display()))?;
+        Ok(self.write_le(file)?)
+    }
+}
+
+impl<N: Network> QuerySnapshot<N> {
+    /// Returns the state root of the snapshot.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the program for the given program ID.
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.programs.get(program_id).cloned().ok_or_else(|| anyhow!("Program {program_id} not found in snapshot"))
+    }
+
+    /// Returns a state path for the given `commitment`.
+    pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.state_paths
+            .get(commitment)
+            .cloned()
+            .ok_or_else(|| anyhow!("State path for commitment {commitment} not found in snapshot"))
+    }
+}
+
+impl<N: Network> FromBytes for QuerySnapshot<N> {
+    /// Reads the snapshot from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid snapshot version"));
+        }
+
+        // Read the state root.
+        let state_root = FromBytes::read_le(&mut reader)?;
+        // Read the programs.
+        let num_programs = u32::read_le(&mut reader)?;
+        let programs = (0..num_programs)
+            .map(|_| {
+                let program = Program::<N>::read_le(&mut reader)?;
+                Ok((*program.id(), program))
+            })
+            .collect::<IoResult<_>>()?;
+        // Read the state paths.
+        let num_state_paths = u32::read_le(&mut reader)?;
+        let state_paths = (0..num_state_paths)
+            .map(|_| Ok((Field::read_le(&mut reader)?, StatePath::read_le(&mut reader)?)))
+            .collect::<IoResult<_>>()?;
+
+        // Return the snapshot.
+        Self::from(state_root, programs, state_paths).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for QuerySnapshot<N> {
+    /// Writes the snapshot to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+
+        // Write the state root.
+        self.state_root.write_le(&mut writer)?;
+        // Write the programs.
+        u32::try_from(self.programs.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for program in self.programs.values() {
+            program.write_le(&mut writer)?;
+        }
+        // Write the state paths.
+        u32::try_from(self.state_paths.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for (commitment, state_path) in &self.state_paths {
+            commitment.write_le(&mut writer)?;
+            state_path.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process::Query, vm::test_helpers::sample_vm_with_genesis_block, BlockMemory};
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_snapshot_query() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let block_store = vm.block_store();
+        // Take a snapshot of all of the commitments.
+        let commitments = block_store.transition_store().commitments().map(|c| *c).collect::<Vec<_>>();
+        assert!(!commitments.is_empty());
+        let snapshot = QuerySnapshot::from_block_store(block_store, commitments.clone()).unwrap();
+
+        // Ensure the snapshot round-trips through a file.
+        let directory = std::env::temp_dir().join(format!("snapshot-{}", rng.gen::<u64>()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("query.snapshot");
+        snapshot.save(&path).unwrap();
+        let snapshot = QuerySnapshot::<CurrentNetwork>::load(&path).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        // Ensure the snapshot answers the same queries as the block store.
+        let query = Query::<CurrentNetwork, BlockMemory<CurrentNetwork>>::from(snapshot);
+        assert_eq!(query.current_state_root().unwrap(), block_store.current_state_root());
+        for commitment in &commitments {
+            assert_eq!(
+                query.get_state_path_for_commitment(commitment).unwrap(),
+                block_store.get_state_path_for_commitment(commitment).unwrap()
+            );
+        }
+        // Ensure unknown commitments are rejected.
+        assert!(query.get_state_path_for_commitment(&Field::zero()).is_err());
+    }
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod analysis;
+pub use analysis::*;
+
 mod authorization;
 pub use authorization::*;
 
@@ -41,6 +44,9 @@ pub use register_types::*;
 mod registers;
 pub use registers::*;
 
+mod traits;
+pub use traits::*;
+
 mod authorize;
 mod deploy;
 mod evaluate;
@@ -83,7 +89,7 @@ use console::{
         Value,
         ValueType,
     },
-    types::{Field, Group, U64},
+    types::{Address, Field, Group, U64},
 };
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -197,6 +203,10 @@ pub struct Stack<N: Network> {
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
     verifying_keys: Arc<RwLock<IndexMap<Identifier<N>, VerifyingKey<N>>>>,
+    /// The edition of the deployed program.
+    edition: u16,
+    /// The owner of the program, if the program is upgradable.
+    owner: Option<Address<N>>,
 }
 
 impl<N: Network> Stack<N> {
@@ -207,6 +217,27 @@ impl<N: Network> Stack<N> {
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Return the stack.
+        Self::new_unchecked(process, program)
+    }
+
+    /// Initializes a new stack for an upgrade of an existing program, given the process and the program.
+    /// Note: This method does not check that the upgrade is compatible with the existing program.
+    #[inline]
+    pub fn new_upgrade(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program already exists in the process.
+        ensure!(process.contains_program(program_id), "Cannot upgrade program '{program_id}', it does not exist");
+        // Return the stack.
+        Self::new_unchecked(process, program)
+    }
+
+    /// Initializes a new stack, without checking whether the program exists in the process.
+    #[inline]
+    fn new_unchecked(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program network-level domain (NLD) is correct.
         ensure!(program_id.is_aleo(), "Program '{program_id}' has an incorrect network-level domain (NLD)");
         // Ensure the program contains functions.
@@ -238,6 +269,25 @@ impl<N: Network> Stack<N> {
         self.program.id()
     }
 
+    /// Returns the edition of the deployed program.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Returns the owner of the program, if the program is upgradable.
+    #[inline]
+    pub const fn owner(&self) -> Option<&Address<N>> {
+        self.owner.as_ref()
+    }
+
+    /// Sets the edition and owner of the deployed program.
+    #[inline]
+    pub(crate) fn set_deployment_info(&mut self, edition: u16, owner: Option<Address<N>>) {
+        self.edition = edition;
+        self.owner = owner;
+    }
+
     /// Returns `true` if the stack contains the external record.
     #[inline]
     pub fn contains_external_record(&self, locator: &Locator<N>) -> bool {
@@ -84,11 +84,46 @@ impl<N: Network> RegisterTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block height type (u32) matches the member type.
+                Operand::BlockHeight => {
+                    // Retrieve the operand type.
+                    let operand_type = self.get_type_from_operand(stack, operand)?;
+                    // Ensure the operand type matches the member type.
+                    ensure!(
+                        operand_type == RegisterType::Plaintext(*member_type),
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{operand_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
     }
 
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of elements match.
+        let num_elements = operands.len();
+        let expected_num_elements = array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Retrieve the element type.
+        let element_type = RegisterType::Plaintext(array_type.element_type());
+
+        // Ensure the operand types match the element type.
+        for operand in operands.iter() {
+            // Retrieve the operand type.
+            let operand_type = self.get_type_from_operand(stack, operand)?;
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == element_type,
+                "Array '{array_type}' expects {element_type}, but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
+
     /// Checks that the given record matches the layout of the record type.
     /// Note: Ordering for `owner` and `gates` **does** matter, however ordering
     /// for record data does **not** matter, as long as all defined members are present.
@@ -127,6 +162,7 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Cannot cast a program ID ('{program_id}') as a record owner")
             }
             Operand::Caller => {}
+            Operand::BlockHeight => bail!("Casting to a record requires the first operand to be an address"),
         }
 
         // Ensure the second input type is a u64.
@@ -147,7 +183,7 @@ impl<N: Network> RegisterTypes<N> {
                 )
             }
             // These operand types are never a `u64` type.
-            Operand::ProgramID(..) | Operand::Caller => {
+            Operand::ProgramID(..) | Operand::Caller | Operand::BlockHeight => {
                 bail!("Casting to a record requires the second operand to be a u64")
             }
         }
@@ -212,6 +248,16 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{caller_type}' in the operand '{operand}'.",
                             )
                         }
+                        // Ensure the block height type (u32) matches the entry type.
+                        Operand::BlockHeight => {
+                            // Retrieve the operand type.
+                            let operand_type = self.get_type_from_operand(stack, operand)?;
+                            // Ensure the operand type matches the entry type.
+                            ensure!(
+                                operand_type == RegisterType::Plaintext(*plaintext_type),
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{operand_type}' in the operand '{operand}'.",
+                            )
+                        }
                     }
                 }
             }
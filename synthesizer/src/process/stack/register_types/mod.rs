@@ -21,6 +21,7 @@ use crate::{CallOperator, Closure, Function, Instruction, Opcode, Operand, Progr
 use console::{
     network::prelude::*,
     program::{
+        Access,
         EntryType,
         Identifier,
         LiteralType,
@@ -79,6 +80,7 @@ impl<N: Network> RegisterTypes<N> {
             Operand::Register(register) => self.get_type(stack, register)?,
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            Operand::BlockHeight => bail!("Forbidden operation: Cannot use 'block.height' outside of 'finalize'"),
         })
     }
 
@@ -110,13 +112,13 @@ impl<N: Network> RegisterTypes<N> {
         };
 
         // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        for access in path.iter() {
             // Update the register type at each step.
-            register_type = match &register_type {
+            register_type = match (&register_type, access) {
                 // Ensure the plaintext type is not a literal, as the register references a member.
-                RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+                (RegisterType::Plaintext(PlaintextType::Literal(..)), _) => bail!("'{register}' references a literal."),
                 // Traverse the member path to output the register type.
-                RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                (RegisterType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(path_name)) => {
                     // Retrieve the member type from the struct.
                     match stack.program().get_struct(struct_name)?.members().get(path_name) {
                         // Update the member type.
@@ -124,7 +126,14 @@ impl<N: Network> RegisterTypes<N> {
                         None => bail!("'{path_name}' does not exist in struct '{struct_name}'"),
                     }
                 }
-                RegisterType::Record(record_name) => {
+                // Traverse the array index to output the register type.
+                (RegisterType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
+                    // Ensure the index is within the bounds of the array.
+                    ensure!(**index < array_type.length(), "'{index}' is out of bounds for array '{array_type}'");
+                    // Update the element type.
+                    RegisterType::Plaintext(array_type.element_type())
+                }
+                (RegisterType::Record(record_name), Access::Member(path_name)) => {
                     // Ensure the record type exists.
                     ensure!(stack.program().contains_record(record_name), "Record '{record_name}' does not exist");
                     // Retrieve the member type from the record.
@@ -147,7 +156,7 @@ impl<N: Network> RegisterTypes<N> {
                         }
                     }
                 }
-                RegisterType::ExternalRecord(locator) => {
+                (RegisterType::ExternalRecord(locator), Access::Member(path_name)) => {
                     // Ensure the external record type exists.
                     ensure!(stack.contains_external_record(locator), "External record '{locator}' does not exist");
                     // Retrieve the member type from the external record.
@@ -170,6 +179,16 @@ impl<N: Network> RegisterTypes<N> {
                         }
                     }
                 }
+                // Ensure the register type is not indexed, unless it is an array.
+                (RegisterType::Plaintext(PlaintextType::Struct(..)), Access::Index(..))
+                | (RegisterType::Record(..), Access::Index(..))
+                | (RegisterType::ExternalRecord(..), Access::Index(..)) => {
+                    bail!("'{register}' references an index of a non-array.")
+                }
+                // Ensure the array type is not accessed by member.
+                (RegisterType::Plaintext(PlaintextType::Array(..)), Access::Member(..)) => {
+                    bail!("'{register}' references a member of an array.")
+                }
             }
         }
         // Output the member type.
@@ -16,29 +16,14 @@
 
 use super::*;
 
-impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
-    /// Loads the literal of a given operand from the registers.
-    ///
-    /// # Errors
-    /// This method will halt if the given operand is not a literal.
-    /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
-    #[inline]
-    pub fn load_literal(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Literal<N>> {
-        match self.load(stack, operand)? {
-            Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
-            Value::Plaintext(Plaintext::Struct(..)) => bail!("Operand must be a literal"),
-            Value::Record(..) => bail!("Operand must be a literal"),
-        }
-    }
-
+impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N, A> {
     /// Loads the value of a given operand from the registers.
     ///
     /// # Errors
     /// This method will halt if the register locator is not found.
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
-    pub fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
+    fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
         // Retrieve the register.
         let register = match operand {
             // If the operand is a literal, return the literal.
@@ -51,6 +36,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             }
             // If the operand is the caller, load the value of the caller.
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
+            // If the operand is the block height, throw an error.
+            Operand::BlockHeight => bail!("Forbidden operation: Cannot use 'block.height' outside of 'finalize'"),
         };
 
         // Retrieve the stack value.
@@ -99,7 +86,9 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
     pub fn load_literal_circuit(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::program::Literal<A>> {
         match self.load_circuit(stack, operand)? {
             circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal),
-            circuit::Value::Plaintext(circuit::Plaintext::Struct(..)) => bail!("Operand must be a literal"),
+            circuit::Value::Plaintext(circuit::Plaintext::Struct(..) | circuit::Plaintext::Array(..)) => {
+                bail!("Operand must be a literal")
+            }
             circuit::Value::Record(..) => bail!("Operand must be a literal"),
         }
     }
@@ -135,6 +124,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
                     self.caller_circuit()?,
                 ))));
             }
+            // If the operand is the block height, throw an error.
+            Operand::BlockHeight => bail!("Forbidden operation: Cannot use 'block.height' outside of 'finalize'"),
         };
 
         // Retrieve the circuit value.
@@ -148,7 +139,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             // If the register is a register member, then load the specific stack value.
             Register::Member(_, ref path) => {
                 // Inject the path.
-                let path = path.iter().map(|member| circuit::Identifier::constant(*member)).collect::<Vec<_>>();
+                let path = path.iter().map(|access| circuit::Access::constant(*access)).collect::<Vec<_>>();
 
                 match circuit_value {
                     // Retrieve the plaintext member from the path.
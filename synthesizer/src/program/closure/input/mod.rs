@@ -86,8 +86,8 @@ mod tests {
         let input1 = Input::<CurrentNetwork>::from_str("input r0 as field;")?;
         let input2 = Input::<CurrentNetwork>::from_str("input r1 as field;")?;
 
-        let input3 = Input::<CurrentNetwork>::from_str("input r0 as signature;")?;
-        let input4 = Input::<CurrentNetwork>::from_str("input r1 as signature;")?;
+        let input3 = Input::<CurrentNetwork>::from_str("input r0 as message;")?;
+        let input4 = Input::<CurrentNetwork>::from_str("input r1 as message;")?;
 
         assert_eq!(input1.partial_cmp(&input1), Some(Ordering::Equal));
         assert_eq!(input1.partial_cmp(&input2), Some(Ordering::Less));
@@ -108,9 +108,9 @@ mod tests {
         assert_eq!(input.register_type(), &RegisterType::<CurrentNetwork>::from_str("field")?);
 
         // Struct
-        let input = Input::<CurrentNetwork>::parse("input r1 as signature;").unwrap().1;
+        let input = Input::<CurrentNetwork>::parse("input r1 as message;").unwrap().1;
         assert_eq!(input.register(), &Register::<CurrentNetwork>::Locator(1));
-        assert_eq!(input.register_type(), &RegisterType::<CurrentNetwork>::from_str("signature")?);
+        assert_eq!(input.register_type(), &RegisterType::<CurrentNetwork>::from_str("message")?);
 
         // Record
         let input = Input::<CurrentNetwork>::parse("input r2 as token.record;").unwrap().1;
@@ -127,8 +127,8 @@ mod tests {
         assert_eq!("input r0 as field;", input.to_string());
 
         // Struct
-        let input = Input::<CurrentNetwork>::from_str("input r1 as signature;")?;
-        assert_eq!("input r1 as signature;", input.to_string());
+        let input = Input::<CurrentNetwork>::from_str("input r1 as message;")?;
+        assert_eq!("input r1 as message;", input.to_string());
 
         // Record
         let input = Input::<CurrentNetwork>::parse("input r2 as token.record;").unwrap().1;
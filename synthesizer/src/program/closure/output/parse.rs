@@ -99,9 +99,9 @@ mod tests {
         assert_eq!(output.register_type(), &RegisterType::<CurrentNetwork>::from_str("field")?);
 
         // Struct
-        let output = Output::<CurrentNetwork>::parse("output r1 as signature;").unwrap().1;
+        let output = Output::<CurrentNetwork>::parse("output r1 as message;").unwrap().1;
         assert_eq!(output.register(), &Register::<CurrentNetwork>::Locator(1));
-        assert_eq!(output.register_type(), &RegisterType::<CurrentNetwork>::from_str("signature")?);
+        assert_eq!(output.register_type(), &RegisterType::<CurrentNetwork>::from_str("message")?);
 
         // Record
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
@@ -118,8 +118,8 @@ mod tests {
         assert_eq!(format!("{}", output), "output r0 as field;");
 
         // Struct
-        let output = Output::<CurrentNetwork>::parse("output r1 as signature;").unwrap().1;
-        assert_eq!(format!("{}", output), "output r1 as signature;");
+        let output = Output::<CurrentNetwork>::parse("output r1 as message;").unwrap().1;
+        assert_eq!(format!("{}", output), "output r1 as message;");
 
         // Record
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{Opcode, Operand, RegistersLoad, Stack};
 use console::{network::prelude::*, program::Register};
 
 /// Finalizes the operands on-chain.
@@ -60,11 +60,7 @@ impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
 impl<N: Network, const VARIANT: u8> FinalizeOperation<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
-        &self,
-        stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
-    ) -> Result<()> {
+    pub fn evaluate(&self, stack: &Stack<N>, registers: &mut impl RegistersLoad<N>) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() > N::MAX_INPUTS {
             bail!("'{}' expects <= {} operands, found {} operands", Self::opcode(), N::MAX_INPUTS, self.operands.len())
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FinalizeRegisters, Opcode, Operand, ProgramStorage, ProgramStore, RegistersLoad, RegistersStore, Stack};
+use console::{
+    network::prelude::*,
+    program::{Access, Identifier, Register, Value},
+};
+
+/// Loads the value stored at the `key` operand in `mapping` into `destination`.
+/// If a `path` is given, loads the member of the value at the `path` instead.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Get<N: Network> {
+    /// The mapping name.
+    mapping: Identifier<N>,
+    /// The key operand.
+    key: Operand<N>,
+    /// The member path into the value, if any.
+    path: Vec<Access<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Get<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("get")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.key.clone()]
+    }
+
+    /// Returns the mapping name.
+    #[inline]
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the key.
+    #[inline]
+    pub const fn key(&self) -> &Operand<N> {
+        &self.key
+    }
+
+    /// Returns the member path into the value.
+    #[inline]
+    pub fn path(&self) -> &[Access<N>] {
+        &self.path
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> Get<N> {
+    /// Evaluates the command.
+    #[inline]
+    pub fn evaluate_finalize<P: ProgramStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &ProgramStore<N, P>,
+        registers: &mut FinalizeRegisters<N>,
+    ) -> Result<()> {
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping(stack.program_id(), &self.mapping)? {
+            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        }
+
+        // Load the key operand as a plaintext.
+        let key = registers.load_plaintext(stack, &self.key)?;
+
+        // Retrieve the value from storage.
+        let value = match store.get_value(stack.program_id(), &self.mapping, &key)? {
+            Some(value) => value,
+            None => bail!("Key '{key}' does not exist in mapping '{}/{}'", stack.program_id(), self.mapping),
+        };
+
+        // If a path is given, retrieve the member of the value.
+        let value = match (value, self.path.is_empty()) {
+            (value, true) => value,
+            (Value::Plaintext(plaintext), false) => Value::Plaintext(plaintext.find(&self.path)?),
+            (Value::Record(..), false) => bail!("Cannot access a member of a record in a mapping"),
+        };
+
+        // Assign the value to the destination register.
+        registers.store(stack, &self.destination, value)
+    }
+}
+
+impl<N: Network> Parser for Get<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the "[" from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the key operand from the string.
+        let (string, key) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "]" from the string.
+        let (string, _) = tag("]")(string)?;
+        // Parse the member path from the string.
+        let (string, path) = map_res(many0(Access::parse), |path: Vec<_>| match path.len() <= N::MAX_DATA_DEPTH {
+            true => Ok(path),
+            false => Err(error(format!("Path exceeds the maximum depth of {}", N::MAX_DATA_DEPTH))),
+        })(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, key, path, destination }))
+    }
+}
+
+impl<N: Network> FromStr for Get<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Get<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Get<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping and key operand.
+        write!(f, "{}[{}]", self.mapping, self.key)?;
+        // Print the member path.
+        self.path.iter().try_for_each(|access| write!(f, "{access}"))?;
+        // Print the destination register.
+        write!(f, " into {};", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Get<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = Identifier::read_le(&mut reader)?;
+        // Read the key operand.
+        let key = Operand::read_le(&mut reader)?;
+        // Read the number of accesses in the member path.
+        let num_accesses = u8::read_le(&mut reader)?;
+        // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+        if num_accesses as usize > N::MAX_DATA_DEPTH {
+            return Err(error(format!("Failed to deserialize 'get': too many accesses ({num_accesses})")));
+        }
+        // Read the member path.
+        let path = (0..num_accesses).map(|_| Access::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, key, path, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Get<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the key operand.
+        self.key.write_le(&mut writer)?;
+        // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+        if self.path.len() > N::MAX_DATA_DEPTH {
+            return Err(error("Failed to serialize 'get': too many accesses"));
+        }
+        // Write the member path.
+        (self.path.len() as u8).write_le(&mut writer)?;
+        self.path.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, get) = Get::<CurrentNetwork>::parse("get account[r0] into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(get.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(get.key, Operand::Register(Register::Locator(0)), "The key operand is incorrect");
+        assert_eq!(get.destination, Register::Locator(1), "The destination register is incorrect");
+        assert!(get.path.is_empty(), "The member path is incorrect");
+
+        let expected = "get account[r0].balance into r1;";
+        let (string, get) = Get::<CurrentNetwork>::parse(expected).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get.path, vec![Access::from_str(".balance").unwrap()], "The member path is incorrect");
+        assert_eq!(get.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(expected, get.to_string());
+    }
+}
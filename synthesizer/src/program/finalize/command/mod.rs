@@ -14,17 +14,44 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod branch;
+pub use branch::*;
+
+mod contains;
+pub use contains::*;
+
 mod decrement;
 pub use decrement::*;
 
 mod finalize;
 pub use finalize::*;
 
+mod get;
+pub use get::*;
+
+mod get_or_init;
+pub use get_or_init::*;
+
 mod increment;
 pub use increment::*;
 
-use crate::{program::Instruction, FinalizeRegisters, ProgramStorage, ProgramStore, Stack};
-use console::network::prelude::*;
+mod position;
+pub use position::*;
+
+mod remove;
+pub use remove::*;
+
+mod repeat;
+pub use repeat::*;
+
+mod set;
+pub use set::*;
+
+use crate::{program::Instruction, FinalizeRegisters, Operand, ProgramStorage, ProgramStore, Stack};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Register},
+};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Command<N: Network> {
@@ -34,24 +61,110 @@ pub enum Command<N: Network> {
     Instruction(Instruction<N>),
     /// Increments the value stored at the `first` operand in `mapping` by the amount in the `second` operand.
     Increment(Increment<N>),
+    /// Stores `true` into `destination` if the `key` operand exists in `mapping`, and `false` otherwise.
+    Contains(Contains<N>),
+    /// Loads the value stored at the `key` operand in `mapping` into `destination`.
+    Get(Get<N>),
+    /// Loads the value stored at the `key` operand in `mapping` into `destination`,
+    /// initializing it to the `default` operand if the key does not exist.
+    GetOrInit(GetOrInit<N>),
+    /// Removes the `key` operand and its value from `mapping`, if the key exists.
+    Remove(Remove<N>),
+    /// Stores the `value` operand at the `key` operand in `mapping`.
+    Set(Set<N>),
+    /// Jumps forward to `position`, if the `first` operand equals the `second` operand.
+    BranchEq(BranchEq<N>),
+    /// Jumps forward to `position`, if the `first` operand does **not** equal the `second` operand.
+    BranchNeq(BranchNeq<N>),
+    /// Marks a labelled position, which may be the target of a branch.
+    Position(Position<N>),
+    /// Evaluates the `commands` in the body, `count` times in sequence.
+    Repeat(Repeat<N>),
 }
 
 impl<N: Network> Command<N> {
-    /// Evaluates the command.
+    /// Returns `true` if the command affects the control flow, i.e. it is a branch, a position, or a repeat.
+    #[inline]
+    pub const fn is_control_flow(&self) -> bool {
+        matches!(self, Self::BranchEq(..) | Self::BranchNeq(..) | Self::Position(..) | Self::Repeat(..))
+    }
+
+    /// Returns `true` if the given variant is that of a branch, a position, or a repeat.
+    #[inline]
+    const fn is_control_flow_variant(variant: u8) -> bool {
+        matches!(variant, 8..=11)
+    }
+
+    /// Returns the number of commands that are evaluated for this command, with the body of a repeat unrolled.
+    #[inline]
+    pub fn num_evaluated_commands(&self) -> usize {
+        match self {
+            Self::Repeat(repeat) => repeat.num_evaluated_commands(),
+            _ => 1,
+        }
+    }
+
+    /// Returns the operands of the command, including those in the body of a repeat.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        match self {
+            Self::Decrement(decrement) => decrement.operands(),
+            Self::Instruction(instruction) => instruction.operands().to_vec(),
+            Self::Increment(increment) => increment.operands(),
+            Self::Contains(contains) => contains.operands(),
+            Self::Get(get) => get.operands(),
+            Self::GetOrInit(get_or_init) => get_or_init.operands(),
+            Self::Remove(remove) => remove.operands(),
+            Self::Set(set) => set.operands(),
+            Self::BranchEq(branch_eq) => branch_eq.operands(),
+            Self::BranchNeq(branch_neq) => branch_neq.operands(),
+            Self::Position(..) => vec![],
+            Self::Repeat(repeat) => repeat.commands().iter().flat_map(Self::operands).collect(),
+        }
+    }
+
+    /// Returns the destination registers of the command, including those in the body of a repeat.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        match self {
+            Self::Instruction(instruction) => instruction.destinations(),
+            Self::Contains(contains) => vec![contains.destination().clone()],
+            Self::Get(get) => vec![get.destination().clone()],
+            Self::GetOrInit(get_or_init) => vec![get_or_init.destination().clone()],
+            Self::Decrement(..)
+            | Self::Increment(..)
+            | Self::Remove(..)
+            | Self::Set(..)
+            | Self::BranchEq(..)
+            | Self::BranchNeq(..)
+            | Self::Position(..) => vec![],
+            Self::Repeat(repeat) => repeat.commands().iter().flat_map(Self::destinations).collect(),
+        }
+    }
+
+    /// Evaluates the command, returning the position to jump to if the command is a taken branch.
     #[inline]
     pub fn evaluate_finalize<P: ProgramStorage<N>>(
         &self,
         stack: &Stack<N>,
         store: &ProgramStore<N, P>,
         registers: &mut FinalizeRegisters<N>,
-    ) -> Result<()> {
+    ) -> Result<Option<Identifier<N>>> {
         match self {
-            Command::Decrement(decrement) => decrement.evaluate_finalize(stack, store, registers),
-            // TODO (howardwu): Implement support for instructions (consider using a trait for `Registers::load/store`).
-            // Command::Instruction(instruction) => instruction.evaluate_finalize(stack, registers),
-            Command::Instruction(_) => bail!("Instructions in 'finalize' are not supported (yet)."),
-            Command::Increment(increment) => increment.evaluate_finalize(stack, store, registers),
+            Command::Decrement(decrement) => decrement.evaluate_finalize(stack, store, registers)?,
+            Command::Instruction(instruction) => instruction.finalize(stack, registers)?,
+            Command::Increment(increment) => increment.evaluate_finalize(stack, store, registers)?,
+            Command::Contains(contains) => contains.evaluate_finalize(stack, store, registers)?,
+            Command::Get(get) => get.evaluate_finalize(stack, store, registers)?,
+            Command::GetOrInit(get_or_init) => get_or_init.evaluate_finalize(stack, store, registers)?,
+            Command::Remove(remove) => remove.evaluate_finalize(stack, store, registers)?,
+            Command::Set(set) => set.evaluate_finalize(stack, store, registers)?,
+            Command::BranchEq(branch_eq) => return branch_eq.evaluate_finalize(stack, registers),
+            Command::BranchNeq(branch_neq) => return branch_neq.evaluate_finalize(stack, registers),
+            Command::Position(..) => (),
+            Command::Repeat(repeat) => repeat.evaluate_finalize(stack, store, registers)?,
         }
+        Ok(None)
     }
 }
 
@@ -60,6 +173,15 @@ impl<N: Network> FromBytes for Command<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the variant.
         let variant = u8::read_le(&mut reader)?;
+        // Read the command.
+        Self::read_le_with_variant(variant, reader)
+    }
+}
+
+impl<N: Network> Command<N> {
+    /// Reads the command of the given variant from a buffer.
+    /// Note: This allows the variant to be checked before the command is read, e.g. to reject nested repeats.
+    fn read_le_with_variant<R: Read>(variant: u8, mut reader: R) -> IoResult<Self> {
         match variant {
             // Read the decrement.
             0 => Ok(Self::Decrement(Decrement::read_le(&mut reader)?)),
@@ -67,8 +189,26 @@ impl<N: Network> FromBytes for Command<N> {
             1 => Ok(Self::Instruction(Instruction::read_le(&mut reader)?)),
             // Read the increment.
             2 => Ok(Self::Increment(Increment::read_le(&mut reader)?)),
+            // Read the contains.
+            3 => Ok(Self::Contains(Contains::read_le(&mut reader)?)),
+            // Read the get.
+            4 => Ok(Self::Get(Get::read_le(&mut reader)?)),
+            // Read the get.or_init.
+            5 => Ok(Self::GetOrInit(GetOrInit::read_le(&mut reader)?)),
+            // Read the remove.
+            6 => Ok(Self::Remove(Remove::read_le(&mut reader)?)),
+            // Read the set.
+            7 => Ok(Self::Set(Set::read_le(&mut reader)?)),
+            // Read the branch.eq.
+            8 => Ok(Self::BranchEq(BranchEq::read_le(&mut reader)?)),
+            // Read the branch.neq.
+            9 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
+            // Read the position.
+            10 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the repeat.
+            11 => Ok(Self::Repeat(Repeat::read_le(&mut reader)?)),
             // Invalid variant.
-            3.. => Err(error(format!("Invalid command variant: {}", variant))),
+            12.. => Err(error(format!("Invalid command variant: {}", variant))),
         }
     }
 }
@@ -95,6 +235,60 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the increment.
                 increment.write_le(&mut writer)
             }
+            Self::Contains(contains) => {
+                // Write the variant.
+                3u8.write_le(&mut writer)?;
+                // Write the contains.
+                contains.write_le(&mut writer)
+            }
+            Self::Get(get) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the get.
+                get.write_le(&mut writer)
+            }
+            Self::GetOrInit(get_or_init) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the get.or_init.
+                get_or_init.write_le(&mut writer)
+            }
+            Self::Remove(remove) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the remove.
+                remove.write_le(&mut writer)
+            }
+            Self::Set(set) => {
+                // Write the variant.
+                7u8.write_le(&mut writer)?;
+                // Write the set.
+                set.write_le(&mut writer)
+            }
+            Self::BranchEq(branch_eq) => {
+                // Write the variant.
+                8u8.write_le(&mut writer)?;
+                // Write the branch.eq.
+                branch_eq.write_le(&mut writer)
+            }
+            Self::BranchNeq(branch_neq) => {
+                // Write the variant.
+                9u8.write_le(&mut writer)?;
+                // Write the branch.neq.
+                branch_neq.write_le(&mut writer)
+            }
+            Self::Position(position) => {
+                // Write the variant.
+                10u8.write_le(&mut writer)?;
+                // Write the position.
+                position.write_le(&mut writer)
+            }
+            Self::Repeat(repeat) => {
+                // Write the variant.
+                11u8.write_le(&mut writer)?;
+                // Write the repeat.
+                repeat.write_le(&mut writer)
+            }
         }
     }
 }
@@ -103,10 +297,20 @@ impl<N: Network> Parser for Command<N> {
     /// Parses the string into the command.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Note that `GetOrInit` must be parsed before `Get`, as `get` is a prefix of `get.or_init`.
         alt((
             map(Decrement::parse, |decrement| Self::Decrement(decrement)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
             map(Increment::parse, |increment| Self::Increment(increment)),
+            map(Contains::parse, |contains| Self::Contains(contains)),
+            map(GetOrInit::parse, |get_or_init| Self::GetOrInit(get_or_init)),
+            map(Get::parse, |get| Self::Get(get)),
+            map(Remove::parse, |remove| Self::Remove(remove)),
+            map(Set::parse, |set| Self::Set(set)),
+            map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
+            map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
+            map(Position::parse, |position| Self::Position(position)),
+            map(Repeat::parse, |repeat| Self::Repeat(repeat)),
         ))(string)
     }
 }
@@ -143,6 +347,15 @@ impl<N: Network> Display for Command<N> {
             Self::Decrement(decrement) => Display::fmt(decrement, f),
             Self::Instruction(instruction) => Display::fmt(instruction, f),
             Self::Increment(increment) => Display::fmt(increment, f),
+            Self::Contains(contains) => Display::fmt(contains, f),
+            Self::Get(get) => Display::fmt(get, f),
+            Self::GetOrInit(get_or_init) => Display::fmt(get_or_init, f),
+            Self::Remove(remove) => Display::fmt(remove, f),
+            Self::Set(set) => Display::fmt(set, f),
+            Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
+            Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
+            Self::Position(position) => Display::fmt(position, f),
+            Self::Repeat(repeat) => Display::fmt(repeat, f),
         }
     }
 }
@@ -173,6 +386,60 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Contains
+        let expected = "contains object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Get
+        let expected = "get object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // GetOrInit
+        let expected = "get.or_init object[r0] r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Set
+        let expected = "set r1 into object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchNeq
+        let expected = "branch.neq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Repeat
+        let expected = "repeat 2u32:\n        increment object[r0] by r1;\n    end.repeat;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -194,5 +461,59 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Increment(Increment::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Contains
+        let expected = "contains object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Contains(Contains::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Get
+        let expected = "get object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Get(Get::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // GetOrInit
+        let expected = "get.or_init object[r0] r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::GetOrInit(GetOrInit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Remove(Remove::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Set
+        let expected = "set r1 into object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Set(Set::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchEq(BranchEq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchNeq
+        let expected = "branch.neq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchNeq(BranchNeq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Repeat
+        let expected = "repeat 2u32:\n        increment object[r0] by r1;\n    end.repeat;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Repeat(Repeat::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
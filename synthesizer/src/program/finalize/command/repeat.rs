@@ -0,0 +1,328 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    Command,
+    Contains,
+    Decrement,
+    FinalizeRegisters,
+    Get,
+    GetOrInit,
+    Increment,
+    Instruction,
+    Opcode,
+    ProgramStorage,
+    ProgramStore,
+    Remove,
+    Set,
+    Stack,
+};
+use console::{network::prelude::*, types::U32};
+
+/// Evaluates the `commands` in the body, `count` times in sequence.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Repeat<N: Network> {
+    /// The number of iterations.
+    count: U32<N>,
+    /// The commands in the body, in order of execution.
+    commands: Vec<Command<N>>,
+}
+
+impl<N: Network> Repeat<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("repeat")
+    }
+
+    /// Returns the number of iterations.
+    #[inline]
+    pub const fn count(&self) -> &U32<N> {
+        &self.count
+    }
+
+    /// Returns the commands in the body.
+    #[inline]
+    pub fn commands(&self) -> &[Command<N>] {
+        &self.commands
+    }
+
+    /// Returns the number of commands evaluated by the repeat, i.e. the number of iterations times the size of the body.
+    #[inline]
+    pub fn num_evaluated_commands(&self) -> usize {
+        (*self.count as usize).saturating_mul(self.commands.len())
+    }
+}
+
+impl<N: Network> Repeat<N> {
+    /// Parses a command in the body of a repeat.
+    /// Note: Branches, positions, and nested repeats are not permitted in the body,
+    /// so they are excluded here, rather than rejected after being parsed recursively.
+    fn parse_body_command(string: &str) -> ParserResult<Command<N>> {
+        // Note that `GetOrInit` must be parsed before `Get`, as `get` is a prefix of `get.or_init`.
+        alt((
+            map(Decrement::parse, |decrement| Command::Decrement(decrement)),
+            map(Instruction::parse, |instruction| Command::Instruction(instruction)),
+            map(Increment::parse, |increment| Command::Increment(increment)),
+            map(Contains::parse, |contains| Command::Contains(contains)),
+            map(GetOrInit::parse, |get_or_init| Command::GetOrInit(get_or_init)),
+            map(Get::parse, |get| Command::Get(get)),
+            map(Remove::parse, |remove| Command::Remove(remove)),
+            map(Set::parse, |set| Command::Set(set)),
+        ))(string)
+    }
+}
+
+impl<N: Network> Repeat<N> {
+    /// Evaluates the command.
+    #[inline]
+    pub fn evaluate_finalize<P: ProgramStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &ProgramStore<N, P>,
+        registers: &mut FinalizeRegisters<N>,
+    ) -> Result<()> {
+        // Ensure the number of iterations is within bounds.
+        ensure!(
+            *self.count as usize <= N::MAX_REPEAT_ITERATIONS,
+            "Cannot repeat more than {} times, found '{}'",
+            N::MAX_REPEAT_ITERATIONS,
+            self.count
+        );
+
+        // Retrieve the number of registers assigned before the body.
+        let num_assigned = registers.num_assigned();
+
+        // Evaluate the body, once per iteration.
+        for _ in 0..*self.count {
+            // Unassign the registers of the previous iteration, so they may be reassigned.
+            registers.truncate(num_assigned);
+            for command in &self.commands {
+                // Ensure the command does not branch out of the body.
+                if let Some(position) = command.evaluate_finalize(stack, store, registers)? {
+                    bail!("Cannot branch to '{position}' from inside a 'repeat'")
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for Repeat<N> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the number of iterations from the string.
+        let (string, count) = U32::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ":" from the string.
+        let (string, _) = tag(":")(string)?;
+
+        // Parse the commands from the string.
+        let (string, commands) = many1(Self::parse_body_command)(string)?;
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the "end.repeat" keyword from the string.
+        let (string, _) = tag("end.repeat")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        // Ensure the unrolled body is within bounds.
+        let repeat = Self { count, commands };
+        match repeat.num_evaluated_commands() <= N::MAX_COMMANDS {
+            true => Ok((string, repeat)),
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Cannot repeat more than {} commands", N::MAX_COMMANDS)))
+            })(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for Repeat<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Repeat<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Repeat<N> {
+    /// Prints the command to a string.
+    /// Note: The body is indented relative to the enclosing finalize scope.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} {}:", Self::opcode(), self.count)?;
+        // Print the body.
+        self.commands.iter().try_for_each(|command| write!(f, "\n        {command}"))?;
+        // Print the terminator.
+        write!(f, "\n    end.repeat;")
+    }
+}
+
+impl<N: Network> FromBytes for Repeat<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of iterations.
+        let count = U32::read_le(&mut reader)?;
+
+        // Read the number of commands.
+        let num_commands = u16::read_le(&mut reader)?;
+        // Ensure the number of commands is within bounds.
+        if num_commands == 0 || num_commands as usize > N::MAX_COMMANDS {
+            return Err(error(format!("Failed to deserialize repeat: invalid number of commands ({num_commands})")));
+        }
+        // Ensure the unrolled body is within bounds.
+        if (*count as usize).saturating_mul(num_commands as usize) > N::MAX_COMMANDS {
+            return Err(error(format!("Failed to deserialize repeat: more than {} commands", N::MAX_COMMANDS)));
+        }
+        // Read the commands.
+        let mut commands = Vec::with_capacity(num_commands as usize);
+        for _ in 0..num_commands {
+            // Read the variant.
+            let variant = u8::read_le(&mut reader)?;
+            // Ensure the command is not a branch, position, or nested repeat.
+            // Note: This check precedes reading the command, so nested repeats are never decoded recursively.
+            if Command::<N>::is_control_flow_variant(variant) {
+                return Err(error(format!("Failed to deserialize repeat: invalid command variant ({variant})")));
+            }
+            commands.push(Command::read_le_with_variant(variant, &mut reader)?);
+        }
+
+        // Return the command.
+        Ok(Self { count, commands })
+    }
+}
+
+impl<N: Network> ToBytes for Repeat<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the number of iterations.
+        self.count.write_le(&mut writer)?;
+
+        // Write the number of commands.
+        let num_commands = self.commands.len();
+        match num_commands <= N::MAX_COMMANDS {
+            true => (num_commands as u16).write_le(&mut writer)?,
+            false => return Err(error(format!("Failed to write {num_commands} commands as bytes"))),
+        }
+        // Write the commands.
+        self.commands.iter().try_for_each(|command| command.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let expected = "repeat 3u32:
+        increment account[r0] by 1u64;
+        add r1 r2 into r3;
+    end.repeat;";
+        let (string, repeat) = Repeat::<CurrentNetwork>::parse(expected).unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(*repeat.count, 3, "The number of iterations is incorrect");
+        assert_eq!(repeat.commands.len(), 2, "The number of commands is incorrect");
+        assert_eq!(expected, repeat.to_string());
+
+        // Ensure the body cannot be empty.
+        assert!(Repeat::<CurrentNetwork>::parse("repeat 3u32: end.repeat;").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_control_flow() {
+        // Ensure a nested repeat is rejected.
+        let nested = "repeat 3u32:
+        repeat 3u32:
+            add r0 r1 into r2;
+        end.repeat;
+    end.repeat;";
+        assert!(Repeat::<CurrentNetwork>::from_str(nested).is_err());
+
+        // Ensure a branch or a position is rejected.
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 3u32: branch.eq r0 r1 to exit; end.repeat;").is_err());
+        assert!(Repeat::<CurrentNetwork>::from_str("repeat 3u32: position exit; end.repeat;").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_unrolled_commands_out_of_bounds() {
+        // Ensure the unrolled body may contain up to `MAX_COMMANDS` commands.
+        let max = CurrentNetwork::MAX_COMMANDS;
+        let repeat = format!("repeat {max}u32: add r0 r1 into r2; end.repeat;");
+        assert!(Repeat::<CurrentNetwork>::from_str(&repeat).is_ok());
+
+        // Ensure the unrolled body may not exceed `MAX_COMMANDS` commands.
+        let repeat = format!("repeat {max}u32: add r0 r1 into r2; add r0 r1 into r3; end.repeat;");
+        assert!(Repeat::<CurrentNetwork>::from_str(&repeat).is_err());
+    }
+
+    #[test]
+    fn test_bytes_rejects_control_flow() {
+        let repeat = Repeat::<CurrentNetwork>::from_str("repeat 3u32: add r0 r1 into r2; end.repeat;").unwrap();
+        let bytes = repeat.to_bytes_le().unwrap();
+        assert_eq!(repeat, Repeat::read_le(&bytes[..]).unwrap());
+
+        // Ensure a nested repeat is rejected.
+        let nested = Repeat::<CurrentNetwork> { count: U32::new(3), commands: vec![Command::Repeat(repeat.clone())] };
+        assert!(Repeat::<CurrentNetwork>::read_le(&nested.to_bytes_le().unwrap()[..]).is_err());
+
+        // Ensure a branch or a position is rejected.
+        for command in ["branch.eq r0 r1 to exit;", "position exit;"] {
+            let body =
+                Repeat::<CurrentNetwork> { count: U32::new(3), commands: vec![Command::from_str(command).unwrap()] };
+            assert!(Repeat::<CurrentNetwork>::read_le(&body.to_bytes_le().unwrap()[..]).is_err());
+        }
+
+        // Ensure the unrolled body may not exceed `MAX_COMMANDS` commands.
+        let body = Repeat::<CurrentNetwork> {
+            count: U32::new(CurrentNetwork::MAX_COMMANDS as u32 + 1),
+            commands: repeat.commands.clone(),
+        };
+        assert!(Repeat::<CurrentNetwork>::read_le(&body.to_bytes_le().unwrap()[..]).is_err());
+    }
+}
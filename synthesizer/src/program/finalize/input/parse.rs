@@ -108,9 +108,9 @@ mod tests {
         assert_eq!(input.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("field.public")?);
 
         // Struct
-        let input = Input::<CurrentNetwork>::parse("input r1 as signature.public;").unwrap().1;
+        let input = Input::<CurrentNetwork>::parse("input r1 as message.public;").unwrap().1;
         assert_eq!(input.register(), &Register::<CurrentNetwork>::Locator(1));
-        assert_eq!(input.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("signature.public")?);
+        assert_eq!(input.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("message.public")?);
 
         // Record
         let input = Input::<CurrentNetwork>::parse("input r2 as token.record;").unwrap().1;
@@ -127,8 +127,8 @@ mod tests {
         assert_eq!("input r0 as field.public;", input.to_string());
 
         // Struct
-        let input = Input::<CurrentNetwork>::from_str("input r1 as signature.public;")?;
-        assert_eq!("input r1 as signature.public;", input.to_string());
+        let input = Input::<CurrentNetwork>::from_str("input r1 as message.public;")?;
+        assert_eq!("input r1 as message.public;", input.to_string());
 
         // Record
         let input = Input::<CurrentNetwork>::parse("input r2 as token.record;").unwrap().1;
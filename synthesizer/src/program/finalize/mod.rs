@@ -32,7 +32,7 @@ use console::{
     program::{FinalizeType, Identifier, Register},
 };
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Finalize<N: Network> {
@@ -43,6 +43,8 @@ pub struct Finalize<N: Network> {
     inputs: IndexSet<Input<N>>,
     /// The commands, in order of execution.
     commands: Vec<Command<N>>,
+    /// The mapping of positions to their command index.
+    positions: IndexMap<Identifier<N>, usize>,
     /// The output statements, in order of the desired output.
     outputs: IndexSet<Output<N>>,
 }
@@ -50,7 +52,13 @@ pub struct Finalize<N: Network> {
 impl<N: Network> Finalize<N> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new(), outputs: IndexSet::new() }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            commands: Vec::new(),
+            positions: IndexMap::new(),
+            outputs: IndexSet::new(),
+        }
     }
 
     /// Returns the name of the associated function.
@@ -73,6 +81,11 @@ impl<N: Network> Finalize<N> {
         &self.commands
     }
 
+    /// Returns the mapping of positions to their command index.
+    pub const fn positions(&self) -> &IndexMap<Identifier<N>, usize> {
+        &self.positions
+    }
+
     /// Returns the finalize outputs.
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
@@ -114,13 +127,38 @@ impl<N: Network> Finalize<N> {
     ///
     /// # Errors
     /// This method will halt if the maximum number of commands has been reached.
+    /// This method will halt if the maximum number of evaluated commands, with repeats unrolled, is exceeded.
+    /// This method will halt if the command is a position that was previously added.
     #[inline]
     pub fn add_command(&mut self, command: Command<N>) -> Result<()> {
         // Ensure the maximum number of commands has not been exceeded.
         ensure!(self.commands.len() <= N::MAX_COMMANDS, "Cannot add more than {} commands", N::MAX_COMMANDS);
-
-        // If the command is an instruction, perform additional checks.
-        if let Command::Instruction(instruction) = &command {
+        // Ensure the maximum number of evaluated commands, with the body of each repeat unrolled, is not exceeded.
+        let num_evaluated_commands = self.commands.iter().fold(command.num_evaluated_commands(), |sum, command| {
+            sum.saturating_add(command.num_evaluated_commands())
+        });
+        ensure!(
+            num_evaluated_commands <= N::MAX_COMMANDS,
+            "Cannot evaluate more than {} commands, including the iterations of each 'repeat'",
+            N::MAX_COMMANDS
+        );
+
+        // Retrieve the instructions in the command, including those in the body of a repeat.
+        let instructions = match &command {
+            Command::Instruction(instruction) => vec![instruction],
+            Command::Repeat(repeat) => repeat
+                .commands()
+                .iter()
+                .filter_map(|command| match command {
+                    Command::Instruction(instruction) => Some(instruction),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        // If the command contains instructions, perform additional checks.
+        for instruction in instructions {
             // Ensure the instruction is not a `call`.
             ensure!(
                 !matches!(instruction, Instruction::Call(..)),
@@ -133,6 +171,18 @@ impl<N: Network> Finalize<N> {
             }
         }
 
+        // If the command is a position, record its command index.
+        if let Command::Position(position) = &command {
+            // Ensure the position was not previously added.
+            ensure!(
+                !self.positions.contains_key(position.name()),
+                "Cannot add duplicate position '{}'",
+                position.name()
+            );
+            // Insert the position.
+            self.positions.insert(*position.name(), self.commands.len());
+        }
+
         // Insert the command.
         self.commands.push(command);
         Ok(())
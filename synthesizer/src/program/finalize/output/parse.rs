@@ -99,9 +99,9 @@ mod tests {
         assert_eq!(output.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("field.public")?);
 
         // Struct
-        let output = Output::<CurrentNetwork>::parse("output r1 as signature.public;").unwrap().1;
+        let output = Output::<CurrentNetwork>::parse("output r1 as message.public;").unwrap().1;
         assert_eq!(output.register(), &Register::<CurrentNetwork>::Locator(1));
-        assert_eq!(output.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("signature.public")?);
+        assert_eq!(output.finalize_type(), &FinalizeType::<CurrentNetwork>::from_str("message.public")?);
 
         // Record
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
@@ -118,8 +118,8 @@ mod tests {
         assert_eq!(format!("{}", output), "output r0 as field.public;");
 
         // Struct
-        let output = Output::<CurrentNetwork>::parse("output r1 as signature.public;").unwrap().1;
-        assert_eq!(format!("{}", output), "output r1 as signature.public;");
+        let output = Output::<CurrentNetwork>::parse("output r1 as message.public;").unwrap().1;
+        assert_eq!(format!("{}", output), "output r1 as message.public;");
 
         // Record
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
@@ -161,4 +161,39 @@ finalize foo:
         let finalize = Finalize::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(expected, format!("{finalize}"),);
     }
+
+    #[test]
+    fn test_finalize_display_branch_repeat() {
+        let expected = r"finalize foo:
+    input r0 as u64.public;
+    branch.eq r0 0u64 to done;
+    repeat 2u32:
+        add r0 r0 into r1;
+        increment object[r0] by r1;
+    end.repeat;
+    position done;";
+        let finalize = Finalize::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{finalize}"),);
+        assert_eq!(3, finalize.commands.len());
+        assert_eq!(Some(&2), finalize.positions.get(&Identifier::from_str("done").unwrap()));
+
+        // Ensure a position cannot be defined twice.
+        let candidate = r"finalize foo:
+    input r0 as u64.public;
+    position done;
+    position done;";
+        assert!(Finalize::<CurrentNetwork>::parse(candidate).is_err());
+    }
+
+    #[test]
+    fn test_finalize_parse_repeat_out_of_bounds() {
+        // Ensure the unrolled commands may total `MAX_COMMANDS`.
+        let repeat = format!("repeat {}u32: add r0 r0 into r1; end.repeat;", CurrentNetwork::MAX_COMMANDS - 1);
+        let candidate = format!("finalize foo: input r0 as u64.public; {repeat} add r0 r0 into r2;");
+        assert!(Finalize::<CurrentNetwork>::from_str(&candidate).is_ok());
+
+        // Ensure the unrolled commands may not exceed `MAX_COMMANDS`.
+        let candidate = format!("finalize foo: input r0 as u64.public; {repeat} add r0 r0 into r2; add r0 r0 into r3;");
+        assert!(Finalize::<CurrentNetwork>::from_str(&candidate).is_err());
+    }
 }
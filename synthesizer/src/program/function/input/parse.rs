@@ -108,9 +108,9 @@ mod tests {
         assert_eq!(input.value_type(), &ValueType::<CurrentNetwork>::from_str("field.private")?);
 
         // Struct
-        let input = Input::<CurrentNetwork>::parse("input r1 as signature.private;").unwrap().1;
+        let input = Input::<CurrentNetwork>::parse("input r1 as message.private;").unwrap().1;
         assert_eq!(input.register(), &Register::<CurrentNetwork>::Locator(1));
-        assert_eq!(input.value_type(), &ValueType::<CurrentNetwork>::from_str("signature.private")?);
+        assert_eq!(input.value_type(), &ValueType::<CurrentNetwork>::from_str("message.private")?);
 
         // Record
         let input = Input::<CurrentNetwork>::parse("input r2 as token.record;").unwrap().1;
@@ -127,8 +127,8 @@ mod tests {
         assert_eq!("input r0 as field.private;", input.to_string());
 
         // Struct
-        let input = Input::<CurrentNetwork>::from_str("input r1 as signature.private;")?;
-        assert_eq!("input r1 as signature.private;", input.to_string());
+        let input = Input::<CurrentNetwork>::from_str("input r1 as message.private;")?;
+        assert_eq!("input r1 as message.private;", input.to_string());
 
         // Record
         let input = Input::<CurrentNetwork>::parse("input r2 as token.record;").unwrap().1;
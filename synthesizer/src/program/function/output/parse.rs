@@ -99,9 +99,9 @@ mod tests {
         assert_eq!(output.value_type(), &ValueType::<CurrentNetwork>::from_str("field.private")?);
 
         // Struct
-        let output = Output::<CurrentNetwork>::parse("output r1 as signature.private;").unwrap().1;
+        let output = Output::<CurrentNetwork>::parse("output r1 as message.private;").unwrap().1;
         assert_eq!(output.register(), &Register::<CurrentNetwork>::Locator(1));
-        assert_eq!(output.value_type(), &ValueType::<CurrentNetwork>::from_str("signature.private")?);
+        assert_eq!(output.value_type(), &ValueType::<CurrentNetwork>::from_str("message.private")?);
 
         // Record
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
@@ -118,8 +118,8 @@ mod tests {
         assert_eq!(format!("{}", output), "output r0 as field.private;");
 
         // Struct
-        let output = Output::<CurrentNetwork>::parse("output r1 as signature.private;").unwrap().1;
-        assert_eq!(format!("{}", output), "output r1 as signature.private;");
+        let output = Output::<CurrentNetwork>::parse("output r1 as message.private;").unwrap().1;
+        assert_eq!(format!("{}", output), "output r1 as message.private;");
 
         // Record
         let output = Output::<CurrentNetwork>::parse("output r2 as token.record;").unwrap().1;
@@ -26,7 +26,7 @@ pub use operation::*;
 mod bytes;
 mod parse;
 
-use crate::{Registers, Stack};
+use crate::{FinalizeRegisters, Registers, Stack};
 use console::{
     network::{
         prelude::{
@@ -77,6 +77,8 @@ pub enum Instruction<N: Network> {
     Call(Call<N>),
     /// Casts the operands into the declared type.
     Cast(Cast<N>),
+    /// Casts the operand into the declared literal type, truncating the value if it does not fit.
+    CastLossy(CastLossy<N>),
     /// Performs a BHP commitment on inputs of 256-bit chunks.
     CommitBHP256(CommitBHP256<N>),
     /// Performs a BHP commitment on inputs of 512-bit chunks.
@@ -107,6 +109,8 @@ pub enum Instruction<N: Network> {
     HashBHP768(HashBHP768<N>),
     /// Performs a BHP hash on inputs of 1024-bit chunks.
     HashBHP1024(HashBHP1024<N>),
+    /// Performs a Keccak hash with a 256-bit output.
+    HashKeccak256(HashKeccak256<N>),
     /// Performs a Pedersen hash on up to a 64-bit input.
     HashPED64(HashPED64<N>),
     /// Performs a Pedersen hash on up to a 128-bit input.
@@ -117,6 +121,10 @@ pub enum Instruction<N: Network> {
     HashPSD4(HashPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashPSD8(HashPSD8<N>),
+    /// Performs a SHA-3 hash with a 256-bit output.
+    HashSha3_256(HashSha3_256<N>),
+    /// Performs a SHA-256 hash with a 256-bit output.
+    HashSha256(HashSha256<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -159,6 +167,8 @@ pub enum Instruction<N: Network> {
     Shr(Shr<N>),
     /// Shifts `first` right by `second` bits, continuing past the boundary of the type, storing the outcome in `destination`.
     ShrWrapped(ShrWrapped<N>),
+    /// Verifies the signature `first` over the message `third` for the address `second`, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
     /// Squares 'first', storing the outcome in `destination`.
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
@@ -211,6 +221,7 @@ macro_rules! instruction {
             AssertNeq,
             Call,
             Cast,
+            CastLossy,
             CommitBHP256,
             CommitBHP512,
             CommitBHP768,
@@ -226,11 +237,14 @@ macro_rules! instruction {
             HashBHP512,
             HashBHP768,
             HashBHP1024,
+            HashKeccak256,
             HashPED64,
             HashPED128,
             HashPSD2,
             HashPSD4,
             HashPSD8,
+            HashSha3_256,
+            HashSha256,
             Inv,
             IsEq,
             IsNeq,
@@ -252,6 +266,7 @@ macro_rules! instruction {
             ShlWrapped,
             Shr,
             ShrWrapped,
+            SignVerify,
             Square,
             SquareRoot,
             Sub,
@@ -359,7 +374,13 @@ impl<N: Network> Instruction<N> {
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        instruction!(self, |instruction| instruction.evaluate::<A>(stack, registers))
+        instruction!(self, |instruction| instruction.evaluate(stack, registers))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        instruction!(self, |instruction| instruction.finalize(stack, registers))
     }
 
     /// Executes the instruction.
@@ -404,7 +425,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            61,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -34,6 +34,8 @@ pub enum Operand<N: Network> {
     ProgramID(ProgramID<N>),
     /// The operand is the caller address.
     Caller,
+    /// The operand is the height of the block being finalized.
+    BlockHeight,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
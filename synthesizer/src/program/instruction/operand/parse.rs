@@ -25,6 +25,7 @@ impl<N: Network> Parser for Operand<N> {
             map(Literal::parse, |literal| Self::Literal(literal)),
             map(Register::parse, |register| Self::Register(register)),
             map(tag("self.caller"), |_| Self::Caller),
+            map(tag("block.height"), |_| Self::BlockHeight),
             map(ProgramID::parse, |program_id| Self::ProgramID(program_id)),
         ))(string)
     }
@@ -67,6 +68,8 @@ impl<N: Network> Display for Operand<N> {
             Self::ProgramID(program_id) => Display::fmt(program_id, f),
             // Prints the caller, i.e. self.caller
             Self::Caller => write!(f, "self.caller"),
+            // Prints the block height, i.e. block.height
+            Self::BlockHeight => write!(f, "block.height"),
         }
     }
 }
@@ -95,6 +98,9 @@ mod tests {
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(Operand::Caller, operand);
 
+        let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
+        assert_eq!(Operand::BlockHeight, operand);
+
         // Sanity check a failure case.
         let (remainder, operand) = Operand::<CurrentNetwork>::parse("1field.private").unwrap();
         assert_eq!(Operand::Literal(Literal::from_str("1field")?), operand);
@@ -119,6 +125,9 @@ mod tests {
 
         let operand = Operand::<CurrentNetwork>::parse("self.caller").unwrap().1;
         assert_eq!(format!("{operand}"), "self.caller");
+
+        let operand = Operand::<CurrentNetwork>::parse("block.height").unwrap().1;
+        assert_eq!(format!("{operand}"), "block.height");
     }
 
     #[test]
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{FinalizeRegisters, Opcode, Operand, Registers, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{
@@ -37,9 +37,19 @@ use console::{
 
 use indexmap::IndexMap;
 
+/// Casts the operands into the declared type, ensuring the value is preserved.
+pub type Cast<N> = CastOperation<N, { CastVariant::Cast as u8 }>;
+/// Casts the operand into the declared literal type, truncating the value if it does not fit.
+pub type CastLossy<N> = CastOperation<N, { CastVariant::CastLossy as u8 }>;
+
+enum CastVariant {
+    Cast,
+    CastLossy,
+}
+
 /// Casts the operands into the declared type.
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct Cast<N: Network> {
+pub struct CastOperation<N: Network, const VARIANT: u8> {
     /// The operands.
     operands: Vec<Operand<N>>,
     /// The destination register.
@@ -48,11 +58,15 @@ pub struct Cast<N: Network> {
     register_type: RegisterType<N>,
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
-        Opcode::Cast
+        match VARIANT {
+            0 => Opcode::Cast("cast"),
+            1 => Opcode::Cast("cast.lossy"),
+            _ => panic!("Invalid 'cast' instruction opcode"),
+        }
     }
 
     /// Returns the operands in the operation.
@@ -74,19 +88,44 @@ impl<N: Network> Cast<N> {
     }
 }
 
-impl<N: Network> Cast<N> {
+impl<N: Network, const VARIANT: u8> CastOperation<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N> + RegistersCaller<N>),
     ) -> Result<()> {
+        // Ensure the lossy variant only casts to a literal type.
+        if VARIANT == CastVariant::CastLossy as u8 {
+            ensure!(
+                matches!(self.register_type, RegisterType::Plaintext(PlaintextType::Literal(..))),
+                "Instruction '{}' only supports casting to a literal type",
+                Self::opcode()
+            );
+        }
+
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly one operand");
+                // Retrieve the literal.
+                let literal = match &inputs[0] {
+                    Value::Plaintext(Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+                // Cast the literal.
+                let output = match VARIANT {
+                    0 => literal.cast(literal_type)?,
+                    1 => literal.cast_lossy(literal_type)?,
+                    _ => bail!("Invalid 'cast' variant: {VARIANT}"),
+                };
+                // Store the output.
+                registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Ensure the operands is not empty.
                 ensure!(!inputs.is_empty(), "Casting to a struct requires at least one operand");
@@ -119,6 +158,40 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store(stack, &self.destination, Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the array length.
+                ensure!(
+                    inputs.len() == array_type.length() as usize,
+                    "Casting to '{array_type}' requires exactly {} operands",
+                    array_type.length()
+                );
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
@@ -198,6 +271,12 @@ impl<N: Network> Cast<N> {
         }
     }
 
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
     /// Executes the instruction.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>>(
@@ -207,12 +286,41 @@ impl<N: Network> Cast<N> {
     ) -> Result<()> {
         use circuit::{Eject, Inject, ToBits};
 
+        // Ensure the lossy variant only casts to a literal type.
+        if VARIANT == CastVariant::CastLossy as u8 {
+            ensure!(
+                matches!(self.register_type, RegisterType::Plaintext(PlaintextType::Literal(..))),
+                "Instruction '{}' only supports casting to a literal type",
+                Self::opcode()
+            );
+        }
+
         // Load the operands values.
         let inputs: Vec<_> =
             self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly one operand");
+                // Retrieve the literal.
+                let literal = match &inputs[0] {
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires a literal operand"),
+                };
+                // Cast the literal.
+                let output = match VARIANT {
+                    0 => literal.cast(literal_type)?,
+                    1 => literal.cast_lossy(literal_type)?,
+                    _ => bail!("Invalid 'cast' variant: {VARIANT}"),
+                };
+                // Store the output.
+                registers.store_circuit(
+                    stack,
+                    &self.destination,
+                    circuit::Value::Plaintext(circuit::Plaintext::from(output)),
+                )
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_)) => {
                 // Ensure the operands is not empty.
                 ensure!(!inputs.is_empty(), "Casting to a struct requires at least one operand");
@@ -250,6 +358,43 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the array length.
+                ensure!(
+                    inputs.len() == array_type.length() as usize,
+                    "Casting to '{array_type}' requires exactly {} operands",
+                    array_type.length()
+                );
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(
+                                &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
+                                &register_type,
+                            )?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        circuit::Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = circuit::Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
@@ -353,9 +498,35 @@ impl<N: Network> Cast<N> {
             self.operands.len(),
         );
 
+        // Ensure the lossy variant only casts to a literal type.
+        if VARIANT == CastVariant::CastLossy as u8 {
+            ensure!(
+                matches!(self.register_type, RegisterType::Plaintext(PlaintextType::Literal(..))),
+                "Instruction '{}' only supports casting to a literal type",
+                Self::opcode()
+            );
+        }
+
         // Ensure the output type is defined in the program.
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(input_types.len() == 1, "Casting to a literal requires exactly one operand");
+                // Ensure the input type is a literal that can be cast.
+                match &input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Literal(LiteralType::String)) => {
+                        bail!("Casting from a string literal is unsupported")
+                    }
+                    RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature)) => {
+                        bail!("Casting from a signature literal is unsupported")
+                    }
+                    RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+                    input_type => bail!("Casting to '{literal_type}' requires a literal operand, found '{input_type}'"),
+                }
+                // Ensure the output type is a literal that can be cast into.
+                ensure!(literal_type != LiteralType::String, "Casting to a string literal is unsupported");
+                ensure!(literal_type != LiteralType::Signature, "Casting to a signature literal is unsupported");
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_name)?;
@@ -380,6 +551,36 @@ impl<N: Network> Cast<N> {
                     }
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of input types matches the array length.
+                ensure!(
+                    input_types.len() == array_type.length() as usize,
+                    "Casting to '{array_type}' requires exactly {} operands",
+                    array_type.length()
+                );
+                // Retrieve the element type.
+                let element_type = array_type.element_type();
+                // Ensure the input types match the element type.
+                for input_type in input_types {
+                    match input_type {
+                        // Ensure the plaintext type matches the element type.
+                        RegisterType::Plaintext(plaintext_type) => {
+                            ensure!(
+                                element_type == *plaintext_type,
+                                "Array '{array_type}' element type mismatch: expected '{element_type}', found '{plaintext_type}'"
+                            )
+                        }
+                        // Ensure the input type cannot be a record (this is unsupported behavior).
+                        RegisterType::Record(record_name) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{element_type}', found record '{record_name}'"
+                        ),
+                        // Ensure the input type cannot be an external record (this is unsupported behavior).
+                        RegisterType::ExternalRecord(locator) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{element_type}', found external record '{locator}'"
+                        ),
+                    }
+                }
+            }
             RegisterType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(&record_name)?;
@@ -431,7 +632,7 @@ impl<N: Network> Cast<N> {
     }
 }
 
-impl<N: Network> Parser for Cast<N> {
+impl<N: Network, const VARIANT: u8> Parser for CastOperation<N, VARIANT> {
     /// Parses a string into an operation.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
@@ -480,7 +681,7 @@ impl<N: Network> Parser for Cast<N> {
     }
 }
 
-impl<N: Network> FromStr for Cast<N> {
+impl<N: Network, const VARIANT: u8> FromStr for CastOperation<N, VARIANT> {
     type Err = Error;
 
     /// Parses a string into an operation.
@@ -498,14 +699,14 @@ impl<N: Network> FromStr for Cast<N> {
     }
 }
 
-impl<N: Network> Debug for Cast<N> {
+impl<N: Network, const VARIANT: u8> Debug for CastOperation<N, VARIANT> {
     /// Prints the operation as a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         Display::fmt(self, f)
     }
 }
 
-impl<N: Network> Display for Cast<N> {
+impl<N: Network, const VARIANT: u8> Display for CastOperation<N, VARIANT> {
     /// Prints the operation to a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // Ensure the number of operands is within the bounds.
@@ -525,7 +726,7 @@ impl<N: Network> Display for Cast<N> {
     }
 }
 
-impl<N: Network> FromBytes for Cast<N> {
+impl<N: Network, const VARIANT: u8> FromBytes for CastOperation<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the number of operands.
@@ -565,7 +766,7 @@ impl<N: Network> FromBytes for Cast<N> {
     }
 }
 
-impl<N: Network> ToBytes for Cast<N> {
+impl<N: Network, const VARIANT: u8> ToBytes for CastOperation<N, VARIANT> {
     /// Writes the operation to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Ensure the number of operands is within the bounds.
@@ -592,7 +793,11 @@ impl<N: Network> ToBytes for Cast<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{network::Testnet3, program::Identifier};
+    use console::{
+        network::Testnet3,
+        program::{Access, ArrayType, Identifier},
+        types::U32,
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -604,17 +809,17 @@ mod tests {
         assert_eq!(cast.operands.len(), 3, "The number of operands is incorrect");
         assert_eq!(
             cast.operands[0],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("owner").unwrap()])),
+            Operand::Register(Register::Member(0, vec![Access::Member(Identifier::from_str("owner").unwrap())])),
             "The first operand is incorrect"
         );
         assert_eq!(
             cast.operands[1],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("gates").unwrap()])),
+            Operand::Register(Register::Member(0, vec![Access::Member(Identifier::from_str("gates").unwrap())])),
             "The second operand is incorrect"
         );
         assert_eq!(
             cast.operands[2],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("token_amount").unwrap()])),
+            Operand::Register(Register::Member(0, vec![Access::Member(Identifier::from_str("token_amount").unwrap())])),
             "The third operand is incorrect"
         );
         assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
@@ -625,6 +830,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_into_literal() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 into r1 as u8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8)),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast r0 into r1 as u8");
+
+        let (string, cast) = CastLossy::<CurrentNetwork>::parse("cast.lossy r0 into r1 as u8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(cast.to_string(), "cast.lossy r0 into r1 as u8");
+        assert_eq!(CastLossy::<CurrentNetwork>::from_bytes_le(&cast.to_bytes_le().unwrap()).unwrap(), cast);
+
+        // Ensure the opcodes are not interchangeable.
+        assert!(Cast::<CurrentNetwork>::parse("cast.lossy r0 into r1 as u8").is_err());
+        assert!(CastLossy::<CurrentNetwork>::parse("cast r0 into r1 as u8").is_err());
+    }
+
+    #[test]
+    fn test_parse_cast_into_array() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 r1[0u32] 5u64 into r2 as [u64; 3]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(
+            cast.operands[1],
+            Operand::Register(Register::Member(1, vec![Access::Index(U32::new(0))])),
+            "The second operand is incorrect"
+        );
+        assert_eq!(cast.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Array(ArrayType::from_str("[u64; 3]").unwrap())),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast r0 r1[0u32] 5u64 into r2 as [u64; 3]");
+        assert_eq!(Cast::<CurrentNetwork>::from_bytes_le(&cast.to_bytes_le().unwrap()).unwrap(), cast);
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{FinalizeRegisters, Opcode, Operand, Registers, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Address,
 };
 
 /// BHP256 is a collision-resistant function that processes inputs in 256-bit chunks.
@@ -50,6 +51,8 @@ pub struct CommitInstruction<N: Network, const VARIANT: u8> {
     operands: Vec<Operand<N>>,
     /// The destination register.
     destination: Register<N>,
+    /// The destination register type.
+    destination_type: LiteralType,
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
@@ -81,15 +84,21 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> LiteralType {
+        self.destination_type
+    }
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() != 2 {
@@ -115,10 +124,31 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             5 => Literal::Group(N::commit_ped128(&input.to_bits_le(), &randomizer)?),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         };
+        // Convert the commitment to the destination type.
+        let output = match (output, self.destination_type) {
+            (Literal::Group(output), LiteralType::Group) => Literal::Group(output),
+            (Literal::Group(output), LiteralType::Address) => Literal::Address(Address::new(output)),
+            // Note: Field commitments are mapped to a group element with Poseidon.
+            (Literal::Field(output), LiteralType::Group) => Literal::Group(N::hash_to_group_psd2(&[output])?),
+            (Literal::Field(output), LiteralType::Address) => {
+                Literal::Address(Address::new(N::hash_to_group_psd2(&[output])?))
+            }
+            // Truncate the commitment (or its x-coordinate) to the destination type.
+            (Literal::Group(output), destination_type) => {
+                Literal::Field(output.to_x_coordinate()).cast_lossy(destination_type)?
+            }
+            (output, destination_type) => output.cast_lossy(destination_type)?,
+        };
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
 
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
     /// Executes the instruction.
     #[inline]
     pub fn execute<A: circuit::Aleo<Network = N>>(
@@ -126,7 +156,7 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        use circuit::ToBits;
+        use circuit::{FromGroup, ToBits};
 
         // Ensure the number of operands is correct.
         if self.operands.len() != 2 {
@@ -144,7 +174,7 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             _ => bail!("Invalid randomizer type for the commit execution, expected a scalar"),
         };
 
-        // Commits the input.
+        // Commit the input.
         let output = match VARIANT {
             0 => circuit::Literal::Field(A::commit_bhp256(&input.to_bits_le(), &randomizer)),
             1 => circuit::Literal::Field(A::commit_bhp512(&input.to_bits_le(), &randomizer)),
@@ -154,6 +184,25 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             5 => circuit::Literal::Group(A::commit_ped128(&input.to_bits_le(), &randomizer)),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         };
+        // Convert the commitment to the destination type.
+        let output = match (output, self.destination_type) {
+            (circuit::Literal::Group(output), LiteralType::Group) => circuit::Literal::Group(output),
+            (circuit::Literal::Group(output), LiteralType::Address) => {
+                circuit::Literal::Address(circuit::Address::from_group(output))
+            }
+            // Note: Field commitments are mapped to a group element with Poseidon.
+            (circuit::Literal::Field(output), LiteralType::Group) => {
+                circuit::Literal::Group(A::hash_to_group_psd2(&[output]))
+            }
+            (circuit::Literal::Field(output), LiteralType::Address) => {
+                circuit::Literal::Address(circuit::Address::from_group(A::hash_to_group_psd2(&[output])))
+            }
+            // Truncate the commitment (or its x-coordinate) to the destination type.
+            (circuit::Literal::Group(output), destination_type) => {
+                circuit::Literal::Field(output.to_x_coordinate()).cast_lossy(destination_type)?
+            }
+            (output, destination_type) => output.cast_lossy(destination_type)?,
+        };
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
@@ -172,10 +221,15 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
         }
 
+        // Ensure the destination type is not a string or a signature.
+        if matches!(self.destination_type, LiteralType::String | LiteralType::Signature) {
+            bail!("Instruction '{}' cannot output a '{}'", Self::opcode(), self.destination_type)
+        }
+
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0 | 1 | 2 | 3 | 4 | 5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))]),
+            0 | 1 | 2 | 3 | 4 | 5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type))]),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         }
     }
@@ -203,8 +257,16 @@ impl<N: Network, const VARIANT: u8> Parser for CommitInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        let (string, destination_type) = LiteralType::parse(string)?;
 
-        Ok((string, Self { operands: vec![first, second], destination }))
+        Ok((string, Self { operands: vec![first, second], destination, destination_type }))
     }
 }
 
@@ -244,7 +306,7 @@ impl<N: Network, const VARIANT: u8> Display for CommitInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{} ", operand))?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into {} as {}", self.destination, self.destination_type)
     }
 }
 
@@ -259,9 +321,11 @@ impl<N: Network, const VARIANT: u8> FromBytes for CommitInstruction<N, VARIANT>
         }
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        let destination_type = LiteralType::read_le(&mut reader)?;
 
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands, destination, destination_type })
     }
 }
 
@@ -275,7 +339,9 @@ impl<N: Network, const VARIANT: u8> ToBytes for CommitInstruction<N, VARIANT> {
         // Write the operands.
         self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
         // Write the destination register.
-        self.destination.write_le(&mut writer)
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        self.destination_type.write_le(&mut writer)
     }
 }
 
@@ -288,11 +354,21 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let (string, commit) = CommitBHP512::<CurrentNetwork>::parse("commit.bhp512 r0 r1 into r2").unwrap();
+        let (string, commit) = CommitBHP512::<CurrentNetwork>::parse("commit.bhp512 r0 r1 into r2 as field").unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
         assert_eq!(commit.operands.len(), 2, "The number of operands is incorrect");
         assert_eq!(commit.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(commit.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
         assert_eq!(commit.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(commit.destination_type, LiteralType::Field, "The destination type is incorrect");
+
+        let (string, commit) = CommitPED64::<CurrentNetwork>::parse("commit.ped64 r0 r1 into r2 as address").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.destination_type, LiteralType::Address, "The destination type is incorrect");
+        assert_eq!(commit.to_string(), "commit.ped64 r0 r1 into r2 as address");
+        assert_eq!(CommitPED64::<CurrentNetwork>::from_bytes_le(&commit.to_bytes_le().unwrap()).unwrap(), commit);
+
+        // Ensure the destination type is required.
+        assert!(CommitBHP256::<CurrentNetwork>::from_str("commit.bhp256 r0 r1 into r2").is_err());
     }
 }
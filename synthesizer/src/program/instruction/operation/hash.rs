@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{FinalizeRegisters, Opcode, Operand, Registers, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
-    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Address, Field},
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -29,6 +30,11 @@ pub type HashBHP768<N> = HashInstruction<N, { Hasher::BHP768 as u8 }>;
 /// BHP1024 is a collision-resistant hash function that processes inputs in 1024-bit chunks.
 pub type HashBHP1024<N> = HashInstruction<N, { Hasher::BHP1024 as u8 }>;
 
+/// Keccak256 is a cryptographic hash function that outputs a 256-bit digest, as used in Ethereum.
+/// It hashes the raw bits of a literal (or an array of literals), and outputs the full digest into
+/// an array of unsigned integers, such as `[u8; 32]` or `[u128; 2]`.
+pub type HashKeccak256<N> = HashInstruction<N, { Hasher::Keccak256 as u8 }>;
+
 /// Pedersen64 is a collision-resistant hash function that processes inputs in 64-bit chunks.
 pub type HashPED64<N> = HashInstruction<N, { Hasher::PED64 as u8 }>;
 /// Pedersen128 is a collision-resistant hash function that processes inputs in 128-bit chunks.
@@ -41,6 +47,11 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// SHA3-256 is a cryptographic hash function that outputs a 256-bit digest.
+pub type HashSha3_256<N> = HashInstruction<N, { Hasher::Sha3_256 as u8 }>;
+/// SHA-256 is a cryptographic hash function that outputs a 256-bit digest.
+pub type HashSha256<N> = HashInstruction<N, { Hasher::Sha256 as u8 }>;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -51,6 +62,9 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    Keccak256,
+    Sha3_256,
+    Sha256,
 }
 
 /// Hashes the operand into the declared type.
@@ -60,6 +74,9 @@ pub struct HashInstruction<N: Network, const VARIANT: u8> {
     operands: Vec<Operand<N>>,
     /// The destination register.
     destination: Register<N>,
+    /// The destination register type.
+    /// Note: Only the 256-bit digests (Keccak256, SHA3-256, SHA-256) may be output into an array.
+    destination_type: PlaintextType<N>,
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -76,6 +93,9 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.keccak256"),
+            10 => Opcode::Hash("hash.sha3_256"),
+            11 => Opcode::Hash("hash.sha256"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -94,15 +114,41 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> PlaintextType<N> {
+        self.destination_type
+    }
+
+    /// Returns `true` if the variant outputs a 256-bit digest.
+    #[inline]
+    const fn is_digest_256() -> bool {
+        matches!(VARIANT, 9..=11)
+    }
+
+    /// Returns the number of bits in each element of the given array type,
+    /// if it is an array of unsigned integers that holds exactly 256 bits.
+    fn digest_element_size_in_bits(array_type: &ArrayType<N>) -> Option<usize> {
+        let size_in_bits = match array_type.element_type() {
+            PlaintextType::Literal(LiteralType::U8) => 8,
+            PlaintextType::Literal(LiteralType::U16) => 16,
+            PlaintextType::Literal(LiteralType::U32) => 32,
+            PlaintextType::Literal(LiteralType::U64) => 64,
+            PlaintextType::Literal(LiteralType::U128) => 128,
+            _ => return None,
+        };
+        (size_in_bits * array_type.length() as usize == 256).then_some(size_in_bits)
+    }
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() != 1 {
@@ -111,20 +157,115 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
-            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        let output = match self.destination_type {
+            PlaintextType::Literal(LiteralType::Address | LiteralType::Group) => {
+                // Hash the input to a group element.
+                let output = match VARIANT {
+                    6 => N::hash_to_group_psd2(&input.to_fields()?)?,
+                    7 => N::hash_to_group_psd4(&input.to_fields()?)?,
+                    8 => N::hash_to_group_psd8(&input.to_fields()?)?,
+                    // Note: The remaining hash functions map their digest to a group element with Poseidon.
+                    _ => N::hash_to_group_psd2(&[self.hash_to_field(&input)?])?,
+                };
+                match self.destination_type {
+                    PlaintextType::Literal(LiteralType::Address) => {
+                        Plaintext::from(Literal::Address(Address::new(output)))
+                    }
+                    _ => Plaintext::from(Literal::Group(output)),
+                }
+            }
+            PlaintextType::Literal(LiteralType::Scalar) => {
+                // Hash the input to a scalar element.
+                let output = match VARIANT {
+                    6 => N::hash_to_scalar_psd2(&input.to_fields()?)?,
+                    7 => N::hash_to_scalar_psd4(&input.to_fields()?)?,
+                    8 => N::hash_to_scalar_psd8(&input.to_fields()?)?,
+                    // Note: The remaining hash functions map their digest to a scalar element with Poseidon.
+                    _ => N::hash_to_scalar_psd2(&[self.hash_to_field(&input)?])?,
+                };
+                Plaintext::from(Literal::Scalar(output))
+            }
+            // Truncate the digest to the destination type.
+            PlaintextType::Literal(destination_type) => {
+                Plaintext::from(Literal::Field(self.hash_to_field(&input)?).cast_lossy(destination_type)?)
+            }
+            // Split the full digest into the elements of the array.
+            PlaintextType::Array(array_type) => {
+                let size_in_bits = match Self::digest_element_size_in_bits(&array_type) {
+                    Some(size_in_bits) if Self::is_digest_256() => size_in_bits,
+                    _ => bail!("Instruction '{}' cannot output a '{array_type}'", Self::opcode()),
+                };
+                let variant = match array_type.element_type() {
+                    PlaintextType::Literal(element_type) => element_type as u8,
+                    _ => bail!("Instruction '{}' cannot output a '{array_type}'", Self::opcode()),
+                };
+                let elements = self
+                    .hash_to_bits(&input)?
+                    .chunks(size_in_bits)
+                    .map(|bits_le| Ok(Plaintext::from(Literal::from_bits_le(variant, bits_le)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                Plaintext::Array(elements, Default::default())
+            }
+            PlaintextType::Struct(..) => bail!("Instruction '{}' cannot output a struct", Self::opcode()),
         };
         // Store the output.
-        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Returns the raw bits of the given literal, or array of literals, without their type information.
+    /// Note: This is the input to the 256-bit digests, so that byte-aligned inputs match the standard digests.
+    fn to_raw_bits_le(input: &Value<N>) -> Result<Vec<bool>> {
+        match input {
+            Value::Plaintext(Plaintext::Literal(literal, _)) => Ok(literal.to_bits_le()),
+            Value::Plaintext(Plaintext::Array(elements, _)) => {
+                let mut bits_le = Vec::new();
+                for element in elements {
+                    match element {
+                        Plaintext::Literal(literal, _) => bits_le.extend(literal.to_bits_le()),
+                        _ => bail!("Instruction '{}' expects an array of literals", Self::opcode()),
+                    }
+                }
+                Ok(bits_le)
+            }
+            _ => bail!("Instruction '{}' expects a literal or an array of literals", Self::opcode()),
+        }
+    }
+
+    /// Hashes the given input into the full 256-bit digest, as little-endian bits.
+    #[inline]
+    fn hash_to_bits(&self, input: &Value<N>) -> Result<Vec<bool>> {
+        match VARIANT {
+            9 => N::hash_keccak256(&Self::to_raw_bits_le(input)?),
+            10 => N::hash_sha3_256(&Self::to_raw_bits_le(input)?),
+            11 => N::hash_sha256(&Self::to_raw_bits_le(input)?),
+            _ => bail!("Invalid 'hash' variant for a 256-bit digest: {VARIANT}"),
+        }
+    }
+
+    /// Hashes the given input into a base field element.
+    #[inline]
+    fn hash_to_field(&self, input: &Value<N>) -> Result<Field<N>> {
+        match VARIANT {
+            0 => N::hash_bhp256(&input.to_bits_le()),
+            1 => N::hash_bhp512(&input.to_bits_le()),
+            2 => N::hash_bhp768(&input.to_bits_le()),
+            3 => N::hash_bhp1024(&input.to_bits_le()),
+            4 => N::hash_ped64(&input.to_bits_le()),
+            5 => N::hash_ped128(&input.to_bits_le()),
+            6 => N::hash_psd2(&input.to_fields()?),
+            7 => N::hash_psd4(&input.to_fields()?),
+            8 => N::hash_psd8(&input.to_fields()?),
+            // Note: The 256-bit digests are truncated to the data capacity of a field element.
+            // To retrieve the full digest, output it into an array of unsigned integers instead.
+            9..=11 => Field::from_bits_le(&self.hash_to_bits(input)?[..Field::<N>::SIZE_IN_DATA_BITS]),
+            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        }
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut FinalizeRegisters<N>) -> Result<()> {
+        self.evaluate(stack, registers)
     }
 
     /// Executes the instruction.
@@ -134,7 +275,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
     ) -> Result<()> {
-        use circuit::{ToBits, ToFields};
+        use circuit::{FromGroup, Inject, ToFields};
 
         // Ensure the number of operands is correct.
         if self.operands.len() != 1 {
@@ -143,23 +284,131 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
-            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        let output = match self.destination_type {
+            PlaintextType::Literal(LiteralType::Address | LiteralType::Group) => {
+                // Hash the input to a group element.
+                let output = match VARIANT {
+                    6 => A::hash_to_group_psd2(&input.to_fields()),
+                    7 => A::hash_to_group_psd4(&input.to_fields()),
+                    8 => A::hash_to_group_psd8(&input.to_fields()),
+                    // Note: The remaining hash functions map their digest to a group element with Poseidon.
+                    _ => A::hash_to_group_psd2(&[self.hash_to_field_circuit::<A>(&input)?]),
+                };
+                let output = match self.destination_type {
+                    PlaintextType::Literal(LiteralType::Address) => {
+                        circuit::Literal::Address(circuit::Address::from_group(output))
+                    }
+                    _ => circuit::Literal::Group(output),
+                };
+                circuit::Plaintext::Literal(output, Default::default())
+            }
+            PlaintextType::Literal(LiteralType::Scalar) => {
+                // Hash the input to a scalar element.
+                let output = match VARIANT {
+                    6 => A::hash_to_scalar_psd2(&input.to_fields()),
+                    7 => A::hash_to_scalar_psd4(&input.to_fields()),
+                    8 => A::hash_to_scalar_psd8(&input.to_fields()),
+                    // Note: The remaining hash functions map their digest to a scalar element with Poseidon.
+                    _ => A::hash_to_scalar_psd2(&[self.hash_to_field_circuit::<A>(&input)?]),
+                };
+                circuit::Plaintext::Literal(circuit::Literal::Scalar(output), Default::default())
+            }
+            // Truncate the digest to the destination type.
+            PlaintextType::Literal(destination_type) => {
+                let output =
+                    circuit::Literal::Field(self.hash_to_field_circuit::<A>(&input)?).cast_lossy(destination_type)?;
+                circuit::Plaintext::Literal(output, Default::default())
+            }
+            // Split the full digest into the elements of the array.
+            PlaintextType::Array(array_type) => {
+                let size_in_bits = match Self::digest_element_size_in_bits(&array_type) {
+                    Some(size_in_bits) if Self::is_digest_256() => size_in_bits,
+                    _ => bail!("Instruction '{}' cannot output a '{array_type}'", Self::opcode()),
+                };
+                let variant = match array_type.element_type() {
+                    PlaintextType::Literal(element_type) => {
+                        circuit::U8::constant(console::types::U8::new(element_type as u8))
+                    }
+                    _ => bail!("Instruction '{}' cannot output a '{array_type}'", Self::opcode()),
+                };
+                let elements = self
+                    .hash_to_bits_circuit::<A>(&input)?
+                    .chunks(size_in_bits)
+                    .map(|bits_le| {
+                        circuit::Plaintext::Literal(
+                            circuit::Literal::from_bits_le(&variant, bits_le),
+                            Default::default(),
+                        )
+                    })
+                    .collect();
+                circuit::Plaintext::Array(elements, Default::default())
+            }
+            PlaintextType::Struct(..) => bail!("Instruction '{}' cannot output a struct", Self::opcode()),
         };
-        // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
         // Store the output.
-        registers.store_circuit(stack, &self.destination, output)
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Returns the raw bits of the given circuit literal, or array of literals, without their type information.
+    fn to_raw_bits_le_circuit<A: circuit::Aleo<Network = N>>(
+        input: &circuit::Value<A>,
+    ) -> Result<Vec<circuit::Boolean<A>>> {
+        use circuit::ToBits;
+
+        match input {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, _)) => Ok(literal.to_bits_le()),
+            circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => {
+                let mut bits_le = Vec::new();
+                for element in elements {
+                    match element {
+                        circuit::Plaintext::Literal(literal, _) => bits_le.extend(literal.to_bits_le()),
+                        _ => bail!("Instruction '{}' expects an array of literals", Self::opcode()),
+                    }
+                }
+                Ok(bits_le)
+            }
+            _ => bail!("Instruction '{}' expects a literal or an array of literals", Self::opcode()),
+        }
+    }
+
+    /// Hashes the given circuit input into the full 256-bit digest, as little-endian bits.
+    #[inline]
+    fn hash_to_bits_circuit<A: circuit::Aleo<Network = N>>(
+        &self,
+        input: &circuit::Value<A>,
+    ) -> Result<Vec<circuit::Boolean<A>>> {
+        match VARIANT {
+            9 => Ok(A::hash_keccak256(&Self::to_raw_bits_le_circuit(input)?)),
+            10 => Ok(A::hash_sha3_256(&Self::to_raw_bits_le_circuit(input)?)),
+            11 => Ok(A::hash_sha256(&Self::to_raw_bits_le_circuit(input)?)),
+            _ => bail!("Invalid 'hash' variant for a 256-bit digest: {VARIANT}"),
+        }
+    }
+
+    /// Hashes the given circuit input into a base field element.
+    #[inline]
+    fn hash_to_field_circuit<A: circuit::Aleo<Network = N>>(
+        &self,
+        input: &circuit::Value<A>,
+    ) -> Result<circuit::Field<A>> {
+        use circuit::{FromBits, ToBits, ToFields};
+
+        match VARIANT {
+            0 => Ok(A::hash_bhp256(&input.to_bits_le())),
+            1 => Ok(A::hash_bhp512(&input.to_bits_le())),
+            2 => Ok(A::hash_bhp768(&input.to_bits_le())),
+            3 => Ok(A::hash_bhp1024(&input.to_bits_le())),
+            4 => Ok(A::hash_ped64(&input.to_bits_le())),
+            5 => Ok(A::hash_ped128(&input.to_bits_le())),
+            6 => Ok(A::hash_psd2(&input.to_fields())),
+            7 => Ok(A::hash_psd4(&input.to_fields())),
+            8 => Ok(A::hash_psd8(&input.to_fields())),
+            // Note: The 256-bit digests are truncated to the data capacity of a field element.
+            9..=11 => Ok(circuit::Field::from_bits_le(
+                &self.hash_to_bits_circuit::<A>(input)?[..Field::<N>::SIZE_IN_DATA_BITS],
+            )),
+            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        }
     }
 
     /// Returns the output type from the given program and input types.
@@ -174,12 +423,37 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             bail!("Instruction '{}' expects 1 operands, found {} operands", Self::opcode(), self.operands.len())
         }
 
+        // Ensure the destination type is a literal (that is not a string or a signature),
+        // or an array of unsigned integers that holds the full 256-bit digest.
+        match &self.destination_type {
+            PlaintextType::Literal(LiteralType::String | LiteralType::Signature) => {
+                bail!("Instruction '{}' cannot output a '{}'", Self::opcode(), self.destination_type)
+            }
+            PlaintextType::Literal(..) => (),
+            PlaintextType::Array(array_type)
+                if Self::is_digest_256() && Self::digest_element_size_in_bits(array_type).is_some() => {}
+            _ => bail!("Instruction '{}' cannot output a '{}'", Self::opcode(), self.destination_type),
+        }
+
+        // Ensure the input of a 256-bit digest is a literal, or an array of literals.
+        if Self::is_digest_256() {
+            match &input_types[0] {
+                RegisterType::Plaintext(PlaintextType::Literal(..)) => (),
+                RegisterType::Plaintext(PlaintextType::Array(array_type))
+                    if matches!(array_type.element_type(), PlaintextType::Literal(..)) => {}
+                input_type => {
+                    bail!(
+                        "Instruction '{}' expects a literal or an array of literals, found '{input_type}'",
+                        Self::opcode()
+                    )
+                }
+            }
+        }
+
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
-                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
-            }
+            0..=11 => Ok(vec![RegisterType::Plaintext(self.destination_type)]),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
     }
@@ -203,8 +477,20 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        // Note: Only the 256-bit digests may be output into an array.
+        let (string, destination_type) = match Self::is_digest_256() {
+            true => PlaintextType::parse(string)?,
+            false => map(LiteralType::parse, PlaintextType::Literal)(string)?,
+        };
 
-        Ok((string, Self { operands: vec![operand], destination }))
+        Ok((string, Self { operands: vec![operand], destination, destination_type }))
     }
 }
 
@@ -244,7 +530,7 @@ impl<N: Network, const VARIANT: u8> Display for HashInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{} ", operand))?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into {} as {}", self.destination, self.destination_type)
     }
 }
 
@@ -255,8 +541,14 @@ impl<N: Network, const VARIANT: u8> FromBytes for HashInstruction<N, VARIANT> {
         let operands = vec![Operand::read_le(&mut reader)?];
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        // Note: Only the 256-bit digests may be output into an array, so the remaining variants encode a literal type.
+        let destination_type = match Self::is_digest_256() {
+            true => PlaintextType::read_le(&mut reader)?,
+            false => PlaintextType::Literal(LiteralType::read_le(&mut reader)?),
+        };
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands, destination, destination_type })
     }
 }
 
@@ -270,7 +562,15 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
         // Write the operand.
         self.operands[0].write_le(&mut writer)?;
         // Write the destination register.
-        self.destination.write_le(&mut writer)
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        match (Self::is_digest_256(), &self.destination_type) {
+            (true, destination_type) => destination_type.write_le(&mut writer),
+            (false, PlaintextType::Literal(literal_type)) => literal_type.write_le(&mut writer),
+            (false, destination_type) => {
+                Err(error(format!("Instruction '{}' cannot output a '{destination_type}'", Self::opcode())))
+            }
+        }
     }
 }
 
@@ -283,10 +583,47 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let (string, hash) = HashBHP512::<CurrentNetwork>::parse("hash.bhp512 r0 into r1").unwrap();
+        let (string, hash) = HashBHP512::<CurrentNetwork>::parse("hash.bhp512 r0 into r1 as field").unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
         assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            hash.destination_type,
+            PlaintextType::Literal(LiteralType::Field),
+            "The destination type is incorrect"
+        );
+
+        let (string, hash) = HashPSD2::<CurrentNetwork>::parse("hash.psd2 r0 into r1 as u64").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(
+            hash.destination_type,
+            PlaintextType::Literal(LiteralType::U64),
+            "The destination type is incorrect"
+        );
+        assert_eq!(hash.to_string(), "hash.psd2 r0 into r1 as u64");
+        assert_eq!(HashPSD2::<CurrentNetwork>::from_bytes_le(&hash.to_bytes_le().unwrap()).unwrap(), hash);
+
+        let (string, hash) = HashKeccak256::<CurrentNetwork>::parse("hash.keccak256 r0 into r1 as u128").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.to_string(), "hash.keccak256 r0 into r1 as u128");
+
+        let (string, hash) = HashSha3_256::<CurrentNetwork>::parse("hash.sha3_256 r0 into r1 as field").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.to_string(), "hash.sha3_256 r0 into r1 as field");
+
+        let (string, hash) = HashSha256::<CurrentNetwork>::parse("hash.sha256 r0 into r1 as address").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.to_string(), "hash.sha256 r0 into r1 as address");
+
+        let (string, hash) = HashKeccak256::<CurrentNetwork>::parse("hash.keccak256 r0 into r1 as [u8; 32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.to_string(), "hash.keccak256 r0 into r1 as [u8; 32]");
+        assert_eq!(HashKeccak256::<CurrentNetwork>::from_bytes_le(&hash.to_bytes_le().unwrap()).unwrap(), hash);
+
+        // Ensure the destination type is required.
+        assert!(HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1").is_err());
+        // Ensure only the 256-bit digests may be output into an array.
+        assert!(HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1 as [u8; 32]").is_err());
     }
 }
@@ -37,7 +37,7 @@ mod serialize;
 
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
+    program::{EntryType, FinalizeType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
 };
 
 use indexmap::IndexMap;
@@ -165,6 +165,16 @@ function fee:
         &self.mappings
     }
 
+    /// Returns the structs in the program.
+    pub const fn structs(&self) -> &IndexMap<Identifier<N>, Struct<N>> {
+        &self.structs
+    }
+
+    /// Returns the records in the program.
+    pub const fn records(&self) -> &IndexMap<Identifier<N>, RecordType<N>> {
+        &self.records
+    }
+
     /// Returns the closures in the program.
     pub const fn closures(&self) -> &IndexMap<Identifier<N>, Closure<N>> {
         &self.closures
@@ -308,11 +318,36 @@ impl<N: Network> Program<N> {
     /// # Errors
     /// This method will halt if the mapping name is already in use.
     /// This method will halt if the mapping name is a reserved opcode or keyword.
+    /// This method will halt if the mapping key or value is not a public plaintext type.
+    /// This method will halt if any structs in the mapping key or value are not already defined.
     #[inline]
     fn add_mapping(&mut self, mapping: Mapping<N>) -> Result<()> {
         // Retrieve the mapping name.
         let mapping_name = *mapping.name();
 
+        // Ensure the mapping key and value are well-formed.
+        for finalize_type in [mapping.key().finalize_type(), mapping.value().finalize_type()] {
+            // Ensure the type is a public plaintext type.
+            let plaintext_type = match finalize_type {
+                FinalizeType::Public(plaintext_type) => plaintext_type,
+                FinalizeType::Record(..) | FinalizeType::ExternalRecord(..) => {
+                    bail!("Mapping '{mapping_name}' cannot contain a record type ('{finalize_type}').")
+                }
+            };
+            // Ensure the struct, if any, is already defined in the program.
+            let struct_name = match plaintext_type {
+                PlaintextType::Literal(..) => continue,
+                PlaintextType::Struct(struct_name) => *struct_name,
+                PlaintextType::Array(array_type) => match array_type.element_type() {
+                    PlaintextType::Struct(struct_name) => struct_name,
+                    _ => continue,
+                },
+            };
+            if !self.structs.contains_key(&struct_name) {
+                bail!("'{struct_name}' in mapping '{mapping_name}' is not defined.")
+            }
+        }
+
         // Ensure the mapping name is new.
         ensure!(self.is_unique_name(&mapping_name), "'{mapping_name}' is already in use.");
         // Ensure the mapping name is not a reserved keyword.
@@ -367,6 +402,14 @@ impl<N: Network> Program<N> {
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
+                PlaintextType::Array(array_type) => {
+                    // Ensure the element struct name, if any, exists in the program.
+                    if let PlaintextType::Struct(element_identifier) = array_type.element_type() {
+                        if !self.structs.contains_key(&element_identifier) {
+                            bail!("'{element_identifier}' in struct '{}' is not defined.", struct_name)
+                        }
+                    }
+                }
             }
         }
 
@@ -420,6 +463,13 @@ impl<N: Network> Program<N> {
                             bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                         }
                     }
+                    PlaintextType::Array(array_type) => {
+                        if let PlaintextType::Struct(identifier) = array_type.element_type() {
+                            if !self.structs.contains_key(&identifier) {
+                                bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
+                            }
+                        }
+                    }
                 },
             }
         }
@@ -552,6 +602,7 @@ impl<N: Network> Program<N> {
         "u128",
         "scalar",
         "string",
+        "signature",
         // Boolean
         "true",
         "false",
@@ -672,6 +723,47 @@ mapping message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_mapping_struct_value() -> Result<()> {
+        // Create a new mapping with a struct value.
+        let mapping = Mapping::<CurrentNetwork>::from_str(
+            r"
+mapping account:
+    key owner as address.public;
+    value info as info.public;",
+        )?;
+
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+
+        // Ensure the mapping cannot be added before the struct is defined.
+        assert!(program.add_mapping(mapping.clone()).is_err());
+
+        // Add the struct to the program.
+        program.add_struct(Struct::from_str(
+            r"
+struct info:
+    balance as u64;
+    nonce as u64;
+    frozen as boolean;",
+        )?)?;
+        // Add the mapping to the program.
+        program.add_mapping(mapping.clone())?;
+        // Ensure the retrieved mapping matches.
+        assert_eq!(mapping, program.get_mapping(&Identifier::from_str("account")?)?);
+
+        // Ensure a mapping cannot contain a record type.
+        let mapping = Mapping::<CurrentNetwork>::from_str(
+            r"
+mapping tokens:
+    key owner as address.public;
+    value token as token.record;",
+        )?;
+        assert!(program.add_mapping(mapping).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_struct() -> Result<()> {
         // Create a new struct.
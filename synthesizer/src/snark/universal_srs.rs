@@ -25,7 +25,12 @@ pub struct UniversalSRS<N: Network> {
 impl<N: Network> UniversalSRS<N> {
     /// Initializes the universal SRS.
     pub fn load() -> Result<Self> {
-        Ok(Self { srs: Arc::new(OnceCell::new()) })
+        match N::local_universal_srs() {
+            // If the network samples its universal SRS locally, use it.
+            Some(universal_srs) => Ok(Self { srs: Arc::new(OnceCell::with_value(universal_srs.clone())) }),
+            // Otherwise, the universal SRS is loaded on first use.
+            None => Ok(Self { srs: Arc::new(OnceCell::new()) }),
+        }
     }
 
     /// Returns the circuit proving and verifying key.
@@ -14,9 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod scan;
+pub use scan::*;
+
 use crate::{
     atomic_write_batch,
-    block::{Block, Header, Transactions},
+    block::{Block, Header, Transaction, Transactions, Transition},
     coinbase_puzzle::{CoinbaseSolution, PuzzleCommitment},
     cow_to_cloned,
     cow_to_copied,
@@ -25,6 +28,7 @@ use crate::{
         TransactionMemory,
         TransactionStorage,
         TransactionStore,
+        TransitionError,
         TransitionMemory,
         TransitionStorage,
         TransitionStore,
@@ -34,7 +38,7 @@ use crate::{
 use console::{
     account::Signature,
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockTree, HeaderLeaf, ProgramID, StatePath, TransactionLeaf},
     types::Field,
 };
 
@@ -343,47 +347,116 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => bail!("The block hash for commitment '{commitment}' is missing in storage"),
         };
 
-        // Retrieve the transition.
-        let transition = match self.transition_store().get_transition(&transition_id)? {
-            Some(transition) => transition,
-            None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
+        // Retrieve the block height.
+        let block_height = match self.get_block_height(&block_hash)? {
+            Some(block_height) => block_height,
+            None => bail!("The block height for commitment '{commitment}' is missing in storage"),
         };
-        // Retrieve the block.
-        let block = match self.get_block(&block_hash)? {
-            Some(block) => block,
-            None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
+        // Retrieve the previous block hash.
+        let previous_block_hash = match self.get_previous_block_hash(block_height)? {
+            Some(previous_block_hash) => previous_block_hash,
+            None => bail!("The previous block hash for commitment '{commitment}' is missing in storage"),
         };
 
         // Construct the global state root and block path.
         let global_state_root = *block_tree.root();
-        let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
+        let block_path = block_tree.prove(block_height as usize, &block_hash.to_bits_le())?;
 
         // Ensure the global state root exists in storage.
         if !self.reverse_state_root_map().contains_key(&global_state_root)? {
             bail!("The global state root '{global_state_root}' for commitment '{commitment}' is missing in storage");
         }
 
-        // Construct the transition path and transaction leaf.
-        let transition_leaf = transition.to_leaf(commitment, false)?;
-        let transition_path = transition.to_path(&transition_leaf)?;
+        // Note: The paths below are constructed from the stored IDs, rather than the transitions and
+        // transactions themselves, so that the state path remains available for pruned transitions.
 
-        // Construct the transactions path.
-        let transactions = block.transactions();
-        let transactions_path = match transactions.to_path(transaction_id) {
-            Ok(transactions_path) => transactions_path,
-            Err(_) => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
+        // Retrieve the transition inputs and outputs.
+        let inputs = self.transition_store().get_inputs(&transition_id)?;
+        let outputs = self.transition_store().get_outputs(&transition_id)?;
+        // Construct the transition leaf.
+        let transition_leaf = match outputs.iter().position(|output| output.id() == commitment) {
+            Some(index) => outputs[index].to_transition_leaf((inputs.len() + index) as u8),
+            None => bail!("The output for commitment '{commitment}' is not in transition '{transition_id}'"),
         };
+        // Construct the transition path.
+        let transition_path = Transition::function_tree(&inputs, &outputs)?
+            .prove(transition_leaf.index() as usize, &transition_leaf.to_bits_le())?;
 
-        // Construct the transaction path and transaction leaf.
-        let transaction = match transactions.get(&transaction_id) {
-            Some(transaction) => transaction,
+        // Retrieve the transaction IDs in the block.
+        let transaction_ids = match self.get_block_transaction_ids(&block_hash)? {
+            Some(transaction_ids) => transaction_ids,
+            None => bail!("The transactions for block '{block_hash}' are missing in storage"),
+        };
+        // Construct the transactions path.
+        let transactions_path = match transaction_ids.iter().position(|id| *id == transaction_id) {
+            Some(index) => Transactions::transactions_tree_from_ids(&transaction_ids)?
+                .prove(index, &transaction_id.to_bits_le())?,
             None => bail!("The transaction '{transaction_id}' for commitment '{commitment}' is not in the block"),
         };
-        let transaction_leaf = transaction.to_leaf(transition.id())?;
-        let transaction_path = transaction.to_path(&transaction_leaf)?;
 
+        // Retrieve the transition IDs and the optional additional fee ID, if the transaction is an execution.
+        let execution_ids = self.transaction_store().get_execution_transition_ids(&transaction_id)?;
+        // Construct the transaction leaf and the transaction tree.
+        let (transaction_leaf, transaction_tree) = match execution_ids {
+            // If the transaction is an execution, construct the execution tree.
+            Some((transition_ids, additional_fee_id)) => {
+                // Construct the transaction leaf.
+                let transaction_leaf = match transition_ids.iter().position(|id| *id == transition_id) {
+                    Some(index) => TransactionLeaf::new_execution(index as u16, *transition_id),
+                    None => match additional_fee_id {
+                        Some(additional_fee_id) if additional_fee_id == transition_id => {
+                            TransactionLeaf::new_execution(transition_ids.len() as u16, *transition_id)
+                        }
+                        _ => bail!("The transition '{transition_id}' is not in transaction '{transaction_id}'"),
+                    },
+                };
+                // Construct the execution tree.
+                let execution_tree = Transaction::execution_tree_from_ids(&transition_ids, additional_fee_id.as_ref())?;
+                (transaction_leaf, execution_tree)
+            }
+            // Otherwise, the transition is the fee of a deployment, so construct the deployment tree.
+            None => {
+                // Ensure the transition is the fee of the deployment.
+                match self.transaction_store().get_transition_ids(&transaction_id)? {
+                    Some(fee_ids) if fee_ids == [transition_id] => (),
+                    _ => bail!("The transition '{transition_id}' is not in transaction '{transaction_id}'"),
+                }
+                // Retrieve the deployment.
+                let deployment = match self.transaction_store().get_deployment(&transaction_id)? {
+                    Some(deployment) => deployment,
+                    None => bail!("The deployment '{transaction_id}' for commitment '{commitment}' is missing"),
+                };
+                // Compute the function hashes.
+                let function_hashes = deployment
+                    .program()
+                    .functions()
+                    .values()
+                    .map(|function| N::hash_bhp1024(&function.to_bytes_le()?.to_bits_le()))
+                    .collect::<Result<Vec<_>>>()?;
+                // Construct the transaction leaf.
+                let transaction_leaf = TransactionLeaf::new_deployment(
+                    function_hashes.len() as u16, // The index after the functions.
+                    *transition_id,
+                );
+                // Construct the deployment tree.
+                let deployment_tree = Transaction::deployment_tree_from_ids(
+                    &function_hashes,
+                    &transition_id,
+                    &deployment.to_owner_id()?,
+                )?;
+                (transaction_leaf, deployment_tree)
+            }
+        };
+        // Construct the transaction path.
+        let transaction_path =
+            transaction_tree.prove(transaction_leaf.index() as usize, &transaction_leaf.to_bits_le())?;
+
+        // Retrieve the block header.
+        let block_header = match self.get_block_header(&block_hash)? {
+            Some(block_header) => block_header,
+            None => bail!("The block header for commitment '{commitment}' is missing in storage"),
+        };
         // Construct the block header path.
-        let block_header = block.header();
         let header_root = block_header.to_root()?;
         let header_leaf = HeaderLeaf::<N>::new(1, block_header.transactions_root());
         let header_path = block_header.to_path(&header_leaf)?;
@@ -391,13 +464,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(StatePath::from(
             global_state_root.into(),
             block_path,
-            block.hash(),
-            block.previous_hash(),
+            block_hash,
+            previous_block_hash,
             header_root,
             header_path,
             header_leaf,
             transactions_path,
-            transaction.id(),
+            transaction_id,
             transaction_path,
             transaction_leaf,
             transition_path,
@@ -440,6 +513,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the transaction IDs, in order, for the given `block hash`.
+    fn get_block_transaction_ids(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<N::TransactionID>>> {
+        match self.transactions_map().get(block_hash)? {
+            Some(transaction_ids) => Ok(Some(cow_to_cloned!(transaction_ids))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the block transactions for the given `block hash`.
     fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
         // Retrieve the transaction IDs.
@@ -681,6 +762,34 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the block tree.
         let mut tree = self.tree.write();
+        // Insert the block, and prepare the updated Merkle tree.
+        let updated_tree = self.insert_into(&tree, block)?;
+        // Update the block tree.
+        *tree = updated_tree;
+        // Return success.
+        Ok(())
+    }
+
+    /// Stores the given block into storage, and returns the updated Merkle tree without setting it.
+    /// Note: This method is for inserting the block in a larger atomic batch, after which the caller
+    /// sets the returned tree with `BlockStore::set_tree`, only once the batch is committed.
+    pub(crate) fn insert_uncommitted(&self, block: &Block<N>) -> Result<BlockTree<N>> {
+        self.insert_into(&self.tree.read(), block)
+    }
+
+    /// Sets the block tree to the given tree, which is returned by `BlockStore::insert_uncommitted`.
+    pub(crate) fn set_tree(&self, updated_tree: BlockTree<N>) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Ensure the updated tree extends the block tree by one block.
+        ensure!(updated_tree.number_of_leaves() == tree.number_of_leaves() + 1, "The block tree is out of date");
+        // Update the block tree.
+        *tree = updated_tree;
+        Ok(())
+    }
+
+    /// Stores the given block into storage, and returns the given Merkle tree with the block hash appended.
+    fn insert_into(&self, tree: &BlockTree<N>, block: &Block<N>) -> Result<BlockTree<N>> {
         // Prepare an updated Merkle tree containing the new block hash.
         let updated_tree = tree.prepare_append(&[block.hash().to_bits_le()])?;
         // Ensure the next block height is correct.
@@ -689,10 +798,8 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         }
         // Insert the (state root, block height) pair.
         self.storage.insert((*updated_tree.root()).into(), block)?;
-        // Update the block tree.
-        *tree = updated_tree;
-        // Return success.
-        Ok(())
+        // Return the updated Merkle tree.
+        Ok(updated_tree)
     }
 
     /// Removes the last 'n' blocks from storage.
@@ -745,6 +852,64 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Prunes the proofs and ciphertexts of every transition in the block at the given `height`.
+    /// Note: The serial numbers, commitments, tags, and block tree are retained, so double-spend checks
+    /// and state paths remain available, but the block itself can no longer be retrieved from storage.
+    pub fn prune_transitions(&self, height: u32) -> Result<()> {
+        // Retrieve the transition IDs that are not yet pruned.
+        let mut transition_ids = Vec::new();
+        for transition_id in self.get_transition_ids(height)? {
+            if !self.transition_store().is_pruned(&transition_id)? {
+                transition_ids.push(transition_id);
+            }
+        }
+
+        atomic_write_batch!(self, {
+            // Prune the transitions.
+            for transition_id in &transition_ids {
+                self.transition_store().prune(transition_id)?;
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Ensures the transitions in the block at the given `height` are not pruned,
+    /// returning `TransitionError::Pruned` otherwise.
+    pub fn check_not_pruned(&self, height: u32) -> Result<()> {
+        for transition_id in self.get_transition_ids(height)? {
+            if self.transition_store().is_pruned(&transition_id)? {
+                return Err(TransitionError::Pruned { transition_id: transition_id.to_string() }.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the transition IDs, including the fee transition IDs, in the block at the given `height`.
+    fn get_transition_ids(&self, height: u32) -> Result<Vec<N::TransitionID>> {
+        // Retrieve the block hash.
+        let block_hash = match self.storage.get_block_hash(height)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing block hash for block {height}"),
+        };
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.storage.get_block_transaction_ids(&block_hash)? {
+            Some(transaction_ids) => transaction_ids,
+            None => bail!("Missing transactions for block {height}"),
+        };
+
+        // Retrieve the transition IDs.
+        let mut transition_ids = Vec::new();
+        for transaction_id in &transaction_ids {
+            match self.transaction_store().get_transition_ids(transaction_id)? {
+                Some(ids) => transition_ids.extend(ids),
+                None => bail!("Missing transaction '{transaction_id}' in block {height}"),
+            }
+        }
+        Ok(transition_ids)
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -799,6 +964,49 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     ) -> Result<Option<N::BlockHash>> {
         self.storage.find_block_hash_from_puzzle_commitment(puzzle_commitment)
     }
+
+    /// Returns the transition ID that spent the given `serial number`.
+    pub fn find_transition_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransitionID>> {
+        match self.transition_store().contains_serial_number(serial_number)? {
+            true => Ok(Some(self.transition_store().find_transition_id(serial_number)?)),
+            false => Ok(None),
+        }
+    }
+
+    /// Returns the transaction ID that spent the given `serial number`.
+    pub fn find_transaction_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransactionID>> {
+        match self.find_transition_id_from_serial_number(serial_number)? {
+            Some(transition_id) => self.transaction_store().find_transaction_id(&transition_id),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transition ID that produced the given `commitment`.
+    pub fn find_transition_id_from_commitment(&self, commitment: &Field<N>) -> Result<Option<N::TransitionID>> {
+        match self.transition_store().contains_commitment(commitment)? {
+            true => Ok(Some(self.transition_store().find_transition_id(commitment)?)),
+            false => Ok(None),
+        }
+    }
+
+    /// Returns the block hash that produced the given `commitment`.
+    pub fn find_block_hash_from_commitment(&self, commitment: &Field<N>) -> Result<Option<N::BlockHash>> {
+        // Find the transition that produced the commitment.
+        let transition_id = match self.find_transition_id_from_commitment(commitment)? {
+            Some(transition_id) => transition_id,
+            None => return Ok(None),
+        };
+        // Find the transaction that contains the transition.
+        match self.transaction_store().find_transaction_id(&transition_id)? {
+            Some(transaction_id) => self.storage.find_block_hash(&transaction_id),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transaction ID of the first deployment of the given `program ID`.
+    pub fn find_deployment_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
+        self.transaction_store().find_deployment_id_for_edition(program_id, N::EDITION)
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -837,6 +1045,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block_header(block_hash)
     }
 
+    /// Returns the transaction IDs, in order, for the given `block hash`.
+    pub fn get_block_transaction_ids(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<N::TransactionID>>> {
+        self.storage.get_block_transaction_ids(block_hash)
+    }
+
     /// Returns the block transactions for the given `block hash`.
     pub fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
         self.storage.get_block_transactions(block_hash)
@@ -981,4 +1194,86 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_find_from_serial_number_and_commitment() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let block_hash = block.hash();
+        assert!(block.commitments().count() > 0, "This test must be run with at least one commitment.");
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        for transaction in block.transactions().values() {
+            for transition in transaction.transitions() {
+                for serial_number in transition.serial_numbers() {
+                    // Find the transition and transaction IDs.
+                    let candidate = block_store.find_transition_id_from_serial_number(serial_number).unwrap();
+                    assert_eq!(Some(*transition.id()), candidate);
+                    let candidate = block_store.find_transaction_id_from_serial_number(serial_number).unwrap();
+                    assert_eq!(Some(transaction.id()), candidate);
+                }
+                for commitment in transition.commitments() {
+                    // Find the transition ID and block hash.
+                    let candidate = block_store.find_transition_id_from_commitment(commitment).unwrap();
+                    assert_eq!(Some(*transition.id()), candidate);
+                    let candidate = block_store.find_block_hash_from_commitment(commitment).unwrap();
+                    assert_eq!(Some(block_hash), candidate);
+                }
+            }
+        }
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        for serial_number in block.serial_numbers() {
+            // Ensure the transition ID is not found.
+            let candidate = block_store.find_transition_id_from_serial_number(serial_number).unwrap();
+            assert_eq!(None, candidate);
+        }
+        for commitment in block.commitments() {
+            // Ensure the transition ID is not found.
+            let candidate = block_store.find_transition_id_from_commitment(commitment).unwrap();
+            assert_eq!(None, candidate);
+        }
+    }
+
+    #[test]
+    fn test_find_deployment_id_from_program_id() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block, and a deployment transaction.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let program_id = match &transaction {
+            Transaction::Deploy(_, deployment, _) => *deployment.program_id(),
+            _ => panic!("Expected a deployment transaction"),
+        };
+
+        // Propose the next block with the deployment.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let block = vm.propose_next_block(&private_key, &[transaction.clone()], None, rng).unwrap();
+
+        // Initialize a new block store, and insert the genesis block.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&genesis).unwrap();
+
+        // Ensure the program is not found.
+        assert_eq!(None, block_store.find_deployment_id_from_program_id(&program_id).unwrap());
+
+        // Insert the block, and find the deployment.
+        block_store.insert(&block).unwrap();
+        assert_eq!(Some(transaction.id()), block_store.find_deployment_id_from_program_id(&program_id).unwrap());
+
+        // Remove the block, and ensure the program is not found.
+        block_store.remove_last_n(1).unwrap();
+        assert_eq!(None, block_store.find_deployment_id_from_program_id(&program_id).unwrap());
+    }
 }
@@ -0,0 +1,271 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::{
+    account::{PrivateKey, ViewKey},
+    program::{Plaintext, Record},
+};
+
+use core::ops::Range;
+
+/// A record owned by a view key, as found by a scan of the block store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnedRecord<N: Network> {
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The decrypted record.
+    record: Record<N, Plaintext<N>>,
+    /// The height of the block containing the record.
+    block_height: u32,
+    /// The ID of the transition that output the record.
+    transition_id: N::TransitionID,
+    /// Whether the record is spent, if a private key was given for the scan.
+    is_spent: Option<bool>,
+}
+
+impl<N: Network> OwnedRecord<N> {
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns the height of the block containing the record.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the ID of the transition that output the record.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns `true` if the record is spent, or `None` if no private key was given for the scan.
+    pub const fn is_spent(&self) -> Option<bool> {
+        self.is_spent
+    }
+}
+
+/// The records owned by a view key, as found by a scan of a range of blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordScan<N: Network> {
+    /// The owned records, in block order.
+    records: Vec<OwnedRecord<N>>,
+    /// The block height from which to resume the scan.
+    checkpoint: u32,
+}
+
+impl<N: Network> RecordScan<N> {
+    /// Returns the owned records, in block order.
+    pub fn records(&self) -> &[OwnedRecord<N>] {
+        &self.records
+    }
+
+    /// Returns the owned records, in block order.
+    pub fn into_records(self) -> Vec<OwnedRecord<N>> {
+        self.records
+    }
+
+    /// Returns the block height from which to resume the scan, i.e. one past the last scanned block.
+    pub const fn checkpoint(&self) -> u32 {
+        self.checkpoint
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Returns the records owned by the given view key, in the given range of block heights.
+    /// The end of the range is clamped to the latest block, so a scan may be resumed
+    /// with the range `checkpoint..u32::MAX`, using the checkpoint of the previous scan.
+    ///
+    /// If a private key is given, each record is marked as spent if its serial number is in storage.
+    pub fn scan_records(
+        &self,
+        view_key: &ViewKey<N>,
+        private_key: Option<&PrivateKey<N>>,
+        heights: Range<u32>,
+    ) -> Result<RecordScan<N>> {
+        // Ensure the private key corresponds to the view key.
+        if let Some(private_key) = private_key {
+            ensure!(ViewKey::try_from(private_key)? == *view_key, "The private key does not match the view key");
+        }
+
+        // Clamp the end of the range to the number of blocks.
+        let num_blocks = u32::try_from(self.tree.read().number_of_leaves())?;
+        let start = heights.start;
+        let end = heights.end.min(num_blocks).max(start);
+
+        // Compute the x-coordinate of the address once, to check the owner of each record.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+
+        // Scan the blocks in parallel.
+        let records = cfg_into_iter!(start..end)
+            .map(|height| self.scan_block_records(height, view_key, private_key, &address_x_coordinate))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Ok(RecordScan { records, checkpoint: end })
+    }
+
+    /// Returns the records owned by the given view key, in the block at the given height.
+    fn scan_block_records(
+        &self,
+        height: u32,
+        view_key: &ViewKey<N>,
+        private_key: Option<&PrivateKey<N>>,
+        address_x_coordinate: &Field<N>,
+    ) -> Result<Vec<OwnedRecord<N>>> {
+        // Ensure the block is not pruned, as the record ciphertexts of a pruned block are no longer in storage.
+        self.check_not_pruned(height)?;
+
+        // Retrieve the transactions in the block.
+        let block_hash = match self.get_block_hash(height)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing block hash for block {height}"),
+        };
+        let transactions = match self.get_block_transactions(&block_hash)? {
+            Some(transactions) => transactions,
+            None => bail!("Missing transactions for block {height}"),
+        };
+
+        let mut records = Vec::new();
+        for transition in transactions.transitions() {
+            for (commitment, record) in transition.records() {
+                // Skip the records that are not owned by the view key.
+                if !record.is_owner_with_address_x_coordinate(view_key, address_x_coordinate) {
+                    continue;
+                }
+                // Determine whether the record is spent, if the private key is given.
+                let is_spent = match private_key {
+                    Some(private_key) => {
+                        let serial_number = Record::<N, Plaintext<N>>::serial_number(*private_key, *commitment)?;
+                        Some(self.transition_store().contains_serial_number(&serial_number)?)
+                    }
+                    None => None,
+                };
+                records.push(OwnedRecord {
+                    commitment: *commitment,
+                    record: record.decrypt(view_key)?,
+                    block_height: height,
+                    transition_id: *transition.id(),
+                    is_spent,
+                });
+            }
+        }
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::Address;
+
+    type CurrentNetwork = crate::vm::test_helpers::CurrentNetwork;
+
+    #[test]
+    fn test_scan_records() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block and its caller.
+        let block = crate::vm::test_helpers::sample_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize a new block store, and insert the block.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Scan the blocks.
+        let scan = block_store.scan_records(&view_key, Some(&private_key), 0..u32::MAX).unwrap();
+        assert_eq!(scan.checkpoint(), 1);
+        let expected = block.transitions().flat_map(|transition| transition.records()).count();
+        assert!(expected > 0, "This test must be run with at least one record.");
+        assert_eq!(scan.records().len(), expected);
+        for owned in scan.records() {
+            assert_eq!(owned.block_height(), 0);
+            assert_eq!(owned.is_spent(), Some(false));
+            assert!(block.transitions().any(|transition| transition.id() == owned.transition_id()));
+            assert_eq!(**owned.record().owner(), address);
+        }
+
+        // Ensure the scan without a private key does not determine whether the records are spent.
+        let scan = block_store.scan_records(&view_key, None, 0..u32::MAX).unwrap();
+        assert!(scan.records().iter().all(|owned| owned.is_spent().is_none()));
+
+        // Ensure resuming from the checkpoint finds no new records.
+        let scan = block_store.scan_records(&view_key, None, scan.checkpoint()..u32::MAX).unwrap();
+        assert!(scan.records().is_empty());
+        assert_eq!(scan.checkpoint(), 1);
+
+        // Ensure another view key owns no records.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let scan = block_store.scan_records(&other_view_key, None, 0..u32::MAX).unwrap();
+        assert!(scan.records().is_empty());
+
+        // Ensure a mismatched private key fails.
+        assert!(block_store.scan_records(&other_view_key, Some(&private_key), 0..u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_scan_records_across_blocks() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM with the genesis block, and the caller.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Scan the genesis block.
+        let scan = vm.block_store().scan_records(&view_key, Some(&private_key), 0..u32::MAX).unwrap();
+        assert_eq!(scan.checkpoint(), 1);
+        assert!(scan.records().iter().all(|owned| owned.is_spent() == Some(false)));
+
+        // Add a block, whose deployment fee spends the first genesis record.
+        let credits = crate::vm::test_helpers::sample_genesis_credits(rng);
+        let program = crate::vm::test_helpers::sample_program();
+        let (block, change) =
+            crate::vm::test_helpers::sample_next_block_with_deployment(&vm, &private_key, &program, credits, rng);
+        vm.add_next_block(&block).unwrap();
+
+        // Resume the scan from the checkpoint, to find the change record in the new block.
+        let resumed =
+            vm.block_store().scan_records(&view_key, Some(&private_key), scan.checkpoint()..u32::MAX).unwrap();
+        assert_eq!(resumed.checkpoint(), 2);
+        assert!(resumed.records().iter().all(|owned| owned.block_height() == 1));
+        assert!(resumed.records().iter().any(|owned| owned.record() == &change));
+        assert!(resumed.records().iter().all(|owned| owned.is_spent() == Some(false)));
+
+        // Ensure the spent genesis record is marked as spent, and the remaining records are not.
+        let rescan = vm.block_store().scan_records(&view_key, Some(&private_key), 0..1).unwrap();
+        assert_eq!(rescan.checkpoint(), 1);
+        assert_eq!(rescan.records().len(), scan.records().len());
+        assert_eq!(rescan.records()[0].is_spent(), Some(true));
+        assert!(rescan.records()[1..].iter().all(|owned| owned.is_spent() == Some(false)));
+
+        // Ensure the full scan is the rescan followed by the resumed scan.
+        let full = vm.block_store().scan_records(&view_key, Some(&private_key), 0..u32::MAX).unwrap();
+        assert_eq!(full.checkpoint(), resumed.checkpoint());
+        assert_eq!(full.into_records(), [rescan.into_records(), resumed.into_records()].concat());
+    }
+}
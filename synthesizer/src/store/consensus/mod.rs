@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod snapshot;
+
 use crate::store::{
     BlockMemory,
     BlockStorage,
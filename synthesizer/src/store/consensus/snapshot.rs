@@ -0,0 +1,437 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{atomic_write_batch, block::Block};
+use console::{
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+
+use blake2::{Blake2s256, Digest};
+
+/// The magic bytes at the start of a snapshot.
+const SNAPSHOT_MAGIC: [u8; 8] = *b"snarkVM\0";
+/// The version of the snapshot format.
+const SNAPSHOT_VERSION: u16 = 1;
+/// The maximum number of mapping entries that are inserted in a single batch, when importing a snapshot.
+const SNAPSHOT_CHUNK_SIZE: u32 = 1024;
+
+impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
+    /// Writes a snapshot of the consensus store to the given writer.
+    ///
+    /// The snapshot is streamed as: the header (magic, version, network ID), the blocks in ascending order
+    /// (including their transactions and transitions), the program mappings and their entries, the state root,
+    /// the program checksum, and finally the BLAKE2s checksum of all preceding bytes.
+    /// Note: The finalize diffs are not exported, so an imported store cannot roll back below the snapshot.
+    /// A store with pruned blocks cannot be exported, and returns `TransitionError::Pruned` before anything is written.
+    pub fn export_snapshot<W: Write>(&self, writer: W) -> Result<()> {
+        // Retrieve the block heights, in ascending order.
+        let heights = self.block_store().heights().map(|height| *height).sorted().collect::<Vec<_>>();
+        // Ensure the block heights are contiguous from the genesis block.
+        ensure!(heights.iter().zip(0u32..).all(|(a, b)| *a == b), "Cannot export a snapshot with missing blocks");
+        // Ensure the blocks are not pruned.
+        for height in &heights {
+            self.block_store().check_not_pruned(*height)?;
+        }
+
+        let mut writer = SnapshotWriter::new(writer);
+
+        // Write the header.
+        writer.write_all(&SNAPSHOT_MAGIC)?;
+        SNAPSHOT_VERSION.write_le(&mut writer)?;
+        N::ID.write_le(&mut writer)?;
+
+        // Write the blocks.
+        u32::try_from(heights.len())?.write_le(&mut writer)?;
+        for height in heights {
+            let block_hash = self
+                .block_store()
+                .get_block_hash(height)?
+                .ok_or_else(|| anyhow!("Block hash for height {height} is missing in storage"))?;
+            let block = self
+                .block_store()
+                .get_block(&block_hash)?
+                .ok_or_else(|| anyhow!("Block {height} is missing in storage"))?;
+            block.write_le(&mut writer)?;
+        }
+
+        // Retrieve the program mappings.
+        let mut mappings = Vec::new();
+        for program_id in self.program_store().program_ids() {
+            if let Some(mapping_names) = self.program_store().get_mapping_names(&program_id)? {
+                mappings.extend(mapping_names.into_iter().map(|mapping_name| (*program_id, mapping_name)));
+            }
+        }
+        // Write the program mappings.
+        u32::try_from(mappings.len())?.write_le(&mut writer)?;
+        for (program_id, mapping_name) in mappings {
+            let entries = self
+                .program_store()
+                .get_mapping_entries(&program_id, &mapping_name)?
+                .ok_or_else(|| anyhow!("Mapping '{program_id}/{mapping_name}' is missing in storage"))?;
+            program_id.write_le(&mut writer)?;
+            mapping_name.write_le(&mut writer)?;
+            u32::try_from(entries.len())?.write_le(&mut writer)?;
+            for (key, value) in entries {
+                key.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+
+        // Write the state root and the program checksum.
+        self.block_store().current_state_root().write_le(&mut writer)?;
+        self.program_store().get_checksum()?.write_le(&mut writer)?;
+
+        // Write the checksum of the snapshot.
+        let (mut writer, checksum) = writer.finish();
+        writer.write_all(&checksum)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Reads a snapshot from the given reader into the consensus store, which must be empty.
+    ///
+    /// The snapshot is streamed into the store: the blocks are inserted as they are read, and the mapping entries
+    /// are inserted in batches of `SNAPSHOT_CHUNK_SIZE`. Once read, the snapshot is verified against what the
+    /// store computed: the state root (against both the snapshot and the given expected state root),
+    /// the program checksum (against both the snapshot and the given expected checksum), and the checksum
+    /// of the snapshot. A snapshot that fails to import is removed again, leaving the store empty.
+    pub fn import_snapshot<R: Read>(
+        &self,
+        reader: R,
+        expected_state_root: N::StateRoot,
+        expected_checksum: Field<N>,
+    ) -> Result<()> {
+        // Ensure the consensus store is empty.
+        ensure!(self.block_store().heights().next().is_none(), "Cannot import a snapshot into a non-empty store");
+        ensure!(self.program_store().program_ids().next().is_none(), "Cannot import a snapshot into a non-empty store");
+
+        // Import the snapshot, and remove anything that was written if it fails.
+        if let Err(error) = self.import_snapshot_unchecked(reader, expected_state_root, expected_checksum) {
+            if let Err(clear_error) = self.clear_snapshot() {
+                bail!("{error} (failed to clear the partially imported snapshot: {clear_error})")
+            }
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Reads a snapshot from the given reader into the consensus store, without clearing the store on failure.
+    fn import_snapshot_unchecked<R: Read>(
+        &self,
+        reader: R,
+        expected_state_root: N::StateRoot,
+        expected_checksum: Field<N>,
+    ) -> Result<()> {
+        let mut reader = SnapshotReader::new(reader);
+
+        // Read the header.
+        let mut magic = [0u8; SNAPSHOT_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        ensure!(magic == SNAPSHOT_MAGIC, "Invalid snapshot: the file is not a snapshot");
+        let version = u16::read_le(&mut reader)?;
+        ensure!(version == SNAPSHOT_VERSION, "Invalid snapshot: unsupported version {version}");
+        let network = u16::read_le(&mut reader)?;
+        ensure!(network == N::ID, "Invalid snapshot: expected network ID {}, found {network}", N::ID);
+
+        // Read and insert the blocks, in order.
+        let num_blocks = u32::read_le(&mut reader)?;
+        for height in 0..num_blocks {
+            let block = Block::<N>::read_le(&mut reader)?;
+            // Ensure the blocks are contiguous from the genesis block.
+            ensure!(block.height() == height, "Invalid snapshot: the blocks are not contiguous from the genesis block");
+            self.block_store().insert(&block)?;
+        }
+
+        // Read and insert the program mappings, in order.
+        let num_mappings = u32::read_le(&mut reader)?;
+        for _ in 0..num_mappings {
+            let program_id = ProgramID::<N>::read_le(&mut reader)?;
+            let mapping_name = Identifier::<N>::read_le(&mut reader)?;
+            self.program_store().initialize_mapping(&program_id, &mapping_name)?;
+
+            // Read and insert the entries, in chunks.
+            let mut num_entries = u32::read_le(&mut reader)?;
+            while num_entries > 0 {
+                let chunk_size = num_entries.min(SNAPSHOT_CHUNK_SIZE);
+                let entries = (0..chunk_size)
+                    .map(|_| Ok((Plaintext::<N>::read_le(&mut reader)?, Value::<N>::read_le(&mut reader)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                atomic_write_batch!(self.program_store(), {
+                    for (key, value) in entries {
+                        self.program_store().insert_key_value(&program_id, &mapping_name, key, value)?;
+                    }
+                    Ok(())
+                });
+                num_entries -= chunk_size;
+            }
+        }
+
+        // Read the state root and the program checksum.
+        let state_root = N::StateRoot::read_le(&mut reader)?;
+        let program_checksum = Field::<N>::read_le(&mut reader)?;
+
+        // Read the checksum of the snapshot.
+        let (mut reader, expected_snapshot_checksum) = reader.finish();
+        let mut snapshot_checksum = [0u8; 32];
+        reader.read_exact(&mut snapshot_checksum)?;
+        ensure!(snapshot_checksum == expected_snapshot_checksum, "Invalid snapshot: checksum mismatch");
+
+        // Ensure the state root of the imported blocks matches the snapshot, and the expected state root.
+        ensure!(
+            self.block_store().current_state_root() == state_root,
+            "Invalid snapshot: the state root of the blocks does not match"
+        );
+        ensure!(
+            state_root == expected_state_root,
+            "Invalid snapshot: expected state root {expected_state_root}, found {state_root}"
+        );
+        // Ensure the program checksum of the imported mappings matches the snapshot, and the expected checksum.
+        ensure!(
+            self.program_store().get_checksum()? == program_checksum,
+            "Invalid snapshot: the program checksum of the mappings does not match"
+        );
+        ensure!(
+            program_checksum == expected_checksum,
+            "Invalid snapshot: expected program checksum {expected_checksum}, found {program_checksum}"
+        );
+
+        Ok(())
+    }
+
+    /// Removes the blocks and program mappings of a partially imported snapshot from the consensus store.
+    fn clear_snapshot(&self) -> Result<()> {
+        // Remove the program mappings.
+        let program_ids = self.program_store().program_ids().map(|program_id| *program_id).collect::<Vec<_>>();
+        for program_id in program_ids {
+            self.program_store().remove_program(&program_id)?;
+        }
+        // Remove the blocks.
+        let num_blocks = u32::try_from(self.block_store().heights().count())?;
+        if num_blocks > 0 {
+            self.block_store().remove_last_n(num_blocks)?;
+        }
+        Ok(())
+    }
+}
+
+/// A writer that computes the checksum of the bytes written to it.
+struct SnapshotWriter<W: Write> {
+    /// The inner writer.
+    writer: W,
+    /// The checksum hasher.
+    hasher: Blake2s256,
+}
+
+impl<W: Write> SnapshotWriter<W> {
+    /// Initializes a new snapshot writer.
+    fn new(writer: W) -> Self {
+        Self { writer, hasher: Blake2s256::new() }
+    }
+
+    /// Returns the inner writer and the checksum of the bytes written.
+    fn finish(self) -> (W, [u8; 32]) {
+        let mut checksum = [0u8; 32];
+        checksum.copy_from_slice(&self.hasher.finalize());
+        (self.writer, checksum)
+    }
+}
+
+impl<W: Write> Write for SnapshotWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        let num_bytes = self.writer.write(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        Ok(num_bytes)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.writer.flush()
+    }
+}
+
+/// A reader that computes the checksum of the bytes read from it.
+struct SnapshotReader<R: Read> {
+    /// The inner reader.
+    reader: R,
+    /// The checksum hasher.
+    hasher: Blake2s256,
+}
+
+impl<R: Read> SnapshotReader<R> {
+    /// Initializes a new snapshot reader.
+    fn new(reader: R) -> Self {
+        Self { reader, hasher: Blake2s256::new() }
+    }
+
+    /// Returns the inner reader and the checksum of the bytes read.
+    fn finish(self) -> (R, [u8; 32]) {
+        let mut checksum = [0u8; 32];
+        checksum.copy_from_slice(&self.hasher.finalize());
+        (self.reader, checksum)
+    }
+}
+
+impl<R: Read> Read for SnapshotReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let num_bytes = self.reader.read(buf)?;
+        self.hasher.update(&buf[..num_bytes]);
+        Ok(num_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        store::ConsensusMemory,
+        vm::{
+            test_helpers::{
+                sample_deployment_transaction,
+                sample_genesis_private_key,
+                sample_program,
+                sample_vm_with_genesis_block,
+                CurrentNetwork,
+            },
+            VM,
+        },
+    };
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn test_export_import_snapshot() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block, and a block with a deployment.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let block = vm.propose_next_block(&private_key, &[sample_deployment_transaction(rng)], None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Populate a mapping.
+        let program_id = ProgramID::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        vm.program_store().initialize_mapping(&program_id, &mapping_name).unwrap();
+        for i in 0..4u64 {
+            let key = Plaintext::from_str(&format!("{i}field")).unwrap();
+            let value = Value::from_str(&format!("{}u64", i * 100)).unwrap();
+            vm.program_store().insert_key_value(&program_id, &mapping_name, key, value).unwrap();
+        }
+
+        // Export the snapshot.
+        let mut snapshot = Vec::new();
+        vm.export_snapshot(&mut snapshot).unwrap();
+
+        // Import the snapshot.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        let state_root = vm.block_store().current_state_root();
+        let checksum = vm.program_store().get_checksum().unwrap();
+        let imported_vm = VM::from_snapshot(store, &snapshot[..], state_root, checksum).unwrap();
+
+        // Ensure the imported VM matches.
+        assert_eq!(vm.block_store().current_state_root(), imported_vm.block_store().current_state_root());
+        assert_eq!(vm.program_store().get_checksum().unwrap(), imported_vm.program_store().get_checksum().unwrap());
+        assert_eq!(vm.latest_block().unwrap(), imported_vm.latest_block().unwrap());
+        assert!(imported_vm.contains_program(sample_program().id()));
+        assert_eq!(
+            vm.program_store().get_mapping_entries(&program_id, &mapping_name).unwrap(),
+            imported_vm.program_store().get_mapping_entries(&program_id, &mapping_name).unwrap()
+        );
+        for transaction_id in block.transaction_ids() {
+            assert!(imported_vm.transaction_store().contains_transaction_id(transaction_id).unwrap());
+        }
+
+        // Ensure the snapshot cannot be imported into a non-empty store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        store.import_snapshot(&snapshot[..], state_root, checksum).unwrap();
+        assert!(store.import_snapshot(&snapshot[..], state_root, checksum).is_err());
+    }
+
+    #[test]
+    fn test_import_invalid_snapshot() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block, and populate a mapping.
+        let vm = sample_vm_with_genesis_block(rng);
+        let program_id = ProgramID::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        vm.program_store().initialize_mapping(&program_id, &mapping_name).unwrap();
+        let key = Plaintext::from_str("0field").unwrap();
+        let value = Value::from_str("100u64").unwrap();
+        vm.program_store().insert_key_value(&program_id, &mapping_name, key, value).unwrap();
+        let state_root = vm.block_store().current_state_root();
+        let checksum = vm.program_store().get_checksum().unwrap();
+
+        // Export the snapshot.
+        let mut snapshot = Vec::new();
+        vm.export_snapshot(&mut snapshot).unwrap();
+
+        // Initializes a new consensus store.
+        let open = || ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        // Ensures the import of the given snapshot fails, and leaves the store empty.
+        let assert_import_fails =
+            |snapshot: &[u8], state_root: <CurrentNetwork as Network>::StateRoot, checksum: Field<CurrentNetwork>| {
+                let store = open();
+                assert!(store.import_snapshot(snapshot, state_root, checksum).is_err());
+                assert!(store.block_store().heights().next().is_none());
+                assert!(store.program_store().program_ids().next().is_none());
+                assert_eq!(store.block_store().current_state_root(), open().block_store().current_state_root());
+            };
+
+        // Ensure a snapshot with an unsupported version fails.
+        let mut invalid = snapshot.clone();
+        invalid[SNAPSHOT_MAGIC.len()] += 1;
+        assert_import_fails(&invalid, state_root, checksum);
+
+        // Ensure a snapshot with a tampered checksum fails.
+        let mut invalid = snapshot.clone();
+        *invalid.last_mut().unwrap() ^= 1;
+        assert_import_fails(&invalid, state_root, checksum);
+
+        // Ensure a truncated snapshot fails.
+        assert_import_fails(&snapshot[..snapshot.len() - 1], state_root, checksum);
+
+        // Ensure a snapshot for an unexpected state root fails.
+        assert_import_fails(&snapshot, Field::<CurrentNetwork>::zero().into(), checksum);
+
+        // Ensure a snapshot for an unexpected program checksum fails.
+        assert_import_fails(&snapshot, state_root, Field::<CurrentNetwork>::zero());
+
+        // Ensure a snapshot whose program checksum does not match its mappings fails, even with a valid checksum.
+        // Note: The program checksum is the last field before the checksum of the snapshot.
+        let checksum_offset = snapshot.len() - 32;
+        let field_size = Field::<CurrentNetwork>::zero().to_bytes_le().unwrap().len();
+        let mut invalid = snapshot[..checksum_offset - field_size].to_vec();
+        Field::<CurrentNetwork>::zero().write_le(&mut invalid).unwrap();
+        let mut hasher = Blake2s256::new();
+        hasher.update(&invalid);
+        invalid.extend_from_slice(&hasher.finalize());
+        assert_import_fails(&invalid, state_root, Field::<CurrentNetwork>::zero());
+
+        // Ensure a snapshot whose state root does not match its blocks fails, even with a valid checksum.
+        let mut invalid = snapshot[..checksum_offset - 2 * field_size].to_vec();
+        Field::<CurrentNetwork>::zero().write_le(&mut invalid).unwrap();
+        invalid.extend_from_slice(&snapshot[checksum_offset - field_size..checksum_offset]);
+        let mut hasher = Blake2s256::new();
+        hasher.update(&invalid);
+        invalid.extend_from_slice(&hasher.finalize());
+        assert_import_fails(&invalid, Field::<CurrentNetwork>::zero().into(), checksum);
+
+        // Ensure the snapshot succeeds.
+        assert!(open().import_snapshot(&snapshot[..], state_root, checksum).is_ok());
+    }
+}
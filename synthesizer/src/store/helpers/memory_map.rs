@@ -215,6 +215,13 @@ impl<
         if self.batch_in_progress.load(Ordering::SeqCst) { self.atomic_batch.lock().get(key).cloned() } else { None }
     }
 
+    ///
+    /// Returns the keys that are scheduled to be inserted or removed as part of an atomic batch.
+    ///
+    fn batched_keys(&self) -> Vec<K> {
+        self.atomic_batch.lock().keys().copied().collect()
+    }
+
     ///
     /// Returns an iterator visiting each key-value pair in the map.
     ///
@@ -16,6 +16,9 @@
 
 pub mod memory_map;
 
+#[cfg(feature = "rocks")]
+pub mod rocksdb;
+
 use console::network::prelude::*;
 
 use core::{borrow::Borrow, hash::Hash};
@@ -102,6 +105,11 @@ pub trait MapRead<
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized;
 
+    ///
+    /// Returns the keys that are scheduled to be inserted or removed as part of an atomic batch.
+    ///
+    fn batched_keys(&self) -> Vec<K>;
+
     ///
     /// Returns the value for the given key from the atomic batch first, if it exists,
     /// or return from the map, otherwise.
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::{
+    helpers::rocksdb::{BlockDB, ProgramDB, TransactionDB, TransitionDB},
+    BlockStore,
+    ConsensusStorage,
+    ProgramStore,
+};
+use console::network::prelude::*;
+
+/// A RocksDB consensus storage.
+#[derive(Clone)]
+pub struct ConsensusDB<N: Network> {
+    /// The program store.
+    program_store: ProgramStore<N, ProgramDB<N>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockDB<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
+    type ProgramStorage = ProgramDB<N>;
+    type BlockStorage = BlockDB<N>;
+    type TransactionStorage = TransactionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the consensus storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the program store.
+        let program_store = ProgramStore::<N, ProgramDB<N>>::open(dev)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockDB<N>>::open(dev)?;
+        // Return the consensus storage.
+        Ok(Self {
+            program_store,
+            block_store,
+        })
+    }
+
+    /// Returns the program store.
+    fn program_store(&self) -> &ProgramStore<N, Self::ProgramStorage> {
+        &self.program_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        store::{helpers::rocksdb::test_helpers::sample_test_db, ConsensusStore},
+        vm::VM,
+    };
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_reopen_vm() {
+        let rng = &mut TestRng::default();
+        let test_db = sample_test_db(CurrentNetwork::ID, rng);
+        let dev = test_db.dev();
+
+        // Sample the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        let (state_root, checksum) = {
+            // Initialize the VM.
+            let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+            let vm = VM::from(store).unwrap();
+            // Add the genesis block.
+            vm.add_next_block(&genesis).unwrap();
+            (vm.block_store().current_state_root(), vm.program_store().get_checksum().unwrap())
+        };
+
+        // Reopen the VM from the same directory.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+        let vm = VM::from(store).unwrap();
+
+        // Ensure the chain state was restored.
+        assert_eq!(vm.block_store().current_state_root(), state_root);
+        assert_eq!(vm.program_store().get_checksum().unwrap(), checksum);
+        assert_eq!(vm.block_store().get_block(&genesis.hash()).unwrap(), Some(genesis));
+    }
+
+    #[test]
+    fn test_reopen_vm_with_imports() {
+        use crate::{
+            program::Program,
+            vm::test_helpers::{sample_genesis_credits, sample_genesis_private_key, sample_next_block_with_deployment},
+        };
+
+        let rng = &mut TestRng::default();
+        let test_db = sample_test_db(CurrentNetwork::ID, rng);
+        let dev = test_db.dev();
+
+        // Initialize a program, and a program that imports it.
+        let parent = Program::<CurrentNetwork>::from_str(
+            r"
+program parent.aleo;
+
+function double:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+        let child = Program::<CurrentNetwork>::from_str(
+            r"
+import parent.aleo;
+
+program child.aleo;
+
+function quadruple:
+    input r0 as u64.public;
+    call parent.aleo/double r0 into r1;
+    call parent.aleo/double r1 into r2;
+    output r2 as u64.public;",
+        )
+        .unwrap();
+
+        {
+            // Initialize the VM.
+            let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+            let vm = VM::from(store).unwrap();
+            let private_key = sample_genesis_private_key(rng);
+            vm.add_next_block(&crate::vm::test_helpers::sample_genesis_block(rng)).unwrap();
+
+            // Deploy the programs, in separate blocks.
+            let credits = sample_genesis_credits(rng);
+            let (block, credits) = sample_next_block_with_deployment(&vm, &private_key, &parent, credits, rng);
+            vm.add_next_block(&block).unwrap();
+            let (block, _) = sample_next_block_with_deployment(&vm, &private_key, &child, credits, rng);
+            vm.add_next_block(&block).unwrap();
+        }
+
+        // Reopen the VM from the same directory, which loads the programs in the order they were deployed.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(dev).unwrap();
+        let vm = VM::from(store).unwrap();
+        assert!(vm.contains_program(parent.id()));
+        assert!(vm.contains_program(child.id()));
+    }
+}
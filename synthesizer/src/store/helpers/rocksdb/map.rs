@@ -0,0 +1,469 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{MapID, RocksDB};
+use crate::store::helpers::{Map, MapRead};
+use console::network::prelude::*;
+use indexmap::IndexMap;
+
+use core::{borrow::Borrow, hash::Hash, marker::PhantomData};
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A persistent map, backed by a prefixed key range in RocksDB.
+#[derive(Clone)]
+pub struct DataMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    /// The database.
+    database: RocksDB,
+    /// The key prefix of this map.
+    context: Vec<u8>,
+    /// The flag indicating if an atomic batch is in progress.
+    batch_in_progress: Arc<AtomicBool>,
+    /// The queued operations of the atomic batch.
+    atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> DataMap<K, V>
+{
+    /// Initializes a new map over the given database, for the given map ID.
+    pub fn new(database: RocksDB, map_id: MapID) -> Self {
+        Self {
+            database,
+            context: (map_id as u16).to_be_bytes().to_vec(),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+        }
+    }
+
+    /// Returns the database key for the given map key.
+    fn create_prefixed_key<Q: Serialize + ?Sized>(&self, key: &Q) -> Result<Vec<u8>> {
+        let mut raw_key = self.context.clone();
+        bincode::serialize_into(&mut raw_key, key)?;
+        Ok(raw_key)
+    }
+
+    /// Returns the raw key-value pairs of this map, in key order.
+    fn raw_iter(&self) -> RawIter<'_> {
+        RawIter {
+            iter: self.database.iterator(rocksdb::IteratorMode::From(&self.context, rocksdb::Direction::Forward)),
+            context: &self.context,
+        }
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Map<'a, K, V> for DataMap<K, V>
+{
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
+
+        match is_batch {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => {
+                self.atomic_batch.lock().insert(key, Some(value));
+            }
+            // Otherwise, write the key-value pair directly to the database.
+            false => {
+                self.database.put(self.create_prefixed_key(&key)?, bincode::serialize(&value)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
+
+        match is_batch {
+            // If a batch is in progress, add the key-None pair to the batch.
+            true => {
+                self.atomic_batch.lock().insert(*key, None);
+            }
+            // Otherwise, delete the key-value pair directly from the database.
+            false => {
+                self.database.delete(self.create_prefixed_key(key)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        // Set the atomic batch flag to `true`.
+        self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.lock().is_empty());
+        // Begin the atomic batch in the database.
+        self.database.start_atomic();
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress. This can be done to ensure
+    /// that lower-level operations don't start and finish their individual atomic write batch
+    /// if they are already part of a larger one.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.batch_in_progress.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        // Clear the atomic batch.
+        *self.atomic_batch.lock() = Default::default();
+        // Set the atomic batch flag to `false`, and abort the atomic batch in the database.
+        if self.batch_in_progress.swap(false, Ordering::SeqCst) {
+            self.database.abort_atomic();
+        }
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes.
+    /// Note: The queued writes of every map in the database are written in a single RocksDB write batch,
+    /// once the outermost map finishes its atomic operation.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        // Retrieve the atomic batch.
+        let operations = core::mem::take(&mut *self.atomic_batch.lock());
+        // Set the atomic batch flag to `false`.
+        if !self.batch_in_progress.swap(false, Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        // Serialize the queued operations.
+        let operations = operations
+            .into_iter()
+            .map(|(key, value)| {
+                Ok((self.create_prefixed_key(&key)?, value.map(|value| bincode::serialize(&value)).transpose()?))
+            })
+            .collect::<Result<Vec<_>>>();
+
+        match operations {
+            // Queue the operations in the database, which writes them once every map has finished.
+            Ok(operations) => self.database.finish_atomic(operations),
+            // Abort the atomic batch in the database, if the operations could not be serialized.
+            Err(error) => {
+                self.database.abort_atomic();
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MapRead<'a, K, V> for DataMap<K, V>
+{
+    type Iterator = Iter<'a, K, V>;
+    type Keys = Keys<'a, K>;
+    type Values = Values<'a, V>;
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.database.get_pinned(self.create_prefixed_key(key)?)?.is_some())
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        match self.database.get_pinned(self.create_prefixed_key(key)?)? {
+            Some(bytes) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
+            None => Ok(None),
+        }
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early if there is no atomic batch in progress.
+        if self.batch_in_progress.load(Ordering::SeqCst) { self.atomic_batch.lock().get(key).cloned() } else { None }
+    }
+
+    ///
+    /// Returns the keys that are scheduled to be inserted or removed as part of an atomic batch.
+    ///
+    fn batched_keys(&self) -> Vec<K> {
+        self.atomic_batch.lock().keys().copied().collect()
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter(&'a self) -> Self::Iterator {
+        Iter { iter: self.raw_iter(), _phantom: PhantomData }
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys(&'a self) -> Self::Keys {
+        Keys { iter: self.raw_iter(), _phantom: PhantomData }
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values(&'a self) -> Self::Values {
+        Values { iter: self.raw_iter(), _phantom: PhantomData }
+    }
+}
+
+/// An iterator over the raw (unprefixed key, value) pairs of a single map.
+pub struct RawIter<'a> {
+    /// The database iterator, positioned at the start of the map.
+    iter: rocksdb::DBIterator<'a>,
+    /// The key prefix of the map.
+    context: &'a [u8],
+}
+
+impl<'a> Iterator for RawIter<'a> {
+    type Item = (Box<[u8]>, Box<[u8]>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = match self.iter.next()? {
+            Ok(entry) => entry,
+            Err(error) => {
+                error!("RocksDB iterator error: {error}");
+                return None;
+            }
+        };
+        // Stop once the iterator leaves the key range of the map.
+        match key.starts_with(self.context) {
+            true => Some((key[self.context.len()..].into(), value)),
+            false => None,
+        }
+    }
+}
+
+/// Deserializes the given bytes, logging any failure.
+fn deserialize<T: for<'de> Deserialize<'de>>(bytes: &[u8]) -> Option<T> {
+    match bincode::deserialize(bytes) {
+        Ok(item) => Some(item),
+        Err(error) => {
+            error!("RocksDB deserialization error: {error}");
+            None
+        }
+    }
+}
+
+/// An iterator over the key-value pairs of a map.
+pub struct Iter<'a, K, V> {
+    iter: RawIter<'a>,
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator
+    for Iter<'a, K, V>
+{
+    type Item = (Cow<'a, K>, Cow<'a, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.iter.next()?;
+        Some((Cow::Owned(deserialize(&key)?), Cow::Owned(deserialize(&value)?)))
+    }
+}
+
+/// An iterator over the keys of a map.
+pub struct Keys<'a, K> {
+    iter: RawIter<'a>,
+    _phantom: PhantomData<K>,
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>> Iterator for Keys<'a, K> {
+    type Item = Cow<'a, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, _) = self.iter.next()?;
+        Some(Cow::Owned(deserialize(&key)?))
+    }
+}
+
+/// An iterator over the values of a map.
+pub struct Values<'a, V> {
+    iter: RawIter<'a>,
+    _phantom: PhantomData<V>,
+}
+
+impl<'a, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator for Values<'a, V> {
+    type Item = Cow<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, value) = self.iter.next()?;
+        Some(Cow::Owned(deserialize(&value)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::helpers::rocksdb::test_helpers::sample_test_db;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_insert_and_reopen() {
+        let rng = &mut TestRng::default();
+        let test_db = sample_test_db(CurrentNetwork::ID, rng);
+        let dev = test_db.dev();
+
+        {
+            // Initialize a map.
+            let map: DataMap<u32, String> = RocksDB::open_map(CurrentNetwork::ID, dev, MapID::BlockID).unwrap();
+            assert!(map.iter().next().is_none());
+
+            // Insert items into the map.
+            for i in 0..10 {
+                map.insert(i, i.to_string()).unwrap();
+            }
+            map.remove(&9).unwrap();
+        }
+
+        // Reopen the map, and ensure the items were persisted.
+        let map: DataMap<u32, String> = RocksDB::open_map(CurrentNetwork::ID, dev, MapID::BlockID).unwrap();
+        assert_eq!(map.keys().count(), 9);
+        for i in 0..9 {
+            assert_eq!(map.get(&i).unwrap(), Some(Cow::Owned(i.to_string())));
+        }
+        assert!(!map.contains_key(&9).unwrap());
+
+        // Ensure a different map over the same database is disjoint.
+        let other: DataMap<u32, String> = RocksDB::open_map(CurrentNetwork::ID, dev, MapID::BlockStateRoot).unwrap();
+        assert!(other.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_atomic_batch() {
+        let rng = &mut TestRng::default();
+        let test_db = sample_test_db(CurrentNetwork::ID, rng);
+        let dev = test_db.dev();
+
+        // Initialize a map.
+        let map: DataMap<u32, String> = RocksDB::open_map(CurrentNetwork::ID, dev, MapID::BlockID).unwrap();
+
+        // Start an atomic write batch.
+        map.start_atomic();
+        map.insert(0, "0".to_string()).unwrap();
+        // Check that the item is only in the batch.
+        assert!(map.get(&0).unwrap().is_none());
+        assert_eq!(map.get_batched(&0), Some(Some("0".to_string())));
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        // Abort the batch, and ensure nothing was written.
+        map.abort_atomic();
+        assert!(!map.is_atomic_in_progress());
+        assert!(map.get(&0).unwrap().is_none());
+
+        // Start another atomic write batch, and finish it.
+        map.start_atomic();
+        map.insert(1, "1".to_string()).unwrap();
+        map.insert(2, "2".to_string()).unwrap();
+        map.remove(&2).unwrap();
+        map.finish_atomic().unwrap();
+
+        // Ensure the batch was written.
+        assert_eq!(map.get(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+        assert!(map.get(&2).unwrap().is_none());
+        assert_eq!(map.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_shared_atomic_batch() {
+        let rng = &mut TestRng::default();
+        let test_db = sample_test_db(CurrentNetwork::ID, rng);
+        let dev = test_db.dev();
+
+        // Initialize two maps over the same database.
+        let map: DataMap<u32, String> = RocksDB::open_map(CurrentNetwork::ID, dev, MapID::BlockID).unwrap();
+        let other: DataMap<u32, String> = RocksDB::open_map(CurrentNetwork::ID, dev, MapID::BlockStateRoot).unwrap();
+
+        // Start an atomic write batch on both maps.
+        map.start_atomic();
+        other.start_atomic();
+        map.insert(0, "0".to_string()).unwrap();
+        other.insert(1, "1".to_string()).unwrap();
+
+        // Finish the first map, and ensure nothing is written until the second map finishes.
+        map.finish_atomic().unwrap();
+        assert!(!map.is_atomic_in_progress());
+        assert!(map.get(&0).unwrap().is_none());
+        other.finish_atomic().unwrap();
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert_eq!(other.get(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+
+        // Start another atomic write batch on both maps, and abort it after the first map finishes.
+        map.start_atomic();
+        other.start_atomic();
+        map.insert(2, "2".to_string()).unwrap();
+        map.finish_atomic().unwrap();
+        other.abort_atomic();
+
+        // Ensure nothing was written, and that a later batch is unaffected.
+        assert!(map.get(&2).unwrap().is_none());
+        map.start_atomic();
+        map.insert(3, "3".to_string()).unwrap();
+        map.finish_atomic().unwrap();
+        assert_eq!(map.keys().count(), 2);
+    }
+}
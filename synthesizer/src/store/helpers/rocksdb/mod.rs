@@ -0,0 +1,312 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod map;
+pub use map::*;
+
+mod block;
+pub use block::*;
+
+mod consensus;
+pub use consensus::*;
+
+mod program;
+pub use program::*;
+
+mod transaction;
+pub use transaction::*;
+
+mod transition;
+pub use transition::*;
+
+use console::network::prelude::*;
+
+use core::hash::Hash;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+        Weak,
+    },
+};
+
+/// The registry of open database handles, keyed by their `(network ID, dev)` pair.
+/// RocksDB takes an exclusive lock on its directory, so every storage opened
+/// for the same `(network ID, dev)` pair must share a single handle.
+static DATABASES: Lazy<Mutex<HashMap<(u16, Option<u16>), Weak<Database>>>> = Lazy::new(Default::default);
+
+/// The identifier of each map within the database. Every key is prefixed with the
+/// (big-endian) map ID, so that the maps can share a single RocksDB instance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MapID {
+    /* Block */
+    BlockStateRoot = 0,
+    BlockReverseStateRoot = 1,
+    BlockID = 2,
+    BlockReverseID = 3,
+    BlockHeader = 4,
+    BlockTransactions = 5,
+    BlockReverseTransactions = 6,
+    BlockCoinbaseSolution = 7,
+    BlockCoinbasePuzzleCommitment = 8,
+    BlockSignature = 9,
+    /* Deployment */
+    DeploymentID = 100,
+    DeploymentEdition = 101,
+    DeploymentReverseID = 102,
+    DeploymentProgram = 103,
+    DeploymentVerifyingKey = 104,
+    DeploymentCertificate = 105,
+    DeploymentFee = 106,
+    DeploymentOwner = 107,
+    /* Execution */
+    ExecutionID = 200,
+    ExecutionReverseID = 201,
+    ExecutionInclusion = 202,
+    ExecutionFee = 203,
+    /* Transaction */
+    TransactionID = 300,
+    /* Transition */
+    TransitionLocator = 400,
+    TransitionFinalize = 401,
+    TransitionProof = 402,
+    TransitionTPK = 403,
+    TransitionReverseTPK = 404,
+    TransitionTCM = 405,
+    TransitionReverseTCM = 406,
+    TransitionFee = 407,
+    /* Transition Input */
+    InputID = 500,
+    InputReverseID = 501,
+    InputConstant = 502,
+    InputPublic = 503,
+    InputPrivate = 504,
+    InputRecord = 505,
+    InputRecordTag = 506,
+    InputExternalRecord = 507,
+    /* Transition Output */
+    OutputID = 600,
+    OutputReverseID = 601,
+    OutputConstant = 602,
+    OutputPublic = 603,
+    OutputPrivate = 604,
+    OutputRecord = 605,
+    OutputRecordNonce = 606,
+    OutputExternalRecord = 607,
+    /* Program */
+    ProgramID = 700,
+    ProgramMappingID = 701,
+    ProgramKeyValueID = 702,
+    ProgramKey = 703,
+    ProgramValue = 704,
+    ProgramFinalizeDiff = 705,
+}
+
+/// An open RocksDB instance, shared by every map of the persistent storages.
+struct Database {
+    /// The underlying database.
+    database: rocksdb::DB,
+    /// The path to the database.
+    path: PathBuf,
+    /// The number of maps with an atomic batch in progress.
+    atomic_depth: AtomicUsize,
+    /// The queued operations of the maps that finished their atomic batch.
+    atomic_batch: Mutex<rocksdb::WriteBatch>,
+}
+
+/// A handle to the RocksDB instance that backs the persistent storages.
+#[derive(Clone)]
+pub struct RocksDB {
+    /// The underlying database.
+    database: Arc<Database>,
+    /// The network ID.
+    network_id: u16,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+impl Deref for RocksDB {
+    type Target = rocksdb::DB;
+
+    fn deref(&self) -> &Self::Target {
+        &self.database.database
+    }
+}
+
+impl RocksDB {
+    /// Opens the database for the given network ID and optional development ID, at its default path.
+    /// If the database is already open in this process, the existing handle is returned.
+    pub fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
+        // Acquire the lock on the registry.
+        let mut databases = DATABASES.lock();
+        // Reuse the database handle, if it is still alive.
+        if let Some(database) = databases.get(&(network_id, dev)).and_then(Weak::upgrade) {
+            return Ok(Self { database, network_id, dev });
+        }
+        // Open the database at its default path.
+        Self::open_unregistered(&mut databases, Self::path(network_id, dev), network_id, dev)
+    }
+
+    /// Opens the database for the given network ID and optional development ID, at the given path.
+    /// While the returned handle is alive, the storages opened for the same IDs share this database.
+    pub fn open_at<P: AsRef<Path>>(path: P, network_id: u16, dev: Option<u16>) -> Result<Self> {
+        // Acquire the lock on the registry.
+        let mut databases = DATABASES.lock();
+        // Reuse the database handle, if it is still alive and at the same path.
+        if let Some(database) = databases.get(&(network_id, dev)).and_then(Weak::upgrade) {
+            ensure!(
+                database.path == path.as_ref(),
+                "The database is already open at '{}', not '{}'",
+                database.path.display(),
+                path.as_ref().display()
+            );
+            return Ok(Self { database, network_id, dev });
+        }
+        // Open the database at the given path.
+        Self::open_unregistered(&mut databases, path.as_ref().to_path_buf(), network_id, dev)
+    }
+
+    /// Opens the database at the given path, and registers the handle for the given IDs.
+    fn open_unregistered(
+        databases: &mut HashMap<(u16, Option<u16>), Weak<Database>>,
+        path: PathBuf,
+        network_id: u16,
+        dev: Option<u16>,
+    ) -> Result<Self> {
+        // Prepare the database options.
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        options.set_compression_type(rocksdb::DBCompressionType::Lz4);
+
+        // Open the database.
+        let database = Arc::new(Database {
+            database: rocksdb::DB::open(&options, &path)?,
+            path,
+            atomic_depth: Default::default(),
+            atomic_batch: Default::default(),
+        });
+        // Register the database handle.
+        databases.insert((network_id, dev), Arc::downgrade(&database));
+
+        Ok(Self { database, network_id, dev })
+    }
+
+    /// Opens the map with the given map ID, for the given network ID and optional development ID.
+    pub fn open_map<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    >(
+        network_id: u16,
+        dev: Option<u16>,
+        map_id: MapID,
+    ) -> Result<DataMap<K, V>> {
+        Ok(DataMap::new(Self::open(network_id, dev)?, map_id))
+    }
+
+    /// Returns the network ID.
+    pub const fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    /// Returns the optional development ID.
+    pub const fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+
+    /// Returns the default path to the database, for the given network ID and optional development ID.
+    ///
+    /// The production ledger is stored in `~/.aleo/storage/ledger-{network_id}`, and each
+    /// development ledger is stored in `~/.aleo/storage/ledger-{network_id}-{dev}`.
+    pub fn path(network_id: u16, dev: Option<u16>) -> PathBuf {
+        let mut path = aleo_std::aleo_dir();
+        path.push("storage");
+        match dev {
+            Some(dev) => path.push(format!("ledger-{network_id}-{dev}")),
+            None => path.push(format!("ledger-{network_id}")),
+        }
+        path
+    }
+
+    /// Begins an atomic batch for one of the maps in the database.
+    fn start_atomic(&self) {
+        self.database.atomic_depth.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Aborts the atomic batch of one of the maps in the database.
+    /// Once no map has an atomic batch in progress, the queued operations are discarded.
+    fn abort_atomic(&self) {
+        if self.database.atomic_depth.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.database.atomic_batch.lock().clear();
+        }
+    }
+
+    /// Finishes the atomic batch of one of the maps in the database, by queueing its operations.
+    /// Once no map has an atomic batch in progress, the queued operations are written all at once.
+    fn finish_atomic(&self, operations: Vec<(Vec<u8>, Option<Vec<u8>>)>) -> Result<()> {
+        // Acquire the lock on the queued operations.
+        let mut batch = self.database.atomic_batch.lock();
+        // Queue the operations.
+        for (key, value) in operations {
+            match value {
+                Some(value) => batch.put(key, value),
+                None => batch.delete(key),
+            }
+        }
+        // If this is the outermost atomic batch, write the queued operations.
+        if self.database.atomic_depth.fetch_sub(1, Ordering::SeqCst) == 1 {
+            let batch = core::mem::take(&mut *batch);
+            if let Err(error) = self.database.database.write(batch) {
+                bail!("Failed to write the atomic batch to the database: {error}")
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    /// A database in a temporary directory, which is removed once the database is dropped.
+    pub(crate) struct TestDB {
+        /// The development ID of the database.
+        dev: Option<u16>,
+        /// The database handle, which the storages opened for the development ID share.
+        _database: RocksDB,
+        /// The temporary directory of the database.
+        _directory: tempfile::TempDir,
+    }
+
+    impl TestDB {
+        /// Returns the development ID of the database.
+        pub(crate) const fn dev(&self) -> Option<u16> {
+            self.dev
+        }
+    }
+
+    /// Opens a database in a temporary directory, for a development ID that is unique to the calling test.
+    pub(crate) fn sample_test_db(network_id: u16, rng: &mut TestRng) -> TestDB {
+        let dev = Some(rng.gen());
+        let directory = tempfile::tempdir().unwrap();
+        let database = RocksDB::open_at(directory.path(), network_id, dev).unwrap();
+        TestDB { dev, _database: database, _directory: directory }
+    }
+}
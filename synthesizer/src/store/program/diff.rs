@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The prior entries of the program storage that are changed while finalizing a block.
+/// Reverting a finalize diff restores the program storage to its state before the block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FinalizeDiff<N: Network> {
+    /// The prior entries of the program ID map.
+    program_ids: Vec<(ProgramID<N>, Option<IndexSet<Identifier<N>>>)>,
+    /// The prior entries of the mapping ID map.
+    mapping_ids: Vec<((ProgramID<N>, Identifier<N>), Option<Field<N>>)>,
+    /// The prior entries of the key-value ID map.
+    key_value_ids: Vec<(Field<N>, Option<IndexMap<Field<N>, Field<N>>>)>,
+    /// The prior entries of the key map.
+    keys: Vec<(Field<N>, Option<Plaintext<N>>)>,
+    /// The prior entries of the value map.
+    values: Vec<(Field<N>, Option<Value<N>>)>,
+}
+
+impl<N: Network> FinalizeDiff<N> {
+    /// Initializes a new finalize diff from the prior entries of each map.
+    pub const fn new(
+        program_ids: Vec<(ProgramID<N>, Option<IndexSet<Identifier<N>>>)>,
+        mapping_ids: Vec<((ProgramID<N>, Identifier<N>), Option<Field<N>>)>,
+        key_value_ids: Vec<(Field<N>, Option<IndexMap<Field<N>, Field<N>>>)>,
+        keys: Vec<(Field<N>, Option<Plaintext<N>>)>,
+        values: Vec<(Field<N>, Option<Value<N>>)>,
+    ) -> Self {
+        Self { program_ids, mapping_ids, key_value_ids, keys, values }
+    }
+
+    /// Returns the prior entries of the program ID map.
+    pub fn program_ids(&self) -> &[(ProgramID<N>, Option<IndexSet<Identifier<N>>>)] {
+        &self.program_ids
+    }
+
+    /// Returns the prior entries of the mapping ID map.
+    pub fn mapping_ids(&self) -> &[((ProgramID<N>, Identifier<N>), Option<Field<N>>)] {
+        &self.mapping_ids
+    }
+
+    /// Returns the prior entries of the key-value ID map.
+    pub fn key_value_ids(&self) -> &[(Field<N>, Option<IndexMap<Field<N>, Field<N>>>)] {
+        &self.key_value_ids
+    }
+
+    /// Returns the prior entries of the key map.
+    pub fn keys(&self) -> &[(Field<N>, Option<Plaintext<N>>)] {
+        &self.keys
+    }
+
+    /// Returns the prior entries of the value map.
+    pub fn values(&self) -> &[(Field<N>, Option<Value<N>>)] {
+        &self.values
+    }
+
+    /// Returns `true` if the finalize diff does not change the program storage.
+    pub fn is_empty(&self) -> bool {
+        self.program_ids.is_empty()
+            && self.mapping_ids.is_empty()
+            && self.key_value_ids.is_empty()
+            && self.keys.is_empty()
+            && self.values.is_empty()
+    }
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod diff;
+pub use diff::*;
+
 use crate::{
     atomic_write_batch,
     cow_to_cloned,
@@ -29,7 +32,30 @@ use console::{
 use anyhow::Result;
 use core::marker::PhantomData;
 use indexmap::{IndexMap, IndexSet};
-use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// Returns the committed entries of the given map, for each key in its atomic batch.
+macro_rules! committed_entries {
+    ($map:expr) => {
+        $map.batched_keys()
+            .into_iter()
+            .map(|key| Ok((key, $map.get(&key)?.map(|value| cow_to_cloned!(value)))))
+            .collect::<Result<Vec<_>>>()
+    };
+}
+
+/// Restores the given entries into the given map, removing the keys without a value.
+macro_rules! restore_entries {
+    ($map:expr, $entries:expr) => {
+        for (key, value) in $entries {
+            match value {
+                Some(value) => $map.insert(*key, value.clone())?,
+                None => $map.remove(key)?,
+            }
+        }
+    };
+}
 
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
@@ -53,6 +79,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
     /// The mapping of `key ID` to `value`.
     type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `block height` to `finalize diff`.
+    type FinalizeDiffMap: for<'a> Map<'a, u32, FinalizeDiff<N>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -67,6 +95,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_map(&self) -> &Self::KeyMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the finalize diff map.
+    fn finalize_diff_map(&self) -> &Self::FinalizeDiffMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -78,6 +108,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
         self.value_map().start_atomic();
+        self.finalize_diff_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -87,6 +118,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.finalize_diff_map().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
@@ -96,6 +128,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.finalize_diff_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -104,7 +137,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.value_map().finish_atomic()?;
+        self.finalize_diff_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -380,6 +414,62 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the finalize diff for the given `block height`, from the changes in the current atomic batch.
+    /// Note: This method must be called in the atomic batch that finalizes the block, before it is finished.
+    fn insert_finalize_diff(&self, block_height: u32) -> Result<()> {
+        // Ensure an atomic batch is in progress.
+        if !self.is_atomic_in_progress() {
+            bail!("Illegal operation: no atomic batch is in progress - cannot insert the finalize diff.")
+        }
+        // Retrieve the committed entries, for each key that is changed in the atomic batch.
+        let diff = FinalizeDiff::new(
+            committed_entries!(self.program_id_map())?,
+            committed_entries!(self.mapping_id_map())?,
+            committed_entries!(self.key_value_id_map())?,
+            committed_entries!(self.key_map())?,
+            committed_entries!(self.value_map())?,
+        );
+        // Store the finalize diff, unless the block did not change the program storage.
+        match diff.is_empty() {
+            true => Ok(()),
+            false => self.finalize_diff_map().insert(block_height, diff),
+        }
+    }
+
+    /// Removes the finalize diff for the given `block height`, once the block can no longer be reverted.
+    fn remove_finalize_diff(&self, block_height: u32) -> Result<()> {
+        self.finalize_diff_map().remove(&block_height)
+    }
+
+    /// Reverts the finalize diff for the given `block height`, restoring the program storage
+    /// to its state before the block was finalized. Note: The blocks after the given `block height`
+    /// must be reverted first, in descending order.
+    ///
+    /// If there is no finalize diff for the given `block height`, the block did not change the program storage,
+    /// and this method does nothing. Note: The caller must ensure the finalize diff was not pruned.
+    fn revert_finalize_diff(&self, block_height: u32) -> Result<()> {
+        // Retrieve the finalize diff.
+        let diff = match self.finalize_diff_map().get(&block_height)? {
+            Some(diff) => cow_to_cloned!(diff),
+            None => return Ok(()),
+        };
+
+        atomic_write_batch!(self, {
+            // Restore the prior entries.
+            restore_entries!(self.program_id_map(), diff.program_ids());
+            restore_entries!(self.mapping_id_map(), diff.mapping_ids());
+            restore_entries!(self.key_value_id_map(), diff.key_value_ids());
+            restore_entries!(self.key_map(), diff.keys());
+            restore_entries!(self.value_map(), diff.values());
+            // Remove the finalize diff.
+            self.finalize_diff_map().remove(&block_height)?;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key(program_id)
@@ -477,6 +567,41 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the `(key, value)` entries for the given `program ID` and `mapping name`, in insertion order.
+    fn get_mapping_entries(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<Vec<(Plaintext<N>, Value<N>)>>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Retrieve the key-value IDs for the mapping ID.
+        let key_value_ids = match self.key_value_id_map().get_speculative(&mapping_id)? {
+            Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+            None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot get entries."),
+        };
+        // Retrieve the key and value for each key ID.
+        key_value_ids
+            .keys()
+            .map(|key_id| match (self.get_key(key_id)?, self.get_value_from_key_id(key_id)?) {
+                (Some(key), Some(value)) => Ok((key, value)),
+                _ => bail!("Missing key-value for key ID '{key_id}' in storage"),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Some)
+    }
+
+    /// Returns the finalize diff for the given `block height`.
+    fn get_finalize_diff(&self, block_height: u32) -> Result<Option<FinalizeDiff<N>>> {
+        match self.finalize_diff_map().get(&block_height)? {
+            Some(diff) => Ok(Some(cow_to_cloned!(diff))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the checksum.
     fn get_checksum(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -513,6 +638,8 @@ pub struct ProgramMemory<N: Network> {
     key_map: MemoryMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: MemoryMap<Field<N>, Value<N>>,
+    /// The finalize diff map.
+    finalize_diff_map: MemoryMap<u32, FinalizeDiff<N>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -524,6 +651,7 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
     type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type FinalizeDiffMap = MemoryMap<u32, FinalizeDiff<N>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -533,6 +661,7 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
+            finalize_diff_map: MemoryMap::default(),
             dev,
         })
     }
@@ -562,6 +691,11 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
         &self.value_map
     }
 
+    /// Returns the finalize diff map.
+    fn finalize_diff_map(&self) -> &Self::FinalizeDiffMap {
+        &self.finalize_diff_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -640,6 +774,22 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.remove_program(program_id)
     }
 
+    /// Stores the finalize diff for the given `block height`, from the changes in the current atomic batch.
+    pub fn insert_finalize_diff(&self, block_height: u32) -> Result<()> {
+        self.storage.insert_finalize_diff(block_height)
+    }
+
+    /// Removes the finalize diff for the given `block height`, once the block can no longer be reverted.
+    pub fn remove_finalize_diff(&self, block_height: u32) -> Result<()> {
+        self.storage.remove_finalize_diff(block_height)
+    }
+
+    /// Reverts the finalize diff for the given `block height`, restoring the program storage
+    /// to its state before the block was finalized.
+    pub fn revert_finalize_diff(&self, block_height: u32) -> Result<()> {
+        self.storage.revert_finalize_diff(block_height)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -703,6 +853,30 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     ) -> Result<Option<Value<N>>> {
         self.storage.get_value(program_id, mapping_name, key)
     }
+
+    /// Returns the `(key, value)` entries for the given `program ID` and `mapping name`, in insertion order.
+    pub fn get_mapping_entries(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<Vec<(Plaintext<N>, Value<N>)>>> {
+        self.storage.get_mapping_entries(program_id, mapping_name)
+    }
+
+    /// Returns the finalize diff for the given `block height`.
+    pub fn get_finalize_diff(&self, block_height: u32) -> Result<Option<FinalizeDiff<N>>> {
+        self.storage.get_finalize_diff(block_height)
+    }
+
+    /// Returns the checksum.
+    pub fn get_checksum(&self) -> Result<Field<N>> {
+        self.storage.get_checksum()
+    }
+
+    /// Returns an iterator over the program IDs, for all programs with mappings in `self`.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.program_id_map().keys()
+    }
 }
 
 #[cfg(test)]
@@ -1175,4 +1349,122 @@ mod tests {
         check_initialize_insert_remove(&program_store, program_id, mapping_name);
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
+
+    #[test]
+    fn test_revert_finalize_diff() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare a key and values.
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let value = Value::from_str("100u64").unwrap();
+        let incremented_value = Value::from_str("150u64").unwrap();
+        let decremented_value = Value::from_str("25u64").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramMemory::open(None).unwrap();
+        // Retrieve the checksum before any blocks are finalized.
+        let checksum_0 = program_store.get_checksum().unwrap();
+
+        // Finalize block 1, which initializes the mapping and inserts a (key, value).
+        program_store.start_atomic();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        program_store.insert_finalize_diff(1).unwrap();
+        program_store.finish_atomic().unwrap();
+        let checksum_1 = program_store.get_checksum().unwrap();
+
+        // Finalize block 2, which increments the value.
+        program_store.start_atomic();
+        program_store.update_key_value(&program_id, &mapping_name, key.clone(), incremented_value).unwrap();
+        program_store.insert_finalize_diff(2).unwrap();
+        program_store.finish_atomic().unwrap();
+        let checksum_2 = program_store.get_checksum().unwrap();
+
+        // Finalize block 3, which decrements the value.
+        program_store.start_atomic();
+        program_store.update_key_value(&program_id, &mapping_name, key.clone(), decremented_value).unwrap();
+        program_store.insert_finalize_diff(3).unwrap();
+        program_store.finish_atomic().unwrap();
+        let checksum_3 = program_store.get_checksum().unwrap();
+
+        // Ensure the checksums are distinct.
+        assert_ne!(checksum_0, checksum_1);
+        assert_ne!(checksum_1, checksum_2);
+        assert_ne!(checksum_2, checksum_3);
+        // Ensure the finalize diffs exist.
+        assert!(!program_store.get_finalize_diff(1).unwrap().unwrap().is_empty());
+        assert!(!program_store.get_finalize_diff(2).unwrap().unwrap().is_empty());
+        assert!(!program_store.get_finalize_diff(3).unwrap().unwrap().is_empty());
+
+        // Revert block 3.
+        program_store.revert_finalize_diff(3).unwrap();
+        assert_eq!(checksum_2, program_store.get_checksum().unwrap());
+        assert!(program_store.get_finalize_diff(3).unwrap().is_none());
+        // Ensure reverting block 3 again is a no-op.
+        program_store.revert_finalize_diff(3).unwrap();
+        assert_eq!(checksum_2, program_store.get_checksum().unwrap());
+
+        // Revert blocks 2 and 1 in a single atomic batch.
+        program_store.start_atomic();
+        program_store.revert_finalize_diff(2).unwrap();
+        program_store.revert_finalize_diff(1).unwrap();
+        program_store.finish_atomic().unwrap();
+        assert_eq!(checksum_0, program_store.get_checksum().unwrap());
+
+        // Ensure the program storage is restored.
+        assert!(!program_store.contains_program(&program_id).unwrap());
+        assert!(!program_store.contains_mapping(&program_id, &mapping_name).unwrap());
+        assert!(program_store.get_value(&program_id, &mapping_name, &key).unwrap().is_none());
+        assert!(program_store.get_finalize_diff(1).unwrap().is_none());
+        assert!(program_store.get_finalize_diff(2).unwrap().is_none());
+
+        // Ensure program storage still behaves correctly after the above operations.
+        check_initialize_insert_remove(&program_store, program_id, mapping_name);
+    }
+
+    #[test]
+    fn test_empty_and_removed_finalize_diffs() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramMemory::open(None).unwrap();
+
+        // Finalize block 1, which initializes the mapping.
+        program_store.start_atomic();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        program_store.insert_finalize_diff(1).unwrap();
+        program_store.finish_atomic().unwrap();
+        let checksum = program_store.get_checksum().unwrap();
+
+        // Finalize block 2, which does not change the program storage.
+        program_store.start_atomic();
+        program_store.insert_finalize_diff(2).unwrap();
+        program_store.finish_atomic().unwrap();
+
+        // Ensure the empty finalize diff is not stored, and reverting it is a no-op.
+        assert!(program_store.get_finalize_diff(2).unwrap().is_none());
+        program_store.revert_finalize_diff(2).unwrap();
+        assert_eq!(checksum, program_store.get_checksum().unwrap());
+
+        // Remove the finalize diff of block 1.
+        program_store.start_atomic();
+        program_store.remove_finalize_diff(1).unwrap();
+        program_store.finish_atomic().unwrap();
+        assert!(program_store.get_finalize_diff(1).unwrap().is_none());
+        // Ensure the program storage is unchanged.
+        assert_eq!(checksum, program_store.get_checksum().unwrap());
+        assert!(program_store.contains_mapping(&program_id, &mapping_name).unwrap());
+    }
+
+    #[test]
+    fn test_insert_finalize_diff_requires_atomic_batch() {
+        // Initialize a new program store.
+        let program_store = ProgramMemory::<CurrentNetwork>::open(None).unwrap();
+        // Ensure the finalize diff cannot be inserted outside of an atomic batch.
+        assert!(program_store.insert_finalize_diff(1).is_err());
+        assert!(program_store.get_finalize_diff(1).unwrap().is_none());
+    }
 }
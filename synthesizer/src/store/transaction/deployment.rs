@@ -30,6 +30,7 @@ use crate::{
     },
 };
 use console::{
+    account::{Address, Signature},
     network::prelude::*,
     program::{Identifier, ProgramID},
 };
@@ -53,6 +54,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     type VerifyingKeyMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     /// The mapping of `(program ID, function name, edition)` to `certificate`.
     type CertificateMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    /// The mapping of `(program ID, edition)` to `(owner address, owner signature)`.
+    type OwnerMap: for<'a> Map<'a, (ProgramID<N>, u16), (Address<N>, Signature<N>)>;
     /// The mapping of `transaction ID` to `(fee transition ID, global state root, inclusion proof)`.
     type FeeMap: for<'a> Map<'a, N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
     /// The transition storage.
@@ -73,6 +76,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn verifying_key_map(&self) -> &Self::VerifyingKeyMap;
     /// Returns the certificate map.
     fn certificate_map(&self) -> &Self::CertificateMap;
+    /// Returns the owner map.
+    fn owner_map(&self) -> &Self::OwnerMap;
     /// Returns the fee map.
     fn fee_map(&self) -> &Self::FeeMap;
     /// Returns the transition storage.
@@ -91,6 +96,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().start_atomic();
         self.verifying_key_map().start_atomic();
         self.certificate_map().start_atomic();
+        self.owner_map().start_atomic();
         self.fee_map().start_atomic();
         self.transition_store().start_atomic();
     }
@@ -103,6 +109,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             || self.program_map().is_atomic_in_progress()
             || self.verifying_key_map().is_atomic_in_progress()
             || self.certificate_map().is_atomic_in_progress()
+            || self.owner_map().is_atomic_in_progress()
             || self.fee_map().is_atomic_in_progress()
             || self.transition_store().is_atomic_in_progress()
     }
@@ -115,6 +122,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().abort_atomic();
         self.verifying_key_map().abort_atomic();
         self.certificate_map().abort_atomic();
+        self.owner_map().abort_atomic();
         self.fee_map().abort_atomic();
         self.transition_store().abort_atomic();
     }
@@ -127,6 +135,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().finish_atomic()?;
         self.verifying_key_map().finish_atomic()?;
         self.certificate_map().finish_atomic()?;
+        self.owner_map().finish_atomic()?;
         self.fee_map().finish_atomic()?;
         self.transition_store().finish_atomic()
     }
@@ -159,11 +168,16 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             }
         }
 
+        // Determine whether the edition is the latest edition of the program.
+        let is_latest = self.get_edition(&program_id)?.map_or(true, |latest| edition > latest);
+
         atomic_write_batch!(self, {
             // Store the program ID.
             self.id_map().insert(*transaction_id, program_id)?;
-            // Store the edition.
-            self.edition_map().insert(program_id, edition)?;
+            // Store the edition, if it is the latest edition of the program.
+            if is_latest {
+                self.edition_map().insert(program_id, edition)?;
+            }
 
             // Store the reverse program ID.
             self.reverse_id_map().insert((program_id, edition), *transaction_id)?;
@@ -177,6 +191,10 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
                 // Store the certificate.
                 self.certificate_map().insert((program_id, *function_name, edition), certificate.clone())?;
             }
+            // Store the owner, if one is present.
+            if let Some(owner) = deployment.owner() {
+                self.owner_map().insert((program_id, edition), *owner)?;
+            }
 
             // Store the fee.
             self.fee_map().insert(
@@ -200,7 +218,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.find_edition(&program_id, transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
@@ -209,6 +227,16 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             Some(program) => cow_to_cloned!(program),
             None => bail!("Failed to locate program '{program_id}' for transaction '{transaction_id}'"),
         };
+        // Retrieve the latest remaining edition, which becomes the latest edition once this edition is removed.
+        let mut previous_edition = None;
+        for candidate in (0..edition).rev() {
+            if self.reverse_id_map().contains_key(&(program_id, candidate))? {
+                previous_edition = Some(candidate);
+                break;
+            }
+        }
+        // Determine whether the removed edition is the latest edition.
+        let is_latest = self.get_edition(&program_id)? == Some(edition);
         // Retrieve the fee transition ID.
         let (transition_id, _, _) = match self.fee_map().get(transaction_id)? {
             Some(fee_id) => cow_to_cloned!(fee_id),
@@ -218,8 +246,13 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_write_batch!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Remove the edition, restoring the prior edition (if one exists).
+            if is_latest {
+                match previous_edition {
+                    Some(previous_edition) => self.edition_map().insert(program_id, previous_edition)?,
+                    None => self.edition_map().remove(&program_id)?,
+                }
+            }
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -233,6 +266,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
                 // Remove the certificate.
                 self.certificate_map().remove(&(program_id, *function_name, edition))?;
             }
+            // Remove the owner.
+            self.owner_map().remove(&(program_id, edition))?;
 
             // Remove the fee.
             self.fee_map().remove(transaction_id)?;
@@ -276,6 +311,24 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition of the given `program ID` that was deployed in the given `transaction ID`.
+    fn find_edition(&self, program_id: &ProgramID<N>, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        // Retrieve the latest edition.
+        let latest = match self.get_edition(program_id)? {
+            Some(edition) => edition,
+            None => return Ok(None),
+        };
+        // Search the editions, starting from the latest.
+        for edition in (0..=latest).rev() {
+            if let Some(candidate) = self.reverse_id_map().get(&(*program_id, edition))? {
+                if cow_to_copied!(candidate) == *transaction_id {
+                    return Ok(Some(edition));
+                }
+            }
+        }
+        Ok(None)
+    }
+
     /// Returns the program for the given `program ID`.
     fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         // Retrieve the edition.
@@ -334,7 +387,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.find_edition(&program_id, transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
@@ -363,8 +416,30 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             verifying_keys.insert(*function_name, (verifying_key, certificate));
         }
 
+        // Retrieve the owner.
+        let owner = self.owner_map().get(&(program_id, edition))?.map(|owner| cow_to_cloned!(owner));
+
         // Return the deployment.
-        Ok(Some(Deployment::new(edition, program, verifying_keys)?))
+        Ok(Some(Deployment::new(edition, program, verifying_keys)?.with_owner(owner)))
+    }
+
+    /// Returns the fee transition ID for the given `transaction ID`.
+    fn get_fee_transition_id(&self, transaction_id: &N::TransactionID) -> Result<Option<N::TransitionID>> {
+        match self.fee_map().get(transaction_id)? {
+            Some(fee) => Ok(Some(fee.0)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transaction ID that contains the given fee `transition ID`.
+    /// Note: The fee map is keyed by transaction ID, so this method searches every deployment.
+    fn find_transaction_id_from_fee(&self, transition_id: &N::TransitionID) -> Result<Option<N::TransactionID>> {
+        for (transaction_id, fee) in self.fee_map().iter() {
+            if fee.0 == *transition_id {
+                return Ok(Some(cow_to_copied!(transaction_id)));
+            }
+        }
+        Ok(None)
     }
 
     /// Returns the fee for the given `transaction ID`.
@@ -420,6 +495,8 @@ pub struct DeploymentMemory<N: Network> {
     verifying_key_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
     /// The certificate map.
     certificate_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    /// The owner map.
+    owner_map: MemoryMap<(ProgramID<N>, u16), (Address<N>, Signature<N>)>,
     /// The fee map.
     fee_map: MemoryMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>,
     /// The transition store.
@@ -434,6 +511,7 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
     type ProgramMap = MemoryMap<(ProgramID<N>, u16), Program<N>>;
     type VerifyingKeyMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
     type CertificateMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type OwnerMap = MemoryMap<(ProgramID<N>, u16), (Address<N>, Signature<N>)>;
     type FeeMap = MemoryMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -446,6 +524,7 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
             program_map: MemoryMap::default(),
             verifying_key_map: MemoryMap::default(),
             certificate_map: MemoryMap::default(),
+            owner_map: MemoryMap::default(),
             fee_map: MemoryMap::default(),
             transition_store,
         })
@@ -481,6 +560,11 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
         &self.certificate_map
     }
 
+    /// Returns the owner map.
+    fn owner_map(&self) -> &Self::OwnerMap {
+        &self.owner_map
+    }
+
     /// Returns the fee map.
     fn fee_map(&self) -> &Self::FeeMap {
         &self.fee_map
@@ -599,6 +683,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
+
+    /// Returns the fee transition ID for the given `transaction ID`.
+    pub fn get_fee_transition_id(&self, transaction_id: &N::TransactionID) -> Result<Option<N::TransitionID>> {
+        self.storage.get_fee_transition_id(transaction_id)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
@@ -606,6 +695,23 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn find_transaction_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
         self.storage.find_transaction_id(program_id)
     }
+
+    /// Returns the transaction ID that contains the given fee `transition ID`.
+    pub fn find_transaction_id_from_fee(&self, transition_id: &N::TransitionID) -> Result<Option<N::TransactionID>> {
+        self.storage.find_transaction_id_from_fee(transition_id)
+    }
+
+    /// Returns the transaction ID that deployed the given `edition` of the `program ID`.
+    pub fn find_transaction_id_for_edition(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: u16,
+    ) -> Result<Option<N::TransactionID>> {
+        match self.storage.reverse_id_map().get(&(*program_id, edition))? {
+            Some(transaction_id) => Ok(Some(cow_to_copied!(transaction_id))),
+            None => Ok(None),
+        }
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
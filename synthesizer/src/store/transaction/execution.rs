@@ -207,6 +207,17 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the transition IDs and the optional additional fee ID for the given `transaction ID`.
+    fn get_transition_ids(
+        &self,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<(Vec<N::TransitionID>, Option<N::TransitionID>)>> {
+        match self.id_map().get(transaction_id)? {
+            Some(ids) => Ok(Some(cow_to_cloned!(ids))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the execution for the given `transaction ID`.
     fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
         // Retrieve the transition IDs and optional additional fee ID.
@@ -429,6 +440,14 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     pub fn get_execution(&self, transaction_id: &N::TransactionID) -> Result<Option<Execution<N>>> {
         self.storage.get_execution(transaction_id)
     }
+
+    /// Returns the transition IDs and the optional additional fee ID for the given `transaction ID`.
+    pub fn get_transition_ids(
+        &self,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<(Vec<N::TransitionID>, Option<N::TransitionID>)>> {
+        self.storage.get_transition_ids(transaction_id)
+    }
 }
 
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
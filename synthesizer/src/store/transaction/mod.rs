@@ -161,7 +161,12 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id(&self, transition_id: &N::TransitionID) -> Result<Option<N::TransactionID>> {
-        self.execution_store().find_transaction_id(transition_id)
+        // Start by checking the executions (which is the more likely case).
+        if let Some(transaction_id) = self.execution_store().find_transaction_id(transition_id)? {
+            return Ok(Some(transaction_id));
+        }
+        // Then check the deployment fees.
+        self.deployment_store().find_transaction_id_from_fee(transition_id)
     }
 
     /// Returns the transaction ID that contains the given `program ID`.
@@ -332,6 +337,37 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         }
     }
 
+    /// Returns the transition IDs, including the fee transition ID, for the given `transaction ID`.
+    pub fn get_transition_ids(&self, transaction_id: &N::TransactionID) -> Result<Option<Vec<N::TransitionID>>> {
+        // Retrieve the transaction type.
+        let transaction_type = match self.transaction_ids.get(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => return Ok(None),
+        };
+        // Retrieve the transition IDs.
+        match transaction_type {
+            // Return the fee transition ID.
+            TransactionType::Deploy => {
+                Ok(self.storage.deployment_store().get_fee_transition_id(transaction_id)?.map(|id| vec![id]))
+            }
+            // Return the transition IDs and the optional additional fee ID.
+            TransactionType::Execute => match self.storage.execution_store().get_transition_ids(transaction_id)? {
+                Some((transition_ids, additional_fee_id)) => {
+                    Ok(Some(transition_ids.into_iter().chain(additional_fee_id).collect()))
+                }
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Returns the execution transition IDs and the optional additional fee ID for the given `transaction ID`.
+    pub fn get_execution_transition_ids(
+        &self,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<(Vec<N::TransitionID>, Option<N::TransitionID>)>> {
+        self.storage.execution_store().get_transition_ids(transaction_id)
+    }
+
     /// Returns the edition for the given `transaction ID`.
     pub fn get_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
         // Retrieve the transaction type.
@@ -390,9 +426,18 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().find_transaction_id(program_id)
     }
 
+    /// Returns the transaction ID that deployed the given `edition` of the `program ID`.
+    pub fn find_deployment_id_for_edition(
+        &self,
+        program_id: &ProgramID<N>,
+        edition: u16,
+    ) -> Result<Option<N::TransactionID>> {
+        self.storage.deployment_store().find_transaction_id_for_edition(program_id, edition)
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     pub fn find_transaction_id(&self, transition_id: &N::TransitionID) -> Result<Option<N::TransactionID>> {
-        self.storage.execution_store().find_transaction_id(transition_id)
+        self.storage.find_transaction_id(transition_id)
     }
 }
 
@@ -186,6 +186,26 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Prunes the ciphertexts of the inputs for the given `transition ID`.
+    /// Note: The input IDs, serial numbers, and tags are retained.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the input IDs.
+        let input_ids = self.get_ids(transition_id)?;
+
+        atomic_write_batch!(self, {
+            // Prune the private inputs.
+            for input_id in &input_ids {
+                if self.private_map().contains_key(input_id)? {
+                    self.private_map().insert(*input_id, None)?;
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `input ID`.
     fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get(input_id)? {
@@ -408,6 +428,11 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the ciphertexts of the inputs for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
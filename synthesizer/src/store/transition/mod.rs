@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod error;
+pub use error::*;
+
 mod input;
 pub use input::*;
 
@@ -21,6 +24,7 @@ mod output;
 pub use output::*;
 
 use crate::{
+    atomic_write_batch,
     block::{Input, Output, Transition},
     cow_to_cloned,
     cow_to_copied,
@@ -256,6 +260,28 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Prunes the proof and the ciphertexts of the given `transition ID`, retaining the serial numbers,
+    /// tags, commitments, and record nonces for double-spend checks and state paths.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Ensure the transition exists.
+        if !self.locator_map().contains_key(transition_id)? {
+            bail!("Illegal operation: transition '{transition_id}' does not exist - cannot prune.")
+        }
+
+        atomic_write_batch!(self, {
+            // Prune the input ciphertexts.
+            self.input_store().prune(transition_id)?;
+            // Prune the output ciphertexts.
+            self.output_store().prune(transition_id)?;
+            // Remove the proof.
+            self.proof_map().remove(transition_id)?;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transition for the given `transition ID`.
     fn get(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         // Retrieve the program ID and function name.
@@ -298,6 +324,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
                     false => bail!("Mismatch in the transition ID '{transition_id}'"),
                 }
             }
+            // Note: A transition without a proof is pruned.
+            (Some(_), None, Some(_), Some(_), Some(_)) => {
+                Err(TransitionError::Pruned { transition_id: transition_id.to_string() }.into())
+            }
             _ => bail!("Transition '{transition_id}' is missing some data (possible corruption)"),
         }
     }
@@ -483,6 +513,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the proof and the ciphertexts of the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -591,6 +626,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.locator.contains_key(transition_id)
     }
 
+    /// Returns `true` if the given transition ID exists, and its proof and ciphertexts are pruned.
+    pub fn is_pruned(&self, transition_id: &N::TransitionID) -> Result<bool> {
+        Ok(self.locator.contains_key(transition_id)? && !self.proof.contains_key(transition_id)?)
+    }
+
     /* Input */
 
     /// Returns `true` if the given input ID exists.
@@ -879,4 +919,59 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_prune() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transitions.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let transitions = transaction.transitions().cloned().collect::<Vec<_>>();
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+
+        for transition in transitions.iter() {
+            // Retrieve the transition ID.
+            let transition_id = *transition.id();
+
+            // Insert the transition.
+            transition_store.insert(transition).unwrap();
+            assert!(!transition_store.is_pruned(&transition_id).unwrap());
+
+            // Prune the transition.
+            transition_store.prune(&transition_id).unwrap();
+            assert!(transition_store.is_pruned(&transition_id).unwrap());
+
+            // Ensure the transition returns a pruned error.
+            let error = transition_store.get_transition(&transition_id).unwrap_err();
+            assert!(matches!(error.downcast_ref::<TransitionError>(), Some(TransitionError::Pruned { .. })));
+
+            // Ensure the serial numbers, tags, and commitments are retained.
+            for serial_number in transition.serial_numbers() {
+                assert!(transition_store.contains_serial_number(serial_number).unwrap());
+            }
+            for tag in transition.tags() {
+                assert!(transition_store.contains_tag(tag).unwrap());
+            }
+            for commitment in transition.commitments() {
+                assert!(transition_store.contains_commitment(commitment).unwrap());
+                assert_eq!(None, transition_store.get_record(commitment).unwrap());
+            }
+            // Ensure the record nonces are retained.
+            for nonce in transition.nonces() {
+                assert!(transition_store.contains_nonce(nonce).unwrap());
+            }
+
+            // Ensure the pruned transition can be removed, along with its record nonces.
+            transition_store.remove(&transition_id).unwrap();
+            assert!(!transition_store.contains_transition_id(&transition_id).unwrap());
+            for nonce in transition.nonces() {
+                assert!(!transition_store.contains_nonce(nonce).unwrap());
+            }
+        }
+
+        // Ensure a missing transition cannot be pruned.
+        assert!(transition_store.prune(transitions[0].id()).is_err());
+    }
 }
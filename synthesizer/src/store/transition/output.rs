@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    atomic_write_batch,
     block::Output,
     store::helpers::{memory_map::MemoryMap, Map, MapRead},
 };
@@ -197,8 +198,20 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
 
                 // If the output is a record, remove the record nonce.
                 if let Some(record) = self.record_map().get(&output_id)? {
-                    if let Some(record) = &record.1 {
-                        self.record_nonce_map().remove(record.nonce())?;
+                    match &record.1 {
+                        Some(record) => self.record_nonce_map().remove(record.nonce())?,
+                        // Note: If the record ciphertext is pruned, its nonce is found by its commitment.
+                        None => {
+                            let nonces = self
+                                .record_nonce_map()
+                                .iter()
+                                .filter(|(_, commitment)| **commitment == output_id)
+                                .map(|(nonce, _)| nonce.into_owned())
+                                .collect::<Vec<_>>();
+                            for nonce in nonces {
+                                self.record_nonce_map().remove(&nonce)?;
+                            }
+                        }
                     }
                 }
 
@@ -227,6 +240,34 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Prunes the ciphertexts of the outputs for the given `transition ID`.
+    /// Note: The output IDs, commitments, checksums, and record nonces are retained.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids = self.get_ids(transition_id)?;
+
+        atomic_write_batch!(self, {
+            for output_id in &output_ids {
+                // Prune the private output.
+                if self.private_map().contains_key(output_id)? {
+                    self.private_map().insert(*output_id, None)?;
+                }
+
+                // Prune the record ciphertext, retaining the checksum.
+                // Note: The record nonce is retained, so that `contains_nonce` is unaffected by pruning.
+                if let Some(record) = self.record_map().get(output_id)? {
+                    if let (checksum, Some(_)) = &*record {
+                        self.record_map().insert(*output_id, (*checksum, None))?;
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get(output_id)? {
@@ -450,6 +491,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the ciphertexts of the outputs for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -17,35 +17,83 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Finalizes the given transactions into the VM.
+    /// Finalizes the given transactions into the VM, in the block at the given height.
     /// This method assumes the given transactions **are valid**.
+    ///
+    /// If the transactions contain a deployment, they are finalized into a copy of the process, which replaces
+    /// the process only after the batch is committed, so a failed batch leaves the process unchanged.
+    /// Otherwise, the process is not modified, and the transactions are finalized with it in place.
     #[inline]
-    pub fn finalize(&self, transactions: &Transactions<N>) -> Result<()> {
+    pub fn finalize(&self, transactions: &Transactions<N>, block_height: u32) -> Result<()> {
         let timer = timer!("VM::finalize");
+
+        // Acquire the write lock on the process.
+        let mut process_lock = self.process.write();
+        // Initialize the updated copy of the process.
+        let candidate = RwLock::new(None);
+
         atomic_write_batch!(self, {
-            // Acquire the write lock on the process.
-            let mut process = self.process.write();
-
-            for transaction in transactions.values() {
-                // Finalize the transaction.
-                match transaction {
-                    Transaction::Deploy(_, deployment, _) => {
-                        process.finalize_deployment(self.program_store(), deployment)?;
-                        lap!(timer, "Finalize deployment");
-                    }
-                    Transaction::Execute(_, execution, _) => {
-                        process.finalize_execution(self.program_store(), execution)?;
-                        lap!(timer, "Finalize execution");
-                    }
-                }
-            }
+            *candidate.write() = self.finalize_transactions(&process_lock, transactions, block_height)?;
             Ok(())
         });
 
+        // Replace the process, now that the batch is committed.
+        if let Some(candidate) = candidate.into_inner() {
+            *process_lock = candidate;
+        }
+
         finish!(timer);
 
         Ok(())
     }
+
+    /// Finalizes the given transactions with the given process, in the block at the given height.
+    /// This method must be called in an atomic batch, and assumes the given transactions **are valid**.
+    ///
+    /// If the transactions contain a deployment, returns the updated copy of the process,
+    /// which must replace the process once the batch is committed. Otherwise, returns `None`.
+    pub(super) fn finalize_transactions(
+        &self,
+        process: &Process<N>,
+        transactions: &Transactions<N>,
+        block_height: u32,
+    ) -> Result<Option<Process<N>>> {
+        let timer = timer!("VM::finalize_transactions");
+
+        // Initialize a copy of the process, if the transactions contain a deployment.
+        let has_deployment = transactions.values().any(|transaction| matches!(transaction, Transaction::Deploy(..)));
+        let mut candidate = match has_deployment {
+            true => Some(process.clone()),
+            false => None,
+        };
+
+        for transaction in transactions.values() {
+            // Finalize the transaction.
+            match transaction {
+                Transaction::Deploy(_, deployment, _) => {
+                    let process = candidate.as_mut().ok_or_else(|| anyhow!("Missing the process copy"))?;
+                    process.finalize_deployment(self.program_store(), deployment)?;
+                    lap!(timer, "Finalize deployment");
+                }
+                Transaction::Execute(_, execution, _) => {
+                    let process = candidate.as_ref().unwrap_or(process);
+                    process.finalize_execution(self.program_store(), execution, block_height)?;
+                    lap!(timer, "Finalize execution");
+                }
+            }
+        }
+        // Record the finalize diff, to revert the block if it is rolled back.
+        self.program_store().insert_finalize_diff(block_height)?;
+        // Prune the finalize diff of the block that falls below the rollback depth.
+        if let Some(prune_height) = block_height.checked_sub(MAX_ROLLBACK_DEPTH) {
+            self.program_store().remove_finalize_diff(prune_height)?;
+        }
+        lap!(timer, "Record the finalize diff");
+
+        finish!(timer);
+
+        Ok(candidate)
+    }
 }
 
 #[cfg(test)]
@@ -63,9 +111,9 @@ mod tests {
         let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
 
         // Finalize the transaction.
-        vm.finalize(&Transactions::from(&[deployment_transaction.clone()])).unwrap();
+        vm.finalize(&Transactions::from(&[deployment_transaction.clone()]), 1).unwrap();
 
         // Ensure the VM can't redeploy the same transaction.
-        assert!(vm.finalize(&Transactions::from(&[deployment_transaction])).is_err());
+        assert!(vm.finalize(&Transactions::from(&[deployment_transaction]), 1).is_err());
     }
 }
@@ -16,16 +16,23 @@
 
 mod helpers;
 
+mod pruning;
+pub use pruning::*;
+
 mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod propose;
+mod rollback;
+pub use rollback::*;
 mod verify;
 
 use crate::{
     atomic_write_batch,
-    block::{Block, Transaction, Transactions, Transition},
+    block::{Block, BlockError, Transaction, Transactions, Transition},
     cast_ref,
+    coinbase_puzzle::{CoinbasePuzzle, EpochChallenge},
     process,
     process::{Authorization, Deployment, Execution, Fee, Inclusion, InclusionAssignment, Process, Query},
     program::Program,
@@ -35,9 +42,11 @@ use console::{
     account::PrivateKey,
     network::prelude::*,
     program::{Identifier, Plaintext, ProgramID, Record, Response, Value},
+    types::Field,
 };
 
 use aleo_std::prelude::{finish, lap, timer};
+use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
@@ -47,29 +56,92 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     process: Arc<RwLock<Process<N>>>,
     /// The VM store.
     store: ConsensusStore<N, C>,
+    /// The coinbase puzzle, loaded on first use.
+    coinbase_puzzle: Arc<OnceCell<CoinbasePuzzle<N>>>,
+    /// The pruning policy.
+    pruning_policy: Arc<RwLock<PruningPolicy>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Initializes the VM from storage.
     #[inline]
     pub fn from(store: ConsensusStore<N, C>) -> Result<Self> {
+        // Initialize a new process, with the deployments from the store.
+        let process = Self::load_process(store.block_store())?;
+        // Return the new VM.
+        Ok(Self {
+            process: Arc::new(RwLock::new(process)),
+            store,
+            coinbase_puzzle: Default::default(),
+            pruning_policy: Default::default(),
+        })
+    }
+
+    /// Initializes the VM from the snapshot in the given reader, which is imported into the given empty store.
+    /// The snapshot must be for the given expected state root and program checksum.
+    #[inline]
+    pub fn from_snapshot<R: Read>(
+        store: ConsensusStore<N, C>,
+        reader: R,
+        expected_state_root: N::StateRoot,
+        expected_checksum: Field<N>,
+    ) -> Result<Self> {
+        // Import the snapshot into the store.
+        store.import_snapshot(reader, expected_state_root, expected_checksum)?;
+        // Initialize the VM, which loads the deployed programs.
+        Self::from(store)
+    }
+
+    /// Writes a snapshot of the VM state to the given writer.
+    #[inline]
+    pub fn export_snapshot<W: Write>(&self, writer: W) -> Result<()> {
+        self.store.export_snapshot(writer)
+    }
+
+    /// Initializes a new process, and loads the deployments from the given block store.
+    /// Note: The deployments are loaded in the order they were added to the chain (by block height,
+    /// then by index in the block), so that every program is loaded after the programs it imports.
+    fn load_process(block_store: &BlockStore<N, C::BlockStorage>) -> Result<Process<N>> {
+        // Retrieve the latest block height.
+        match block_store.heights().max() {
+            Some(latest_height) => Self::load_process_up_to(block_store, *latest_height),
+            None => Process::load(),
+        }
+    }
+
+    /// Initializes a new process, and loads the deployments from the blocks in the given block store,
+    /// up to (and including) the block at the given height.
+    fn load_process_up_to(block_store: &BlockStore<N, C::BlockStorage>, height: u32) -> Result<Process<N>> {
         // Initialize a new process.
         let mut process = Process::load()?;
 
-        // Retrieve the transaction store.
-        let transaction_store = store.transaction_store();
         // Load the deployments from the store.
-        for transaction_id in transaction_store.deployment_ids() {
-            // Retrieve the deployment.
-            match transaction_store.get_deployment(&transaction_id)? {
-                // Load the deployment.
-                Some(deployment) => process.load_deployment(&deployment)?,
-                None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+        for height in 0..=height {
+            // Retrieve the transaction IDs of the block.
+            let block_hash = match block_store.get_block_hash(height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Block hash for height {height} is not found in storage."),
             };
+            let transaction_ids = match block_store.get_block_transaction_ids(&block_hash)? {
+                Some(transaction_ids) => transaction_ids,
+                None => bail!("Transactions for block {height} are not found in storage."),
+            };
+
+            for transaction_id in transaction_ids {
+                // Skip the transaction, if it is not a deployment.
+                if block_store.transaction_store().get_program_id(&transaction_id)?.is_none() {
+                    continue;
+                }
+                // Retrieve the deployment.
+                match block_store.transaction_store().get_deployment(&transaction_id)? {
+                    // Load the deployment.
+                    Some(deployment) => process.load_deployment(&deployment)?,
+                    None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+                };
+            }
         }
 
-        // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(process)
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -78,20 +150,162 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.process.read().contains_program(program_id)
     }
 
-    /// Adds the given block into the VM.
+    /// Adds the given block into the VM, if it is a valid successor of the current chain tip.
+    /// The block is inserted and its transactions are finalized in a single atomic batch,
+    /// so a block that fails to finalize leaves the VM unchanged.
     #[inline]
-    pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
-        // First, insert the block.
-        self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(block.transactions()) {
-            Ok(_) => Ok(()),
-            Err(error) => {
-                // Rollback the block.
-                self.block_store().remove_last_n(1)?;
-                // Return the error.
-                Err(error)
+    pub fn add_next_block(&self, block: &Block<N>) -> Result<(), BlockError> {
+        // Ensure the block is valid.
+        self.check_next_block(block)?;
+
+        // Acquire the write lock on the process.
+        let mut process_lock = self.process.write();
+        // Initialize the updated block tree and copy of the process.
+        let updates = RwLock::new(None);
+
+        // Insert the block, and finalize the transactions.
+        let insert_and_finalize = || -> Result<()> {
+            atomic_write_batch!(self, {
+                // Insert the block.
+                let tree = self.block_store().insert_uncommitted(block)?;
+                // Finalize the transactions.
+                let candidate = self
+                    .finalize_transactions(&process_lock, block.transactions(), block.height())
+                    .map_err(|error| BlockError::FinalizeFailed { height: block.height(), error })?;
+                *updates.write() = Some((tree, candidate));
+                Ok(())
+            });
+            Ok(())
+        };
+        if let Err(error) = insert_and_finalize() {
+            return Err(error.downcast::<BlockError>().unwrap_or_else(BlockError::from));
+        }
+
+        // Update the block tree and replace the process, now that the batch is committed.
+        if let Some((tree, candidate)) = updates.into_inner() {
+            self.block_store().set_tree(tree)?;
+            if let Some(candidate) = candidate {
+                *process_lock = candidate;
+            }
+        }
+        drop(process_lock);
+
+        // Lastly, prune the block that falls below the pruning depth.
+        // Note: The block is committed at this point, so a pruning failure is logged rather than returned,
+        // and the unpruned block is pruned by the next call to `set_pruning_policy`.
+        if let Err(error) = self.prune_after(block.height()) {
+            warn!("Failed to prune the blocks after block {}: {error}", block.height());
+        }
+        Ok(())
+    }
+
+    /// Checks that the given block is a valid successor of the current chain tip.
+    pub fn check_next_block(&self, block: &Block<N>) -> Result<(), BlockError> {
+        // Retrieve the latest block, if one exists.
+        let latest_block = self.latest_block()?;
+
+        match latest_block {
+            // Ensure the first block is a genesis block.
+            None => {
+                if block.height() != 0 {
+                    return Err(BlockError::InvalidHeight { expected: 0, found: block.height() });
+                }
+                if !block.is_genesis() {
+                    return Err(BlockError::InvalidGenesis { reason: "the block is not a genesis block".to_string() });
+                }
+                if block.network() != N::ID {
+                    return Err(BlockError::InvalidNetworkID { height: 0, expected: N::ID, found: block.network() });
+                }
+            }
+            // Ensure the block extends the latest block.
+            Some(latest_block) => {
+                // Retrieve the coinbase puzzle and epoch challenge, if the block has a coinbase solution.
+                let coinbase = match block.coinbase() {
+                    Some(_) => Some((
+                        self.coinbase_puzzle.get_or_try_init(CoinbasePuzzle::load)?,
+                        self.get_epoch_challenge(block.epoch_number())?,
+                    )),
+                    None => None,
+                };
+                // Verify the block against the latest block.
+                block.verify(
+                    &latest_block,
+                    self.block_store().current_state_root(),
+                    coinbase.as_ref().map(|(coinbase_puzzle, epoch_challenge)| (*coinbase_puzzle, epoch_challenge)),
+                )?;
+            }
+        }
+
+        // Ensure the puzzle commitments are new.
+        if let Some(puzzle_commitments) = block.puzzle_commitments() {
+            for puzzle_commitment in puzzle_commitments {
+                if self.block_store().contains_puzzle_commitment(&puzzle_commitment)? {
+                    return Err(BlockError::InvalidCoinbaseSolution {
+                        height: block.height(),
+                        reason: format!("puzzle commitment '{puzzle_commitment}' already exists in the ledger"),
+                    });
+                }
+            }
+        }
+
+        // Ensure there are no duplicate transaction IDs or serial numbers within the block.
+        if has_duplicates(block.transaction_ids()) {
+            return Err(anyhow!("Found duplicate transaction IDs in block {}", block.height()).into());
+        }
+        if has_duplicates(block.serial_numbers()) {
+            return Err(anyhow!("Found duplicate serial numbers in block {}", block.height()).into());
+        }
+
+        // Ensure each transaction is valid and new.
+        for transaction in block.transactions().iter() {
+            let invalid_transaction = |reason: &str| BlockError::InvalidTransaction {
+                height: block.height(),
+                transaction_id: transaction.id().to_string(),
+                reason: reason.to_string(),
+            };
+            if self.transaction_store().contains_transaction_id(&transaction.id())? {
+                return Err(invalid_transaction("the transaction already exists in the ledger"));
+            }
+            for serial_number in transaction.serial_numbers() {
+                if self.transition_store().contains_serial_number(serial_number)? {
+                    return Err(invalid_transaction("a serial number already exists in the ledger"));
+                }
+            }
+            // Note: The genesis transactions are not verified, as their state roots precede the ledger.
+            if !block.is_genesis() && !self.verify(transaction) {
+                return Err(invalid_transaction("the transaction failed verification"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the epoch challenge for the given epoch number.
+    fn get_epoch_challenge(&self, epoch_number: u32) -> Result<EpochChallenge<N>> {
+        // Retrieve the hash of the block preceding the epoch.
+        let epoch_block_hash = self
+            .block_store()
+            .get_previous_block_hash(epoch_number.saturating_mul(N::NUM_BLOCKS_PER_EPOCH))?
+            .ok_or_else(|| anyhow!("Epoch block hash for epoch {epoch_number} is missing in storage"))?;
+        // Construct the epoch challenge.
+        EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)
+    }
+
+    /// Returns the latest block in the VM, if one exists.
+    pub fn latest_block(&self) -> Result<Option<Block<N>>> {
+        match self.block_store().heights().max().map(|height| *height) {
+            Some(latest_height) => {
+                let latest_hash = self
+                    .block_store()
+                    .get_block_hash(latest_height)?
+                    .ok_or_else(|| anyhow!("Block hash for height {latest_height} is missing in storage"))?;
+                let latest_block = self
+                    .block_store()
+                    .get_block(&latest_hash)?
+                    .ok_or_else(|| anyhow!("Block {latest_height} is missing in storage"))?;
+                Ok(Some(latest_block))
             }
+            None => Ok(None),
         }
     }
 
@@ -201,6 +415,35 @@ pub(crate) mod test_helpers {
         vm
     }
 
+    pub(crate) fn sample_genesis_credits(rng: &mut TestRng) -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Return the genesis record.
+        genesis.into_records().next().unwrap().1.decrypt(&caller_view_key).unwrap()
+    }
+
+    /// Returns the next block with a deployment of the given program, whose fee is paid with the given
+    /// credits, along with the change record of the fee.
+    pub(crate) fn sample_next_block_with_deployment<C: ConsensusStorage<CurrentNetwork>>(
+        vm: &VM<CurrentNetwork, C>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        program: &Program<CurrentNetwork>,
+        credits: Record<CurrentNetwork, Plaintext<CurrentNetwork>>,
+        rng: &mut TestRng,
+    ) -> (Block<CurrentNetwork>, Record<CurrentNetwork, Plaintext<CurrentNetwork>>) {
+        let view_key = ViewKey::try_from(private_key).unwrap();
+        // Deploy the program.
+        let transaction = Transaction::deploy(vm, private_key, program, (credits, 10), None, rng).unwrap();
+        // Propose the next block.
+        let block = vm.propose_next_block(private_key, &[transaction], None, rng).unwrap();
+        // Retrieve the change record of the fee.
+        let change = block.clone().into_records().next().unwrap().1.decrypt(&view_key).unwrap();
+        (block, change)
+    }
+
     pub(crate) fn sample_program() -> Program<CurrentNetwork> {
         static INSTANCE: OnceCell<Program<CurrentNetwork>> = OnceCell::new();
         INSTANCE
@@ -360,3 +603,171 @@ function compute:
             .clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{sample_genesis_block, sample_program, sample_vm, sample_vm_with_genesis_block};
+
+    #[test]
+    fn test_check_next_block_genesis() {
+        let rng = &mut TestRng::default();
+
+        // Initialize an empty VM.
+        let vm = sample_vm();
+        // Sample the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Ensure the genesis block is a valid first block.
+        vm.check_next_block(&genesis).unwrap();
+        vm.add_next_block(&genesis).unwrap();
+    }
+
+    #[test]
+    fn test_add_next_block_rejects_duplicate_genesis() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Sample the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Ensure the genesis block cannot be added twice.
+        let error = vm.add_next_block(&genesis).unwrap_err();
+        assert!(matches!(error, BlockError::InvalidHeight { expected: 1, found: 0 }), "{error}");
+        // Ensure the chain is unchanged.
+        assert_eq!(vm.block_store().heights().count(), 1);
+    }
+
+    #[test]
+    fn test_add_next_block_finalize_failure() {
+        use crate::{
+            program::Program,
+            vm::test_helpers::{sample_genesis_credits, sample_genesis_private_key, sample_next_block_with_deployment},
+        };
+        use console::account::Address;
+
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Deploy a program, whose finalize scope fails if the key is not in the mapping.
+        let program = Program::from_str(
+            r"
+program finalize_failure.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function read_public:
+    input r0 as address.public;
+    finalize r0;
+
+finalize read_public:
+    input r0 as address.public;
+    get account[r0] into r1;",
+        )
+        .unwrap();
+        let (block, change) =
+            sample_next_block_with_deployment(&vm, &private_key, &program, sample_genesis_credits(rng), rng);
+        vm.add_next_block(&block).unwrap();
+
+        // Retrieve the state after the deployment.
+        let state_root = vm.block_store().current_state_root();
+        let checksum = vm.program_store().get_checksum().unwrap();
+
+        // Propose a block with an execution, whose finalize scope fails.
+        let inputs = [Value::from_str(&address.to_string()).unwrap()];
+        let authorization =
+            vm.authorize(&private_key, "finalize_failure.aleo", "read_public", inputs.into_iter(), rng).unwrap();
+        let execution = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let block = vm.propose_next_block(&private_key, &[execution], None, rng).unwrap();
+
+        // Ensure the block fails to finalize, and leaves the VM unchanged.
+        let error = vm.add_next_block(&block).unwrap_err();
+        assert!(matches!(error, BlockError::FinalizeFailed { height: 2, .. }), "{error}");
+        assert_eq!(vm.block_store().heights().count(), 2);
+        assert!(!vm.transaction_store().contains_transaction_id(block.transaction_ids().next().unwrap()).unwrap());
+        assert_eq!(vm.block_store().current_state_root(), state_root);
+        assert_eq!(vm.program_store().get_checksum().unwrap(), checksum);
+        assert!(vm.program_store().get_finalize_diff(2).unwrap().is_none());
+
+        // Ensure the next block can still be added.
+        let (block, _) = sample_next_block_with_deployment(&vm, &private_key, &sample_program(), change, rng);
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(vm.block_store().heights().count(), 3);
+        assert!(vm.contains_program(sample_program().id()));
+    }
+
+    #[test]
+    fn test_devnet_chain() {
+        use crate::ConsensusMemory;
+        use console::{
+            account::{Address, ViewKey},
+            network::Devnet,
+            program::Literal,
+        };
+        use indexmap::IndexMap;
+
+        let rng = &mut TestRng::default();
+
+        // Synthesize the devnet circuit keys.
+        Process::<Devnet>::setup::<circuit::AleoDevnetV0, _>(rng).unwrap();
+
+        // Initialize the VM, and add a genesis block.
+        let vm = VM::from(ConsensusStore::<Devnet, ConsensusMemory<Devnet>>::open(None).unwrap()).unwrap();
+        let private_key = PrivateKey::<Devnet>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let genesis = Block::genesis(&vm, &private_key, rng).unwrap();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Retrieve the genesis record.
+        let records = genesis.transitions().cloned().flat_map(Transition::into_records).collect::<IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&view_key).unwrap();
+
+        // Deploy a program with a finalize scope.
+        let program = Program::<Devnet>::from_str(
+            r"
+program devnet_test.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;",
+        )
+        .unwrap();
+        let deployment = Transaction::deploy(&vm, &private_key, &program, (credits, 10), None, rng).unwrap();
+        let block = vm.propose_next_block(&private_key, &[deployment], None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(program.id()));
+
+        // Execute the program.
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("10u64").unwrap()];
+        let authorization =
+            vm.authorize(&private_key, "devnet_test.aleo", "mint_public", inputs.into_iter(), rng).unwrap();
+        let execution = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let block = vm.propose_next_block(&private_key, &[execution], None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the finalize scope was applied.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(address));
+        let value = vm.program_store().get_value(program.id(), &mapping_name, &key).unwrap();
+        assert_eq!(value, Some(Value::from_str("10u64").unwrap()));
+        assert_eq!(vm.block_store().heights().count(), 3);
+    }
+}
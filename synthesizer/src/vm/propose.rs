@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    block::{Header, Metadata},
+    coinbase_puzzle::CoinbaseSolution,
+    consensus::{coinbase_target, proof_target},
+};
+use console::types::Field;
+
+use std::{
+    collections::HashSet,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a new block that extends the latest block, signed by the given private key.
+    /// The candidate transactions that are invalid, already in the ledger, or conflict
+    /// with a previously-selected transaction are dropped.
+    pub fn propose_next_block<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        candidate_transactions: &[Transaction<N>],
+        coinbase_solution: Option<CoinbaseSolution<N>>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        let timer = timer!("VM::propose_next_block");
+
+        // Retrieve the latest block.
+        let latest_block = match self.latest_block()? {
+            Some(latest_block) => latest_block,
+            None => bail!("Cannot propose the next block without a genesis block"),
+        };
+
+        // Select the transactions for the block.
+        let transactions = self.select_transactions(candidate_transactions)?;
+        ensure!(!transactions.is_empty(), "Cannot propose a block without any valid transactions");
+        lap!(timer, "Select the transactions");
+
+        // Prepare the metadata.
+        let height = latest_block.height() + 1;
+        let round = latest_block.round() + 1;
+        // Note: The timestamp is forced to increase, even if the local clock is behind the latest block.
+        let now = i64::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())?;
+        let timestamp = now.max(latest_block.timestamp() + 1);
+        let coinbase_target = coinbase_target(
+            latest_block.last_coinbase_target(),
+            latest_block.last_coinbase_timestamp(),
+            timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        let proof_target = proof_target(coinbase_target, N::GENESIS_PROOF_TARGET);
+        // Ensure the coinbase solution meets the coinbase target, so that it earns the full anchor reward.
+        if let Some(coinbase_solution) = &coinbase_solution {
+            let cumulative_proof_target = coinbase_solution.to_cumulative_proof_target()?;
+            ensure!(
+                cumulative_proof_target >= coinbase_target as u128,
+                "The coinbase solution does not meet the coinbase target of {coinbase_target}"
+            );
+        }
+        let (last_coinbase_target, last_coinbase_timestamp) = match coinbase_solution {
+            Some(_) => (coinbase_target, timestamp),
+            None => (latest_block.last_coinbase_target(), latest_block.last_coinbase_timestamp()),
+        };
+        let metadata = Metadata::new(
+            N::ID,
+            round,
+            height,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        )?;
+
+        // Prepare the header.
+        let previous_state_root = *self.block_store().current_state_root();
+        let transactions_root = transactions.to_root()?;
+        let coinbase_accumulator_point = match &coinbase_solution {
+            Some(coinbase_solution) => coinbase_solution.to_accumulator_point()?,
+            None => Field::zero(),
+        };
+        let header = Header::from(previous_state_root, transactions_root, coinbase_accumulator_point, metadata)?;
+        lap!(timer, "Construct the header");
+
+        // Construct the block.
+        let block = Block::new(private_key, latest_block.hash(), header, transactions, coinbase_solution, rng)?;
+        // Ensure the block reward is well-defined.
+        block.block_reward()?;
+        finish!(timer);
+
+        Ok(block)
+    }
+
+    /// Returns the candidate transactions that are valid, new, and do not conflict with one another.
+    fn select_transactions(&self, candidate_transactions: &[Transaction<N>]) -> Result<Transactions<N>> {
+        // Track the transaction IDs and serial numbers of the selected transactions.
+        let mut transaction_ids = HashSet::new();
+        let mut serial_numbers = HashSet::new();
+
+        let mut selected = Vec::with_capacity(candidate_transactions.len());
+        for transaction in candidate_transactions {
+            // Skip duplicate transactions.
+            if transaction_ids.contains(&transaction.id())
+                || self.transaction_store().contains_transaction_id(&transaction.id())?
+            {
+                continue;
+            }
+            // Skip transactions that spend a serial number that is already spent.
+            let mut is_conflicting = false;
+            for serial_number in transaction.serial_numbers() {
+                if serial_numbers.contains(serial_number)
+                    || self.transition_store().contains_serial_number(serial_number)?
+                {
+                    is_conflicting = true;
+                    break;
+                }
+            }
+            if is_conflicting {
+                warn!("Dropping transaction '{}' with a conflicting serial number", transaction.id());
+                continue;
+            }
+            // Skip invalid transactions.
+            if !self.verify(transaction) {
+                warn!("Dropping invalid transaction '{}'", transaction.id());
+                continue;
+            }
+
+            transaction_ids.insert(transaction.id());
+            serial_numbers.extend(transaction.serial_numbers().copied());
+            selected.push(transaction.clone());
+        }
+
+        Ok(selected.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        consensus::staking_reward,
+        vm::test_helpers::{
+            sample_deployment_transaction,
+            sample_genesis_private_key,
+            sample_program,
+            sample_vm_with_genesis_block,
+            CurrentNetwork,
+        },
+    };
+
+    #[test]
+    fn test_propose_next_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        // Sample a transaction, and submit it twice.
+        let transaction = sample_deployment_transaction(rng);
+        let candidates = [transaction.clone(), transaction.clone()];
+
+        // Propose the next block.
+        let block = vm.propose_next_block(&private_key, &candidates, None, rng).unwrap();
+        assert_eq!(block.height(), 1);
+        assert_eq!(block.transactions().len(), 1);
+        assert_eq!(block.transaction_ids().next(), Some(&transaction.id()));
+
+        // Ensure the block without a coinbase solution only earns the staking reward.
+        let staking_reward = staking_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::ANCHOR_TIME);
+        assert_eq!(block.coinbase_reward().unwrap(), 0);
+        assert_eq!(block.block_reward().unwrap(), staking_reward);
+
+        // Ensure the block is accepted by the VM.
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(sample_program().id()));
+
+        // Ensure the same transaction cannot be proposed again.
+        assert!(vm.propose_next_block(&private_key, &candidates, None, rng).is_err());
+    }
+}
@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The policy for retaining the proofs and ciphertexts of historical transitions.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PruningPolicy {
+    /// Retains the proofs and ciphertexts of every transition.
+    #[default]
+    Archive,
+    /// Prunes the proofs and ciphertexts of the transitions in every block that is at least `depth` blocks
+    /// below the latest block. The serial numbers, commitments, tags, and block tree are retained.
+    Prune { depth: u32 },
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns the pruning policy.
+    #[inline]
+    pub fn pruning_policy(&self) -> PruningPolicy {
+        *self.pruning_policy.read()
+    }
+
+    /// Sets the pruning policy, and immediately prunes every block that falls below the pruning depth.
+    ///
+    /// Note: A pruned block can no longer be retrieved with `get_block`, scanned for records, exported in a snapshot,
+    /// rolled back to, or replaced by `switch_to_fork`, which return `TransitionError::Pruned`. A pruned block can
+    /// still be removed by `rollback_to`. Double-spend checks and state paths are unaffected.
+    pub fn set_pruning_policy(&self, policy: PruningPolicy) -> Result<()> {
+        // Ensure the latest block is never pruned, as it is required to verify the next block.
+        if let PruningPolicy::Prune { depth } = policy {
+            ensure!(depth > 0, "The pruning depth must be at least 1");
+        }
+        // Update the pruning policy.
+        *self.pruning_policy.write() = policy;
+
+        // Prune the blocks that fall below the pruning depth.
+        if let PruningPolicy::Prune { depth } = policy {
+            if let Some(latest_height) = self.block_store().heights().max().map(|height| *height) {
+                if let Some(end_height) = latest_height.checked_sub(depth) {
+                    for height in 0..=end_height {
+                        self.block_store().prune_transitions(height)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Prunes the block that falls below the pruning depth, once the block at the given height is added.
+    pub(super) fn prune_after(&self, height: u32) -> Result<()> {
+        match self.pruning_policy() {
+            PruningPolicy::Archive => Ok(()),
+            PruningPolicy::Prune { depth } => match height.checked_sub(depth) {
+                Some(prune_height) => self.block_store().prune_transitions(prune_height),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        store::TransitionError,
+        vm::test_helpers::{
+            sample_deployment_transaction,
+            sample_execution_transaction,
+            sample_genesis_credits,
+            sample_genesis_private_key,
+            sample_next_block_with_deployment,
+            sample_program,
+            sample_vm_with_genesis_block,
+        },
+    };
+    use console::account::{Address, ViewKey};
+    use snarkvm_utilities::TestRng;
+
+    /// Returns `true` if the given error is a pruned error.
+    fn is_pruned_error(error: &Error) -> bool {
+        matches!(error.downcast_ref::<TransitionError>(), Some(TransitionError::Pruned { .. }))
+    }
+
+    #[test]
+    fn test_pruning_policy() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let genesis = vm.latest_block().unwrap().unwrap();
+        assert_eq!(vm.pruning_policy(), PruningPolicy::Archive);
+
+        // Add a block with an execution.
+        let block = vm.propose_next_block(&private_key, &[sample_execution_transaction(rng)], None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Retrieve the state paths, prior to pruning.
+        let commitment = *block.commitments().next().unwrap();
+        let genesis_commitment = *genesis.commitments().next().unwrap();
+        let state_path = vm.block_store().get_state_path_for_commitment(&commitment).unwrap();
+        let genesis_state_path = vm.block_store().get_state_path_for_commitment(&genesis_commitment).unwrap();
+
+        // Ensure the pruning depth must be non-zero.
+        assert!(vm.set_pruning_policy(PruningPolicy::Prune { depth: 0 }).is_err());
+        assert_eq!(vm.pruning_policy(), PruningPolicy::Archive);
+
+        // Prune the genesis block.
+        vm.set_pruning_policy(PruningPolicy::Prune { depth: 1 }).unwrap();
+        for transition_id in genesis.transition_ids() {
+            assert!(vm.transition_store().is_pruned(transition_id).unwrap());
+        }
+        for transition_id in block.transition_ids() {
+            assert!(!vm.transition_store().is_pruned(transition_id).unwrap());
+        }
+        assert!(vm.block_store().get_block(&genesis.hash()).is_err());
+
+        // Prune the latest block.
+        vm.block_store().prune_transitions(block.height()).unwrap();
+        for transition_id in block.transition_ids() {
+            // Ensure the transition returns a pruned error.
+            let error = vm.transition_store().get_transition(transition_id).unwrap_err();
+            assert!(matches!(error.downcast_ref::<TransitionError>(), Some(TransitionError::Pruned { .. })));
+        }
+        // Ensure pruning is idempotent.
+        vm.block_store().prune_transitions(block.height()).unwrap();
+
+        // Ensure the serial numbers, commitments, and record nonces are retained, for double-spend checks.
+        for serial_number in block.serial_numbers() {
+            assert!(vm.transition_store().contains_serial_number(serial_number).unwrap());
+        }
+        for commitment in block.commitments() {
+            assert!(vm.transition_store().contains_commitment(commitment).unwrap());
+        }
+        for nonce in block.transitions().flat_map(Transition::nonces) {
+            assert!(vm.transition_store().contains_nonce(nonce).unwrap());
+        }
+        // Ensure the state paths are unchanged.
+        assert!(state_path == vm.block_store().get_state_path_for_commitment(&commitment).unwrap());
+        assert!(genesis_state_path == vm.block_store().get_state_path_for_commitment(&genesis_commitment).unwrap());
+    }
+
+    #[test]
+    fn test_pruned_deployment_fee_state_path() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+
+        // Add a block with a deployment.
+        let transaction = sample_deployment_transaction(rng);
+        let block = vm.propose_next_block(&private_key, &[transaction.clone()], None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Retrieve the fee transition ID and the commitment of its change record.
+        let fee_id = *transaction.transition_ids().next().unwrap();
+        let commitment = *transaction.commitments().next().unwrap();
+
+        // Ensure the state path matches the deployment, prior to pruning.
+        let state_path = vm.block_store().get_state_path_for_commitment(&commitment).unwrap();
+        let transaction_leaf = transaction.to_leaf(&fee_id).unwrap();
+        assert!(*state_path.transaction_id() == transaction.id());
+        assert!(*state_path.transaction_leaf() == transaction_leaf);
+        assert!(*state_path.transaction_path() == transaction.to_path(&transaction_leaf).unwrap());
+
+        // Prune the block.
+        vm.block_store().prune_transitions(block.height()).unwrap();
+        assert!(vm.transition_store().is_pruned(&fee_id).unwrap());
+
+        // Ensure the state path is unchanged.
+        assert!(state_path == vm.block_store().get_state_path_for_commitment(&commitment).unwrap());
+    }
+
+    #[test]
+    fn test_pruned_data() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Add a block with a deployment.
+        let (block_1, change) =
+            sample_next_block_with_deployment(&vm, &private_key, &sample_program(), sample_genesis_credits(rng), rng);
+        vm.add_next_block(&block_1).unwrap();
+        // Add a block with an execution, which spends the change of the deployment fee.
+        let inputs =
+            [Value::Record(change), Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization = vm.authorize(&private_key, "credits.aleo", "transfer", inputs.into_iter(), rng).unwrap();
+        let execution = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        let block_2 = vm.propose_next_block(&private_key, &[execution], None, rng).unwrap();
+        vm.add_next_block(&block_2).unwrap();
+
+        // Returns the latest block height.
+        let latest_height = || vm.block_store().heights().max().map(|height| *height);
+
+        // Prune the latest block.
+        vm.block_store().prune_transitions(block_2.height()).unwrap();
+
+        // Ensure the chain cannot switch to a fork that replaces the pruned block.
+        let error = vm.switch_to_fork(&[block_2.clone()]).unwrap_err();
+        assert!(is_pruned_error(&error), "{error}");
+        assert_eq!(latest_height(), Some(block_2.height()));
+
+        // Ensure the pruned block can be rolled back, and added again.
+        vm.rollback_to(block_1.height()).unwrap();
+        assert_eq!(latest_height(), Some(block_1.height()));
+        for transition_id in block_2.transition_ids() {
+            assert!(!vm.transition_store().contains_transition_id(transition_id).unwrap());
+        }
+        vm.add_next_block(&block_2).unwrap();
+        for transition_id in block_2.transition_ids() {
+            assert!(!vm.transition_store().is_pruned(transition_id).unwrap());
+        }
+
+        // Prune the blocks below the latest block.
+        vm.set_pruning_policy(PruningPolicy::Prune { depth: 1 }).unwrap();
+
+        // Ensure a record scan of the pruned blocks is refused.
+        let error = vm.block_store().scan_records(&view_key, None, 0..u32::MAX).unwrap_err();
+        assert!(is_pruned_error(&error), "{error}");
+        // Ensure a record scan of the latest block succeeds.
+        vm.block_store().scan_records(&view_key, None, block_2.height()..u32::MAX).unwrap();
+
+        // Ensure a snapshot export is refused, before anything is written.
+        let mut snapshot = Vec::new();
+        let error = vm.export_snapshot(&mut snapshot).unwrap_err();
+        assert!(is_pruned_error(&error), "{error}");
+        assert!(snapshot.is_empty());
+
+        // Ensure the chain cannot roll back to a pruned block.
+        let error = vm.rollback_to(block_1.height()).unwrap_err();
+        assert!(is_pruned_error(&error), "{error}");
+        assert_eq!(latest_height(), Some(block_2.height()));
+    }
+}
@@ -0,0 +1,326 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The maximum number of blocks that can be rolled back. The finalize diffs of the blocks
+/// that fall below this depth are pruned, so these blocks can no longer be reverted.
+pub const MAX_ROLLBACK_DEPTH: u32 = 100;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Rolls back the chain to the block at the given height, reverting the finalized state of each
+    /// later block in descending order. Note: The block at the given height is retained.
+    ///
+    /// The chain can be rolled back by at most `MAX_ROLLBACK_DEPTH` blocks.
+    pub fn rollback_to(&self, height: u32) -> Result<()> {
+        let timer = timer!("VM::rollback_to");
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().heights().max() {
+            Some(latest_height) => *latest_height,
+            None => bail!("Cannot roll back to block {height} without a genesis block"),
+        };
+        // Ensure the given height is in the chain.
+        ensure!(height <= latest_height, "Cannot roll back to block {height}, as the latest block is {latest_height}");
+        // If the given height is the latest block, there is nothing to roll back.
+        if height == latest_height {
+            finish!(timer);
+            return Ok(());
+        }
+        // Ensure the finalize diffs of the removed blocks are retained.
+        ensure!(
+            latest_height - height <= MAX_ROLLBACK_DEPTH,
+            "Cannot roll back to block {height}, as it is more than {MAX_ROLLBACK_DEPTH} blocks below the latest block"
+        );
+        // Ensure the block at the given height is not pruned, as the latest block is required to verify the next block.
+        // Note: The blocks above the given height may be pruned, as their finalize diffs are retained.
+        self.block_store().check_not_pruned(height)?;
+
+        // Acquire the write lock on the process.
+        let mut process = self.process.write();
+        // Load the process of the retained blocks, to drop the programs deployed (or upgraded) in the removed blocks.
+        // Note: The process is loaded before the batch, so that a failure leaves the chain and the process unchanged.
+        let candidate = Self::load_process_up_to(self.block_store(), height)?;
+        lap!(timer, "Load the process");
+
+        atomic_write_batch!(self, {
+            // Revert the finalized state of the blocks, in descending order.
+            for block_height in (height + 1..=latest_height).rev() {
+                self.program_store().revert_finalize_diff(block_height)?;
+            }
+            lap!(timer, "Revert the finalize diffs");
+
+            // Remove the blocks.
+            self.block_store().remove_last_n(latest_height - height)?;
+            lap!(timer, "Remove the blocks");
+
+            Ok(())
+        });
+
+        // Swap in the process, now that the batch is committed.
+        *process = candidate;
+
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Switches the chain to the given fork, which is a sequence of consecutive blocks that extends
+    /// a block in the current chain. If a block in the fork is invalid, the current chain is restored.
+    pub fn switch_to_fork(&self, blocks: &[Block<N>]) -> Result<()> {
+        let timer = timer!("VM::switch_to_fork");
+
+        // Retrieve the first block of the fork.
+        let first_block = match blocks.first() {
+            Some(first_block) => first_block,
+            None => bail!("Cannot switch to a fork without any blocks"),
+        };
+        // Ensure the fork does not replace the genesis block.
+        ensure!(first_block.height() > 0, "Cannot switch to a fork that replaces the genesis block");
+        // Determine the height of the block that the fork extends.
+        let fork_height = first_block.height() - 1;
+
+        // Retrieve the latest block height.
+        let latest_height = match self.block_store().heights().max() {
+            Some(latest_height) => *latest_height,
+            None => bail!("Cannot switch to a fork without a genesis block"),
+        };
+        // Ensure the fork extends a block in the current chain.
+        ensure!(
+            fork_height <= latest_height,
+            "Cannot switch to a fork at block {fork_height}, as it is not in the chain"
+        );
+
+        // Retrieve the blocks that are replaced by the fork, in ascending order.
+        let replaced_blocks = (fork_height + 1..=latest_height)
+            .map(|height| {
+                let block_hash = self
+                    .block_store()
+                    .get_block_hash(height)?
+                    .ok_or_else(|| anyhow!("Block hash for height {height} is missing in storage"))?;
+                self.block_store()
+                    .get_block(&block_hash)?
+                    .ok_or_else(|| anyhow!("Block {height} is missing in storage"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Retrieve the replaced blocks");
+
+        // Roll back the chain to the block that the fork extends.
+        self.rollback_to(fork_height)?;
+        lap!(timer, "Roll back the chain");
+
+        // Restores the replaced blocks.
+        let restore = || -> Result<()> {
+            self.rollback_to(fork_height)?;
+            for replaced_block in &replaced_blocks {
+                self.add_next_block(replaced_block)?;
+            }
+            Ok(())
+        };
+
+        // Add the blocks of the fork.
+        for block in blocks {
+            if let Err(error) = self.add_next_block(block) {
+                // Restore the replaced blocks, and report both errors if the chain cannot be restored.
+                if let Err(restore_error) = restore() {
+                    bail!(
+                        "Failed to switch to the fork at block {}: {error}. The chain was not restored: {restore_error}",
+                        block.height()
+                    )
+                }
+                bail!("Failed to switch to the fork at block {}: {error}", block.height())
+            }
+        }
+
+        finish!(timer);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{
+        sample_deployment_transaction,
+        sample_execution_transaction,
+        sample_genesis_credits,
+        sample_genesis_private_key,
+        sample_next_block_with_deployment,
+        sample_program,
+        sample_vm_with_genesis_block,
+    };
+    use console::{account::Address, program::Literal};
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn test_rollback_to() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        // Retrieve the state of the genesis block.
+        let checksum = vm.program_store().get_checksum().unwrap();
+        let state_root = vm.block_store().current_state_root();
+
+        // Add a block with a deployment.
+        let block = vm.propose_next_block(&private_key, &[sample_deployment_transaction(rng)], None, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(sample_program().id()));
+        assert!(vm.program_store().get_finalize_diff(1).unwrap().is_some());
+
+        // Roll back to the genesis block.
+        vm.rollback_to(0).unwrap();
+        assert_eq!(vm.latest_block().unwrap().unwrap().height(), 0);
+        assert_eq!(checksum, vm.program_store().get_checksum().unwrap());
+        assert_eq!(state_root, vm.block_store().current_state_root());
+        assert!(!vm.contains_program(sample_program().id()));
+        assert!(vm.program_store().get_finalize_diff(1).unwrap().is_none());
+
+        // Ensure rolling back to the latest block is a no-op.
+        vm.rollback_to(0).unwrap();
+        assert_eq!(checksum, vm.program_store().get_checksum().unwrap());
+        // Ensure rolling back beyond the latest block fails.
+        assert!(vm.rollback_to(1).is_err());
+
+        // Ensure the block can be added again.
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(sample_program().id()));
+    }
+
+    #[test]
+    fn test_rollback_to_reverts_finalize() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Deploy a program, whose finalize scopes increment and decrement a mapping.
+        let program = Program::from_str(
+            r"
+program rollback_test.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;
+
+function burn_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize burn_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    decrement account[r0] by r1;",
+        )
+        .unwrap();
+        let (block, _) =
+            sample_next_block_with_deployment(&vm, &private_key, &program, sample_genesis_credits(rng), rng);
+        vm.add_next_block(&block).unwrap();
+
+        // Executes the given function of the program in the next block, and returns the checksum.
+        let execute = |function_name: &str, amount: &str, rng: &mut TestRng| {
+            let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str(amount).unwrap()];
+            let authorization =
+                vm.authorize(&private_key, "rollback_test.aleo", function_name, inputs.into_iter(), rng).unwrap();
+            let execution = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+            let block = vm.propose_next_block(&private_key, &[execution], None, rng).unwrap();
+            vm.add_next_block(&block).unwrap();
+            vm.program_store().get_checksum().unwrap()
+        };
+        // Returns the value of the mapping for the address.
+        let get_value = || {
+            let mapping_name = Identifier::from_str("account").unwrap();
+            let key = Plaintext::from(Literal::Address(address));
+            vm.program_store().get_value(program.id(), &mapping_name, &key).unwrap()
+        };
+
+        // Retrieve the checksum after the deployment.
+        let checksum_1 = vm.program_store().get_checksum().unwrap();
+
+        // Increment the mapping, and then decrement it.
+        let checksum_2 = execute("mint_public", "10u64", rng);
+        assert_eq!(get_value(), Some(Value::from_str("10u64").unwrap()));
+        let checksum_3 = execute("burn_public", "3u64", rng);
+        assert_eq!(get_value(), Some(Value::from_str("7u64").unwrap()));
+        assert_ne!(checksum_1, checksum_2);
+        assert_ne!(checksum_2, checksum_3);
+
+        // Roll back the decrement.
+        vm.rollback_to(2).unwrap();
+        assert_eq!(checksum_2, vm.program_store().get_checksum().unwrap());
+        assert_eq!(get_value(), Some(Value::from_str("10u64").unwrap()));
+
+        // Roll back the increment.
+        vm.rollback_to(1).unwrap();
+        assert_eq!(checksum_1, vm.program_store().get_checksum().unwrap());
+        assert_eq!(get_value(), None);
+        assert!(vm.contains_program(program.id()));
+
+        // Ensure the increment can be applied again.
+        assert_eq!(checksum_2, execute("mint_public", "10u64", rng));
+    }
+
+    #[test]
+    fn test_switch_to_fork() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+
+        // Propose two competing blocks, which spend the same record.
+        let block_a = vm.propose_next_block(&private_key, &[sample_deployment_transaction(rng)], None, rng).unwrap();
+        let block_b = vm.propose_next_block(&private_key, &[sample_execution_transaction(rng)], None, rng).unwrap();
+
+        // Add the first block.
+        vm.add_next_block(&block_a).unwrap();
+        assert!(vm.contains_program(sample_program().id()));
+        let checksum_a = vm.program_store().get_checksum().unwrap();
+
+        // Switch to the fork with the second block.
+        vm.switch_to_fork(&[block_b.clone()]).unwrap();
+        assert_eq!(vm.latest_block().unwrap().unwrap().hash(), block_b.hash());
+        assert!(!vm.contains_program(sample_program().id()));
+        // Ensure the second block, which does not change the program storage, has no finalize diff.
+        assert!(vm.program_store().get_finalize_diff(1).unwrap().is_none());
+
+        // Ensure an invalid fork is rejected, and the current chain is restored.
+        assert!(vm.switch_to_fork(&[block_a.clone(), block_a.clone()]).is_err());
+        assert_eq!(vm.latest_block().unwrap().unwrap().hash(), block_b.hash());
+        assert!(!vm.contains_program(sample_program().id()));
+
+        // Switch back to the fork with the first block.
+        vm.switch_to_fork(&[block_a.clone()]).unwrap();
+        assert_eq!(vm.latest_block().unwrap().unwrap().hash(), block_a.hash());
+        assert!(vm.contains_program(sample_program().id()));
+        assert_eq!(checksum_a, vm.program_store().get_checksum().unwrap());
+    }
+}
@@ -76,6 +76,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     warn!("Invalid transaction size (deployment): {error}");
                     return false;
                 }
+                // Ensure the owner signature is valid for the fee.
+                match deployment.is_owner_valid(fee.transition_id()) {
+                    Ok(true) => (),
+                    Ok(false) => {
+                        warn!("Invalid owner signature in the deployment of '{}'", deployment.program_id());
+                        return false;
+                    }
+                    Err(error) => {
+                        warn!("Failed to verify the owner signature of the deployment: {error}");
+                        return false;
+                    }
+                }
                 // Verify the deployment.
                 self.verify_deployment(deployment)
                     // Verify the fee.
@@ -204,6 +216,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 #[cfg(test)]
 mod tests {
     use crate::{vm::test_helpers::sample_program, Inclusion, Transaction};
+    use console::{network::prelude::*, types::Field};
     use snarkvm_utilities::TestRng;
 
     #[test]
@@ -237,6 +250,33 @@ mod tests {
         assert!(vm.verify_deployment(&deployment));
     }
 
+    #[test]
+    fn test_verify_deployment_owner() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Fetch the deployment and fee of a deployment transaction.
+        let (deployment, fee) = match crate::vm::test_helpers::sample_deployment_transaction(rng) {
+            Transaction::Deploy(_, deployment, fee) => (*deployment, fee),
+            Transaction::Execute(..) => unreachable!("Expected a deployment transaction"),
+        };
+
+        // Ensure a deployment signed over its fee verifies.
+        let signed = deployment.clone().sign(&private_key, fee.transition_id(), rng).unwrap();
+        let transaction = Transaction::from_deployment(signed.clone(), fee.clone()).unwrap();
+        assert!(vm.verify(&transaction));
+
+        // Ensure a deployment signed over another fee is rejected.
+        let other_fee_id = Field::rand(rng).into();
+        let unbound = deployment.clone().sign(&private_key, &other_fee_id, rng).unwrap();
+        assert!(!vm.verify(&Transaction::from_deployment(unbound, fee.clone()).unwrap()));
+
+        // Ensure the owner cannot be removed without changing the transaction ID.
+        let stripped = Transaction::Deploy(*transaction.id(), Box::new(deployment), fee);
+        assert!(!vm.verify(&stripped));
+    }
+
     #[test]
     fn test_verify_execution() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CurrentNetwork;
+use crate::prelude::{Address, PrivateKey, ViewKey};
+
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use core::str::FromStr;
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Manages an Aleo account.
+#[derive(Debug, Parser)]
+pub enum Account {
+    /// Generates a new Aleo account
+    New {
+        /// Seeds the RNG with the given value
+        #[clap(short = 's', long)]
+        seed: Option<u64>,
+    },
+    /// Derives the view key and address of an Aleo account from its private key
+    Import {
+        /// The private key of the account
+        private_key: String,
+    },
+}
+
+impl Account {
+    /// Generates or imports an Aleo account.
+    pub fn start(&self) -> Result<String> {
+        let private_key = match self {
+            Self::New { seed } => {
+                // Initialize the RNG.
+                let mut rng = match seed {
+                    Some(seed) => StdRng::seed_from_u64(*seed),
+                    None => StdRng::from_entropy(),
+                };
+                PrivateKey::<CurrentNetwork>::new(&mut rng)?
+            }
+            Self::Import { private_key } => PrivateKey::<CurrentNetwork>::from_str(private_key)?,
+        };
+        Self::format(&private_key)
+    }
+
+    /// Returns the formatted private key, view key, and address of the given account.
+    fn format(private_key: &PrivateKey<CurrentNetwork>) -> Result<String> {
+        let view_key = ViewKey::try_from(private_key)?;
+        let address = Address::try_from(&view_key)?;
+        Ok(format!(
+            "\n {:>12}  {private_key}\n {:>12}  {view_key}\n {:>12}  {address}\n",
+            "Private Key".cyan().bold(),
+            "View Key".cyan().bold(),
+            "Address".cyan().bold(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_new_with_seed_is_deterministic() {
+        let account = Account::New { seed: Some(1234) };
+        assert_eq!(account.start().unwrap(), account.start().unwrap());
+    }
+
+    #[test]
+    fn test_account_import() {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut StdRng::seed_from_u64(1234)).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        let output = Account::Import { private_key: private_key.to_string() }.start().unwrap();
+        assert!(output.contains(&address.to_string()));
+        // Ensure an invalid private key is rejected.
+        assert!(Account::Import { private_key: "invalid".to_string() }.start().is_err());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CurrentNetwork;
+use crate::{package::Package, prelude::Severity};
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use colored::Colorize;
+use std::path::Path;
+
+/// Analyzes the Aleo program in the current directory.
+#[derive(Debug, Parser)]
+pub struct Analyze;
+
+impl Analyze {
+    /// Analyzes the Aleo program in the current directory, and fails if it contains an error.
+    pub fn start(&self) -> Result<String> {
+        self.start_in(&std::env::current_dir()?)
+    }
+
+    /// Analyzes the Aleo program in the given directory, and fails if it contains an error.
+    fn start_in(&self, directory: &Path) -> Result<String> {
+        // Open the package in the given directory.
+        let package = Package::<CurrentNetwork>::open(directory)?;
+        // Construct the process.
+        let process = package.get_process()?;
+
+        // Analyze the program.
+        let stack = process.get_stack(package.program_id())?;
+        let diagnostics = stack.analyze(package.program_file().program_string())?;
+        if diagnostics.is_empty() {
+            return Ok(format!("✅ Analyzed '{}' with no issues", package.program_id().to_string().bold()));
+        }
+
+        // Format the diagnostics.
+        let num_errors = diagnostics.iter().filter(|diagnostic| diagnostic.severity() == Severity::Error).count();
+        let num_warnings = diagnostics.len() - num_errors;
+        let diagnostics = diagnostics.iter().map(|diagnostic| format!(" • {diagnostic}")).collect::<Vec<_>>();
+        let summary = format!("{num_errors} error(s) and {num_warnings} warning(s)");
+
+        match num_errors {
+            0 => Ok(format!(
+                "⚠️  Analyzed '{}' with {summary}\n\n{}",
+                package.program_id().to_string().bold(),
+                diagnostics.join("\n")
+            )),
+            _ => bail!(
+                "Analyzed '{}' with {summary}\n\n{}",
+                package.program_id().to_string().bold(),
+                diagnostics.join("\n")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze() {
+        // Samples a new package at a temporary directory.
+        let (directory, _package) = crate::package::test_helpers::sample_package();
+
+        // Ensure the analysis succeeds.
+        let output = Analyze.start_in(&directory).unwrap();
+        assert!(output.contains("token.aleo"));
+
+        // Ensure the analysis reports warnings, without failing.
+        let program = |instruction: &str| {
+            format!(
+                "
+program token.aleo;
+
+function compute:
+    input r0 as u64.private;
+    {instruction};
+    output r0 as u64.private;"
+            )
+        };
+        std::fs::write(directory.join("main.aleo"), program("add r0 r0 into r1")).unwrap();
+        let output = Analyze.start_in(&directory).unwrap();
+        assert!(output.contains("0 error(s) and 1 warning(s)"), "{output}");
+        assert!(output.contains("warning[unused-register]"), "{output}");
+
+        // Ensure the analysis fails on an error.
+        std::fs::write(directory.join("main.aleo"), program("div r0 0u64 into r1")).unwrap();
+        let error = Analyze.start_in(&directory).unwrap_err().to_string();
+        assert!(error.contains("1 error(s) and 1 warning(s)"), "{error}");
+        assert!(error.contains("error[always-fails]"), "{error}");
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_analyze_without_package() {
+        // Initialize an empty temporary directory.
+        let directory = tempfile::tempdir().unwrap();
+        // Ensure the analysis fails without a package.
+        assert!(Analyze.start_in(directory.path()).is_err());
+    }
+}
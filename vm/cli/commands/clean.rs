@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CurrentNetwork;
+use crate::package::Package;
+
+use anyhow::Result;
+use clap::Parser;
+use std::path::Path;
+
+/// Removes the build directory of the Aleo program in the current directory.
+#[derive(Debug, Parser)]
+pub struct Clean;
+
+impl Clean {
+    /// Removes the build directory of the Aleo program in the current directory.
+    pub fn start(&self) -> Result<String> {
+        self.start_in(&std::env::current_dir()?)
+    }
+
+    /// Removes the build directory of the Aleo program in the given directory.
+    fn start_in(&self, directory: &Path) -> Result<String> {
+        // Clean the package in the given directory.
+        Package::<CurrentNetwork>::clean(directory)?;
+
+        Ok("✅ Cleaned the build directory".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::CurrentAleo;
+
+    #[test]
+    fn test_clean() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package();
+
+        // Build the package.
+        package.build::<CurrentAleo>(None).unwrap();
+        // Ensure the build directory exists.
+        assert!(package.build_directory().exists());
+        // Clean the package.
+        Clean.start_in(&directory).unwrap();
+        // Ensure the build directory does *not* exist.
+        assert!(!package.build_directory().exists());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_clean_without_package() {
+        // Initialize an empty temporary directory.
+        let directory = tempfile::tempdir().unwrap();
+        // Ensure the clean fails without a package.
+        assert!(Clean.start_in(directory.path()).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CurrentNetwork;
+use crate::prelude::{Ciphertext, Record, ViewKey};
+
+use anyhow::Result;
+use clap::Parser;
+use core::str::FromStr;
+
+/// Decrypts a record ciphertext with a view key.
+#[derive(Debug, Parser)]
+pub struct Decrypt {
+    /// The record ciphertext
+    ciphertext: String,
+    /// The view key of the record owner
+    #[clap(short = 'v', long)]
+    view_key: String,
+}
+
+impl Decrypt {
+    /// Decrypts the record ciphertext.
+    pub fn start(&self) -> Result<String> {
+        // Parse the record ciphertext and view key.
+        let record = Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::from_str(&self.ciphertext)?;
+        let view_key = ViewKey::<CurrentNetwork>::from_str(&self.view_key)?;
+        // Decrypt the record.
+        Ok(record.decrypt(&view_key)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{Address, Network, Plaintext, PrivateKey, Scalar, TestRng, Uniform};
+
+    #[test]
+    fn test_decrypt() {
+        let rng = &mut TestRng::default();
+
+        // Sample an account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Sample a record, and encrypt it to the account.
+        let randomizer = Scalar::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, gates: 5u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+
+        // Ensure the record decrypts with the owner's view key.
+        let decrypt = Decrypt { ciphertext: ciphertext.to_string(), view_key: view_key.to_string() };
+        assert_eq!(decrypt.start().unwrap(), record.to_string());
+
+        // Ensure another view key does not recover the record.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let decrypt = Decrypt { ciphertext: ciphertext.to_string(), view_key: other_view_key.to_string() };
+        assert_ne!(decrypt.start().ok(), Some(record.to_string()));
+
+        // Ensure an invalid ciphertext is rejected.
+        let decrypt = Decrypt { ciphertext: "invalid".to_string(), view_key: view_key.to_string() };
+        assert!(decrypt.start().is_err());
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{CurrentAleo, CurrentNetwork};
+use crate::package::Package;
+
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+
+/// Deploys the Aleo program in the current directory.
+#[derive(Debug, Parser)]
+pub struct Deploy {
+    /// Uses the specified endpoint to deploy the program
+    #[clap(long)]
+    endpoint: Option<String>,
+}
+
+impl Deploy {
+    /// Deploys the Aleo program in the current directory.
+    pub fn start(&self) -> Result<String> {
+        // Open the package in the current directory.
+        let package = Package::<CurrentNetwork>::open(&std::env::current_dir()?)?;
+        // Deploy the package.
+        let deployment = package.deploy::<CurrentAleo>(self.endpoint.clone())?;
+
+        Ok(format!(
+            "✅ Deployed '{}' (edition {})\n\n{deployment}",
+            deployment.program_id().to_string().bold(),
+            deployment.edition()
+        ))
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod account;
+pub use account::*;
+
+mod analyze;
+pub use analyze::*;
+
+mod build;
+pub use build::*;
+
+mod clean;
+pub use clean::*;
+
+mod decrypt;
+pub use decrypt::*;
+
+mod deploy;
+pub use deploy::*;
+
+mod new;
+pub use new::*;
+
+mod run;
+pub use run::*;
+
+mod update;
+pub use update::*;
+
+mod verify;
+pub use verify::*;
+
+use anyhow::Result;
+use clap::Parser;
+
+type CurrentNetwork = crate::prelude::Testnet3;
+type CurrentAleo = crate::circuit::AleoV0;
+
+#[derive(Debug, Parser)]
+pub enum Command {
+    /// Manage an Aleo account
+    #[clap(subcommand)]
+    Account(Account),
+    /// Analyze the Aleo program in the current directory
+    Analyze(Analyze),
+    /// Compile the Aleo program in the current directory
+    Build(Build),
+    /// Remove the build directory of the Aleo program in the current directory
+    Clean(Clean),
+    /// Decrypt a record ciphertext with a view key
+    Decrypt(Decrypt),
+    /// Deploy the Aleo program in the current directory
+    Deploy(Deploy),
+    /// Create a new Aleo program
+    New(New),
+    /// Run a function of the Aleo program in the current directory
+    Run(Run),
+    /// Update snarkVM to the latest version
+    Update(Update),
+    /// Verify a transaction from a JSON file
+    Verify(Verify),
+}
+
+impl Command {
+    /// Parse the command.
+    pub fn start(&self) -> Result<String> {
+        match self {
+            Self::Account(command) => command.start(),
+            Self::Analyze(command) => command.start(),
+            Self::Build(command) => command.start(),
+            Self::Clean(command) => command.start(),
+            Self::Decrypt(command) => command.start(),
+            Self::Deploy(command) => command.start(),
+            Self::New(command) => command.start(),
+            Self::Run(command) => command.start(),
+            Self::Update(command) => command.start(),
+            Self::Verify(command) => command.start(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::CurrentNetwork;
+use crate::{package::Package, prelude::ProgramID};
+
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use core::str::FromStr;
+use std::path::Path;
+
+/// Creates a new Aleo program.
+#[derive(Debug, Parser)]
+pub struct New {
+    /// The program name
+    name: String,
+}
+
+impl New {
+    /// Creates a new Aleo program in a new directory with the program name.
+    pub fn start(&self) -> Result<String> {
+        self.start_in(&std::env::current_dir()?)
+    }
+
+    /// Creates a new Aleo program in a new directory with the program name, under the given directory.
+    fn start_in(&self, parent: &Path) -> Result<String> {
+        // Initialize the program ID.
+        let program_id = ProgramID::<CurrentNetwork>::from_str(&format!("{}.aleo", self.name))?;
+        // Prepare the package directory.
+        let directory = parent.join(&self.name);
+        // Create the package.
+        let _package = Package::create(&directory, &program_id)?;
+
+        Ok(format!("✅ Created an Aleo program '{}' at '{}'", program_id.to_string().bold(), directory.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        // Initialize a temporary directory.
+        let parent = tempfile::tempdir().unwrap();
+
+        let output = New { name: "hello".to_string() }.start_in(parent.path()).unwrap();
+        assert!(output.contains("hello.aleo"));
+        // Ensure the package was created.
+        let directory = parent.path().join("hello");
+        assert!(directory.join("main.aleo").exists());
+        assert!(Package::<CurrentNetwork>::open(&directory).is_ok());
+
+        // Ensure an invalid program name is rejected.
+        assert!(New { name: "1hello".to_string() }.start_in(parent.path()).is_err());
+        assert!(!parent.path().join("1hello").exists());
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{CurrentAleo, CurrentNetwork};
+use crate::{
+    package::Package,
+    prelude::{Identifier, Value},
+};
+
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use core::str::FromStr;
+
+/// Runs a function of the Aleo program in the current directory.
+#[derive(Debug, Parser)]
+pub struct Run {
+    /// The function name
+    function: String,
+    /// The function inputs
+    inputs: Vec<String>,
+    /// Uses the specified endpoint to compile the program
+    #[clap(long)]
+    endpoint: Option<String>,
+}
+
+impl Run {
+    /// Runs a function of the Aleo program in the current directory, with the development private key.
+    pub fn start(&self) -> Result<String> {
+        // Parse the function name and inputs.
+        let function_name = Identifier::<CurrentNetwork>::from_str(&self.function)?;
+        let inputs = self.inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>>>()?;
+
+        // Open the package in the current directory.
+        let package = Package::<CurrentNetwork>::open(&std::env::current_dir()?)?;
+        // Retrieve the development private key.
+        let private_key = package.manifest_file().development_private_key();
+
+        // Run the function.
+        let rng = &mut rand::thread_rng();
+        let (response, _execution, _inclusion) =
+            package.run::<CurrentAleo, _>(self.endpoint.clone(), private_key, function_name, &inputs, rng)?;
+
+        // Format the outputs.
+        let outputs = response.outputs().iter().map(|output| format!(" • {output}")).collect::<Vec<_>>();
+        Ok(format!(
+            "✅ Executed '{}/{function_name}'\n\n➡️  Outputs\n\n{}",
+            package.program_id().to_string().bold(),
+            outputs.join("\n")
+        ))
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{CurrentAleo, CurrentNetwork};
+use crate::{
+    file::VerifierFile,
+    package::Package,
+    synthesizer::{Process, Transaction},
+};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use colored::Colorize;
+use core::str::FromStr;
+use std::path::{Path, PathBuf};
+
+/// Verifies a transaction from a JSON file.
+/// Note: This does *not* check that the global state root exists in the ledger.
+#[derive(Debug, Parser)]
+pub struct Verify {
+    /// The path to the transaction JSON file
+    path: PathBuf,
+}
+
+impl Verify {
+    /// Verifies the transaction, with the Aleo program in the current directory (if any) loaded.
+    pub fn start(&self) -> Result<String> {
+        self.start_in(&std::env::current_dir()?)
+    }
+
+    /// Verifies the transaction, with the Aleo program in the given directory (if any) loaded.
+    fn start_in(&self, directory: &Path) -> Result<String> {
+        // Read the transaction.
+        let transaction = Transaction::<CurrentNetwork>::from_str(&std::fs::read_to_string(&self.path)?)?;
+        // Ensure the transaction ID is correct.
+        ensure!(*transaction.id() == transaction.to_root()?, "The transaction ID is incorrect");
+
+        // Load the process.
+        let process = Self::load_process(directory)?;
+        // Verify the transaction.
+        match &transaction {
+            Transaction::Deploy(_, deployment, fee) => {
+                process.verify_deployment::<CurrentAleo, _>(deployment, &mut rand::thread_rng())?;
+                process.verify_fee(fee)?;
+            }
+            Transaction::Execute(_, execution, additional_fee) => {
+                process.verify_execution::<true>(execution)?;
+                if let Some(additional_fee) = additional_fee {
+                    process.verify_fee(additional_fee)?;
+                }
+            }
+        }
+
+        Ok(format!("✅ Verified transaction '{}'", transaction.id().to_string().bold()))
+    }
+
+    /// Returns the process, with the package in the given directory and its verifying keys, if it exists.
+    fn load_process(directory: &Path) -> Result<Process<CurrentNetwork>> {
+        let package = match Package::<CurrentNetwork>::open(directory) {
+            Ok(package) => package,
+            Err(_) => return Process::load(),
+        };
+        let process = package.get_process()?;
+        // Load the verifying keys that have been built.
+        let build_directory = package.build_directory();
+        for function_name in package.program().functions().keys() {
+            if VerifierFile::<CurrentNetwork>::exists_at(&build_directory, function_name) {
+                let verifier = VerifierFile::open(&build_directory, function_name)?;
+                process.insert_verifying_key(package.program_id(), function_name, verifier.verifying_key().clone())?;
+            }
+        }
+        Ok(process)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::TestRng;
+
+    #[test]
+    fn test_verify() {
+        let rng = &mut TestRng::default();
+
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package();
+        // Build the package.
+        package.build::<CurrentAleo>(None).unwrap();
+
+        // Execute the package.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        let (_response, execution, _inclusion) =
+            package.run::<CurrentAleo, _>(None, &private_key, function_name, &inputs, rng).unwrap();
+        let transaction = Transaction::from_execution(execution, None).unwrap();
+
+        // Write the transaction to a file.
+        let path = directory.join("transaction.json");
+        std::fs::write(&path, transaction.to_string()).unwrap();
+        // Ensure the transaction verifies.
+        let output = Verify { path: path.clone() }.start_in(&directory).unwrap();
+        assert!(output.contains(&transaction.id().to_string()));
+
+        // Ensure a malformed transaction file is rejected.
+        std::fs::write(&path, "invalid").unwrap();
+        assert!(Verify { path }.start_in(&directory).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
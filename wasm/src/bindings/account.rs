@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{AleoError, CurrentNetwork, ErrorKind, Signature};
+use snarkvm_console::account;
+
+use core::str::FromStr;
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// An Aleo private key.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct PrivateKey(account::PrivateKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl PrivateKey {
+    /// Samples a new private key.
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Result<PrivateKey, AleoError> {
+        account::PrivateKey::new(&mut OsRng).map(Self).map_err(AleoError::with(ErrorKind::InvalidPrivateKey))
+    }
+
+    /// Imports a private key from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, AleoError> {
+        account::PrivateKey::from_str(private_key).map(Self).map_err(AleoError::with(ErrorKind::InvalidPrivateKey))
+    }
+
+    /// Returns the string representation of the private key.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Derives the view key of the private key.
+    #[wasm_bindgen(js_name = toViewKey)]
+    pub fn to_view_key(&self) -> Result<ViewKey, AleoError> {
+        ViewKey::from_private_key(self)
+    }
+
+    /// Derives the address of the private key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, AleoError> {
+        Address::from_private_key(self)
+    }
+
+    /// Signs the given message.
+    pub fn sign(&self, message: &[u8]) -> Result<Signature, AleoError> {
+        self.0.sign_bytes(message, &mut OsRng).map(Signature::from).map_err(AleoError::with(ErrorKind::SigningFailed))
+    }
+}
+
+impl PrivateKey {
+    /// Returns the underlying private key.
+    pub(crate) const fn inner(&self) -> &account::PrivateKey<CurrentNetwork> {
+        &self.0
+    }
+}
+
+/// An Aleo view key.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct ViewKey(account::ViewKey<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl ViewKey {
+    /// Derives the view key of the given private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Result<ViewKey, AleoError> {
+        account::ViewKey::try_from(private_key.inner()).map(Self).map_err(AleoError::with(ErrorKind::InvalidPrivateKey))
+    }
+
+    /// Imports a view key from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(view_key: &str) -> Result<ViewKey, AleoError> {
+        account::ViewKey::from_str(view_key).map(Self).map_err(AleoError::with(ErrorKind::InvalidViewKey))
+    }
+
+    /// Returns the string representation of the view key.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Derives the address of the view key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, AleoError> {
+        Address::from_view_key(self)
+    }
+}
+
+impl ViewKey {
+    /// Returns the underlying view key.
+    pub(crate) const fn inner(&self) -> &account::ViewKey<CurrentNetwork> {
+        &self.0
+    }
+}
+
+/// An Aleo address.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct Address(account::Address<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Address {
+    /// Derives the address of the given private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Result<Address, AleoError> {
+        account::Address::try_from(private_key.inner()).map(Self).map_err(AleoError::with(ErrorKind::InvalidPrivateKey))
+    }
+
+    /// Derives the address of the given view key.
+    #[wasm_bindgen(js_name = fromViewKey)]
+    pub fn from_view_key(view_key: &ViewKey) -> Result<Address, AleoError> {
+        account::Address::try_from(view_key.inner()).map(Self).map_err(AleoError::with(ErrorKind::InvalidViewKey))
+    }
+
+    /// Imports an address from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(address: &str) -> Result<Address, AleoError> {
+        account::Address::from_str(address).map(Self).map_err(AleoError::with(ErrorKind::InvalidAddress))
+    }
+
+    /// Returns the string representation of the address.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the given signature is valid for the given message and this address.
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        signature.verify(self, message)
+    }
+}
+
+impl Address {
+    /// Returns the underlying address.
+    pub(crate) const fn inner(&self) -> &account::Address<CurrentNetwork> {
+        &self.0
+    }
+}
+
+impl From<account::Address<CurrentNetwork>> for Address {
+    fn from(address: account::Address<CurrentNetwork>) -> Self {
+        Self(address)
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Address, AleoError, CurrentNetwork, ErrorKind, PrivateKey};
+use snarkvm_console::program::{Identifier, ProgramID, Request, Value, ValueType};
+
+use core::str::FromStr;
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// A signed request to call a program function, which roots the authorization of the call.
+/// Note: The requests for any nested calls are signed by the prover, when the call is executed.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Authorization(Request<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Authorization {
+    /// Authorizes a call to the given function, with the given inputs and their types (e.g. `u64.private`).
+    pub fn sign(
+        private_key: &PrivateKey,
+        program_id: &str,
+        function_name: &str,
+        inputs: Box<[JsValue]>,
+        input_types: Box<[JsValue]>,
+    ) -> Result<Authorization, AleoError> {
+        let invalid_input = AleoError::with(ErrorKind::InvalidInput);
+        // Parse the program ID and function name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str(program_id).map_err(&invalid_input)?;
+        let function_name = Identifier::<CurrentNetwork>::from_str(function_name).map_err(&invalid_input)?;
+        // Parse the inputs and input types.
+        let inputs = parse_all::<Value<CurrentNetwork>>(&inputs)?;
+        let input_types = parse_all::<ValueType<CurrentNetwork>>(&input_types)?;
+
+        // Sign the request.
+        Request::sign(private_key.inner(), program_id, function_name, inputs.into_iter(), &input_types, &mut OsRng)
+            .map(Self)
+            .map_err(AleoError::with(ErrorKind::AuthorizationFailed))
+    }
+
+    /// Returns the program ID of the call.
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.0.program_id().to_string()
+    }
+
+    /// Returns the function name of the call.
+    #[wasm_bindgen(js_name = functionName)]
+    pub fn function_name(&self) -> String {
+        self.0.function_name().to_string()
+    }
+
+    /// Returns the address of the caller.
+    pub fn caller(&self) -> Address {
+        Address::from(*self.0.caller())
+    }
+
+    /// Returns `true` if the authorization is valid for the given input types.
+    pub fn verify(&self, input_types: Box<[JsValue]>) -> Result<bool, AleoError> {
+        Ok(self.0.verify(&parse_all::<ValueType<CurrentNetwork>>(&input_types)?))
+    }
+
+    /// Returns the string representation of the authorization.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Parses each of the given JavaScript strings.
+fn parse_all<T: FromStr<Err = snarkvm_console::prelude::Error>>(values: &[JsValue]) -> Result<Vec<T>, AleoError> {
+    values
+        .iter()
+        .map(|value| match value.as_string() {
+            Some(value) => T::from_str(&value).map_err(AleoError::with(ErrorKind::InvalidInput)),
+            None => Err(AleoError::new(ErrorKind::InvalidInput, "Expected a string")),
+        })
+        .collect()
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use wasm_bindgen::prelude::*;
+
+/// The kind of error raised by the bindings.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidPrivateKey,
+    InvalidViewKey,
+    InvalidAddress,
+    InvalidSignature,
+    InvalidRecord,
+    InvalidInput,
+    SigningFailed,
+    DecryptionFailed,
+    AuthorizationFailed,
+}
+
+/// An error raised by the bindings, with a kind that can be matched on in JavaScript.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct AleoError {
+    kind: ErrorKind,
+    message: String,
+}
+
+#[wasm_bindgen]
+impl AleoError {
+    /// Returns the kind of error.
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the error message.
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl AleoError {
+    /// Initializes a new error of the given kind.
+    pub(crate) fn new(kind: ErrorKind, message: impl ToString) -> Self {
+        Self { kind, message: message.to_string() }
+    }
+
+    /// Returns a closure that maps an error into an `AleoError` of the given kind.
+    pub(crate) fn with<E: ToString>(kind: ErrorKind) -> impl Fn(E) -> Self {
+        move |error| Self::new(kind, error)
+    }
+}
+
+impl core::fmt::Display for AleoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
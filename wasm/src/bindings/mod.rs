@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! JavaScript bindings for Aleo accounts, signatures, records, and authorizations.
+
+mod account;
+pub use account::*;
+
+mod authorize;
+pub use authorize::*;
+
+mod error;
+pub use error::*;
+
+mod record;
+pub use record::*;
+
+mod signature;
+pub use signature::*;
+
+type CurrentNetwork = snarkvm_console::network::Testnet3;
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{AleoError, CurrentNetwork, ErrorKind, ViewKey};
+use snarkvm_console::program::{Ciphertext, Plaintext, Record};
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An encrypted Aleo record.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct RecordCiphertext(Record<CurrentNetwork, Ciphertext<CurrentNetwork>>);
+
+#[wasm_bindgen]
+impl RecordCiphertext {
+    /// Imports a record ciphertext from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordCiphertext, AleoError> {
+        Record::from_str(record).map(Self).map_err(AleoError::with(ErrorKind::InvalidRecord))
+    }
+
+    /// Returns the string representation of the record ciphertext.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Decrypts the record with the given view key.
+    pub fn decrypt(&self, view_key: &ViewKey) -> Result<RecordPlaintext, AleoError> {
+        self.0.decrypt(view_key.inner()).map(RecordPlaintext).map_err(AleoError::with(ErrorKind::DecryptionFailed))
+    }
+
+    /// Returns `true` if the given view key belongs to the owner of the record.
+    #[wasm_bindgen(js_name = isOwner)]
+    pub fn is_owner(&self, view_key: &ViewKey) -> bool {
+        self.0.is_owner(view_key.inner())
+    }
+}
+
+/// A decrypted Aleo record.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct RecordPlaintext(Record<CurrentNetwork, Plaintext<CurrentNetwork>>);
+
+#[wasm_bindgen]
+impl RecordPlaintext {
+    /// Imports a record plaintext from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordPlaintext, AleoError> {
+        Record::from_str(record).map(Self).map_err(AleoError::with(ErrorKind::InvalidRecord))
+    }
+
+    /// Returns the string representation of the record plaintext.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{Address, AleoError, CurrentNetwork, ErrorKind};
+use snarkvm_console::account;
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An Aleo signature.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct Signature(account::Signature<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Signature {
+    /// Imports a signature from its string representation.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(signature: &str) -> Result<Signature, AleoError> {
+        account::Signature::from_str(signature).map(Self).map_err(AleoError::with(ErrorKind::InvalidSignature))
+    }
+
+    /// Returns the string representation of the signature.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the signature is valid for the given address and message.
+    pub fn verify(&self, address: &Address, message: &[u8]) -> bool {
+        self.0.verify_bytes(address.inner(), message)
+    }
+}
+
+impl From<account::Signature<CurrentNetwork>> for Signature {
+    fn from(signature: account::Signature<CurrentNetwork>) -> Self {
+        Self(signature)
+    }
+}
@@ -60,3 +60,144 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+mod bindings {
+    use super::*;
+    use crate::bindings;
+    use snarkvm_console::{
+        network::Network,
+        program::{Plaintext, Record},
+        types::Scalar,
+    };
+    use snarkvm_utilities::Uniform;
+
+    use wasm_bindgen::JsValue;
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    #[wasm_bindgen_test]
+    fn test_account() {
+        let private_key = bindings::PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        assert_eq!(ALEO_PRIVATE_KEY, private_key.to_string());
+
+        let view_key = private_key.to_view_key().unwrap();
+        assert_eq!(ALEO_VIEW_KEY, view_key.to_string());
+        assert_eq!(ALEO_VIEW_KEY, bindings::ViewKey::from_string(ALEO_VIEW_KEY).unwrap().to_string());
+
+        let address = view_key.to_address().unwrap();
+        assert_eq!(ALEO_ADDRESS, address.to_string());
+        assert_eq!(ALEO_ADDRESS, private_key.to_address().unwrap().to_string());
+        assert_eq!(ALEO_ADDRESS, bindings::Address::from_string(ALEO_ADDRESS).unwrap().to_string());
+
+        // Ensure a new private key can be sampled.
+        let private_key = bindings::PrivateKey::new().unwrap();
+        assert_eq!(
+            private_key.to_string(),
+            bindings::PrivateKey::from_string(&private_key.to_string()).unwrap().to_string()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_account_errors() {
+        let error = bindings::PrivateKey::from_string("APrivateKey1invalid").err().unwrap();
+        assert_eq!(error.kind(), bindings::ErrorKind::InvalidPrivateKey);
+        let error = bindings::ViewKey::from_string(ALEO_ADDRESS).err().unwrap();
+        assert_eq!(error.kind(), bindings::ErrorKind::InvalidViewKey);
+        let error = bindings::Address::from_string(ALEO_VIEW_KEY).err().unwrap();
+        assert_eq!(error.kind(), bindings::ErrorKind::InvalidAddress);
+        let error = bindings::Signature::from_string("sign1invalid").err().unwrap();
+        assert_eq!(error.kind(), bindings::ErrorKind::InvalidSignature);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_sign_and_verify() {
+        let private_key = bindings::PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let address = private_key.to_address().unwrap();
+
+        // Sign a message, and ensure the signature round-trips through its string representation.
+        let signature = private_key.sign(b"hello world!").unwrap();
+        let signature = bindings::Signature::from_string(&signature.to_string()).unwrap();
+
+        // Ensure the signature is valid for the message.
+        assert!(signature.verify(&address, b"hello world!"));
+        assert!(address.verify(b"hello world!", &signature));
+        // Ensure the signature is invalid for a different message or address.
+        assert!(!signature.verify(&address, b"hello world?"));
+        assert!(!signature.verify(&bindings::PrivateKey::new().unwrap().to_address().unwrap(), b"hello world!"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_record_decrypt() {
+        let mut rng = TestRng::default();
+
+        // Encrypt a record for the address.
+        let randomizer = Scalar::<Testnet3>::rand(&mut rng);
+        let nonce = Testnet3::g_scalar_multiply(&randomizer);
+        let plaintext = Record::<Testnet3, Plaintext<Testnet3>>::from_str(&format!(
+            "{{ owner: {ALEO_ADDRESS}.private, gates: 5u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = plaintext.encrypt(randomizer).unwrap();
+
+        // Decrypt the record with the owner's view key.
+        let record = bindings::RecordCiphertext::from_string(&ciphertext.to_string()).unwrap();
+        let view_key = bindings::ViewKey::from_string(ALEO_VIEW_KEY).unwrap();
+        assert!(record.is_owner(&view_key));
+        assert_eq!(record.decrypt(&view_key).unwrap().to_string(), plaintext.to_string());
+
+        // Ensure a different view key is not the owner.
+        let other_view_key = bindings::PrivateKey::new().unwrap().to_view_key().unwrap();
+        assert!(!record.is_owner(&other_view_key));
+
+        // Ensure an invalid record is rejected.
+        let error = bindings::RecordCiphertext::from_string("{ owner: invalid }").err().unwrap();
+        assert_eq!(error.kind(), bindings::ErrorKind::InvalidRecord);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_authorize() {
+        let private_key = bindings::PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let strings = |values: &[&str]| values.iter().map(|value| JsValue::from_str(value)).collect::<Box<[_]>>();
+
+        // Authorize a call.
+        let input_types = ["address.public", "u64.public"];
+        let authorization = bindings::Authorization::sign(
+            &private_key,
+            "credits.aleo",
+            "mint",
+            strings(&[ALEO_ADDRESS, "100u64"]),
+            strings(&input_types),
+        )
+        .unwrap();
+        assert_eq!(authorization.program_id(), "credits.aleo");
+        assert_eq!(authorization.function_name(), "mint");
+        assert_eq!(authorization.caller().to_string(), ALEO_ADDRESS);
+        assert!(authorization.verify(strings(&input_types)).unwrap());
+
+        // Ensure a mismatched number of inputs is rejected.
+        let error = bindings::Authorization::sign(
+            &private_key,
+            "credits.aleo",
+            "mint",
+            strings(&[ALEO_ADDRESS]),
+            strings(&input_types),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind(), bindings::ErrorKind::AuthorizationFailed);
+
+        // Ensure an invalid input is rejected.
+        let error = bindings::Authorization::sign(
+            &private_key,
+            "credits.aleo",
+            "mint",
+            strings(&[ALEO_ADDRESS, "100"]),
+            strings(&input_types),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind(), bindings::ErrorKind::InvalidInput);
+    }
+}